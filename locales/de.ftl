@@ -0,0 +1,496 @@
+## Allgemein
+
+prompt = Gib hier deinen Befehl ein:
+command-unknown = ❓ Diesen Befehl gibt es nicht.
+quitting = Das Programm wird beendet.
+try-it = Probier es aus! :)
+argument-all = alle
+value-true = ja
+value-false = nein
+
+## Befehle
+
+command-add = hinzufügen
+command-read = lesen
+command-change = ändern
+command-recommend = empfehlen
+command-inventory = inventar
+command-taste = geschmack
+command-save = spielstand
+command-gift = schenken
+command-talk = reden
+command-status = status
+command-export-calendar = kalender-exportieren
+command-report = bericht
+command-relate = verbinden
+command-family = familie
+command-graph = graph
+command-schedule = zeitplan
+command-where = wo
+command-who-is-at = wer-ist-in
+command-festivals = feste
+command-upcoming = demnächst
+command-romance = romanze
+command-next-event = nächstes-ereignis
+command-profile = profil
+command-import-save = spielstand-importieren
+command-import-mod = mod-importieren
+command-uninstall-mod = mod-entfernen
+command-language = sprache
+command-translate = übersetzen
+command-quit = beenden
+
+## Jahreszeiten
+
+season-spring = Frühling
+season-summer = Sommer
+season-fall = Herbst
+season-winter = Winter
+
+## Daten
+
+date = { $day }. { $season }
+weekday-monday = Montag
+weekday-tuesday = Dienstag
+weekday-wednesday = Mittwoch
+weekday-thursday = Donnerstag
+weekday-friday = Freitag
+weekday-saturday = Samstag
+weekday-sunday = Sonntag
+birthday-of = 🎂 { $name }s Geburtstag
+at-location = { $what } – { $location }
+
+## Figuren
+
+character-added = { $name } wurde erfolgreich zur Datenbank hinzugefügt! :)
+character-add-error = Beim Hinzufügen von { $name } ist ein Fehler aufgetreten! { $error }
+character-birthday = { $name }s Geburtstag: { $day }. { $season }
+character-best-gift = { $name }s Lieblingsgeschenk: { $gift }
+character-can-marry = { $name } kann geheiratet werden! ❤
+character-cannot-marry = { $name } kann NICHT geheiratet werden! 💔
+character-from-mod = { $name } stammt aus der Mod { $source }
+character-tags = 🏷 Schlagwörter: { $tags }
+character-not-found = Tut mir leid, ich kann { $name } nicht in der Datenbank finden!
+
+## lesen
+
+read-usage = Gib ein Argument an, etwa 'alle', um alle Figuren zu lesen, oder 'Abigail' für eine bestimmte Figur.
+read-source-usage = ‼ Bitte gib 'vanilla' oder die eindeutige ID einer Mod an, zum Beispiel: 'lesen --source Example.ClaireNPC'.
+read-source-empty = Tut mir leid, ich finde keine Figuren aus { $source } in der Datenbank!
+read-tag-empty = Tut mir leid, ich finde keine Figuren mit dem Schlagwort '{ $tag }'!
+read-more = — { $count } angezeigt. Drücke Enter für mehr oder gib 'q' ein, um aufzuhören. —
+
+## hinzufügen
+
+add-usage = ‼ Bitte gib die Argumente in dieser Reihenfolge an: Name, Geburtstagsjahreszeit, Geburtstag, ob heiratbar, Lieblingsgeschenk. Du hast nur { $count } Argumente eingegeben, ich brauche aber 5.
+add-example = Für Abigail wäre das zum Beispiel: abigail herbst 13 ja amethyst
+
+## ändern
+
+change-usage = ‼ Bitte gib die Argumente in dieser Reihenfolge an: Name der Figur, Name des Werts, der geändert werden soll, und dann der neue Wert. Du hast nur { $count } Argumente eingegeben, ich brauche aber 3.
+change-example = Um zum Beispiel Abigails Geburtstagsjahreszeit zu ändern, schreibst du: 'abigail birthday_season sommer'.
+change-values = Diese Werte gibt es: name, birthday_season, birthday_day, is_bachelor, best_gift
+change-unknown-value = Diesen Wert kenne ich nicht!
+change-done = ✅ Die Änderung wurde gespeichert! Sieh sie dir mit 'lesen' und dem Namen der Figur an.
+change-not-found = Ich kann keine Figur mit dem Namen '{ $name }' in der Datenbank finden!
+change-error = Beim Ändern der Figur in der Datenbank ist ein Fehler aufgetreten!
+
+## Eingaben
+
+invalid-season = ‼ Bitte gib eine richtige Jahreszeit an. Ich habe '{ $input }' bekommen, verstehe aber nur 'frühling', 'sommer', 'herbst' oder 'winter'.
+invalid-day = ‼ Bitte gib einen richtigen Tag an. Denk daran, dass eine Jahreszeit in Stardew Valley nur 28 Tage hat! (Ich habe { $input } bekommen.)
+invalid-bachelor = ‼ Bitte gib an, ob die Figur heiratbar ist. Ich habe '{ $input }' bekommen, verstehe aber nur 'ja' oder 'nein'.
+
+## sprache und übersetzen
+
+language-current = 🌍 Die Sprache ist { $locale }.
+language-available = Verfügbare Sprachen: { $locales }
+language-switched = 🌍 Die Sprache ist jetzt { $locale }!
+language-unknown = ‼ Die Sprache '{ $locale }' kenne ich nicht. Verfügbare Sprachen: { $locales }
+translate-usage = ‼ Bitte gib 'character' oder 'item' an, den Namen (mit _ statt Leerzeichen), die Sprache und den übersetzten Namen.
+translate-example = Zum Beispiel: 'übersetzen item Goat_Cheese de Ziegenkäse'. Ohne Sprache und Namen werden die Übersetzungen aufgelistet.
+translate-list = 🌍 Übersetzungen von { $name }:
+translate-none = Es gibt noch keine.
+translate-saved = ✅ { $name } heißt auf { $locale } jetzt { $display }!
+
+## Figuren finden
+
+lookup-ambiguous = 🔎 Es gibt mehrere Figuren, die '{ $input }' sein könnten:
+lookup-pick = Gib die Nummer der richtigen ein, oder etwas anderes zum Abbrechen:
+lookup-cancelled = Okay, ich habe nichts gemacht.
+
+## löschen und spitzname
+
+command-delete = löschen
+command-alias = spitzname
+delete-usage = ‼ Bitte gib den Namen der Figur an, die du löschen willst, zum Beispiel: 'löschen sebastian'.
+delete-confirm = ‼ Damit werden { $name } und alle Geschmäcker, Freundschaften, Zeitpläne und alles andere gelöscht. Gib zur Bestätigung den Namen noch einmal ein:
+delete-cancelled = Okay, ich habe nichts gelöscht.
+delete-done = 🗑 { $name } wurde aus der Datenbank gelöscht.
+alias-usage = ‼ Bitte gib den Namen der Figur und den Spitznamen an, den du für sie benutzen willst.
+alias-example = Zum Beispiel: 'spitzname abigail abby'. Ohne Spitznamen werden alle aufgelistet.
+alias-none = { $name } hat noch keine Spitznamen.
+alias-list = { $name } ist auch bekannt als: { $aliases }
+alias-added = ✅ Du kannst { $name } jetzt '{ $alias }' nennen!
+alias-error = Der Spitzname '{ $alias }' konnte nicht hinzugefügt werden! Vielleicht gehört er schon jemand anderem.
+
+## suchen
+
+command-search = suchen
+search-usage = ‼ Bitte gib an, wonach du suchst, mit mindestens einem Wort aus { $length } oder mehr Buchstaben.
+search-example = Zum Beispiel: 'suchen gem' oder 'suchen saloon'.
+search-results = 🔎 Figuren, die zu '{ $text }' passen:
+search-none = Tut mir leid, zu '{ $text }' habe ich nichts gefunden!
+search-more = … und { $count } weitere.
+search-field-name = Name
+search-field-best-gift = Lieblingsgeschenk
+search-field-gift-taste = Geschmack
+search-field-alias = Spitzname
+search-field-note = Notiz
+search-field-tag = Schlagwort
+search-field-schedule = Zeitplan
+search-field-heart-event = Herzereignis
+search-field-spouse-room = Zimmer nach der Hochzeit
+
+## notiz, markieren und entmarkieren
+
+command-note = notiz
+command-tag = markieren
+command-untag = entmarkieren
+note-usage = ‼ Bitte gib den Namen der Figur und deine Notiz an. Ohne Notiz werden alle Notizen zu ihr angezeigt.
+note-example = Zum Beispiel: 'notiz leah sonntags schenken, ab 17 Uhr im Saloon'
+note-list = 📝 Notizen zu { $name }:
+note-none = Es gibt noch keine.
+note-too-long = ‼ Notizen können bis zu { $length } Zeichen lang sein.
+note-added = ✅ Für { $name } notiert!
+tag-usage = ‼ Bitte gib den Namen der Figur und ein oder mehrere Schlagwörter an.
+tag-example = Zum Beispiel: 'markieren abigail edelsteine abenteuerlustig'. Alle mit einem Schlagwort findest du mit 'lesen tag=edelsteine'.
+tag-added = 🏷 { $name } hat die Schlagwörter: { $tags }
+tag-known = { $name } hat diese Schlagwörter schon.
+untag-usage = ‼ Bitte gib den Namen der Figur und die zu entfernenden Schlagwörter an, zum Beispiel: 'entmarkieren abigail edelsteine'.
+untag-none = { $name } hat keines dieser Schlagwörter.
+untag-removed = 🏷 Von { $name } entfernt: { $tags }
+
+## konfiguration
+
+command-config = konfiguration
+config-usage = ‼ Mit 'konfiguration show' siehst du die Einstellungen, mit denen das Programm läuft.
+config-missing = Die Einstellung '{ $key }' fehlt. Setze { $env } in deiner .env-Datei, übergib { $flag } oder trage sie in die Konfigurationsdatei ein.
+config-invalid = Die Einstellung '{ $key }' kann nicht '{ $value }' sein: { $reason }
+config-file-error = Die Konfigurationsdatei { $path } kann nicht gelesen werden: { $reason }
+config-unknown-flag = Die Option '{ $flag }' kenne ich nicht. Verwende { $flags } oder --config <datei>.
+config-needs-value = sie braucht einen Wert
+config-unknown-protocol = nur mysql, mariadb und postgres werden unterstützt
+config-no-such-file = diese Datei gibt es nicht
+config-mysql-only = das geht nur mit MySQL, Postgres verwendet immer UTF-8
+config-no-connections = es wird mindestens eine Verbindung gebraucht
+config-settings-file = ⚙ Einstellungen (Konfigurationsdatei: { $path }):
+config-settings-no-file = ⚙ Einstellungen (keine Konfigurationsdatei):
+config-setting = { $key } = { $value } ({ $origin })
+config-not-set = { $key } ist nicht gesetzt
+config-origin-default = Standard
+config-origin-file = Konfigurationsdatei
+config-origin-env = Umgebung
+config-origin-flag = Kommandozeile
+
+## zustand und verbindung
+
+command-health = zustand
+command-ping = ping
+health-failed = ‼ Die Datenbank ist gerade nicht erreichbar:
+health-ok = 🩺 Die Datenbank ist erreichbar, der Ping dauerte { $milliseconds } ms.
+health-server = Serverversion: { $version }
+health-database = Datenbank: { $name } (Profil '{ $profile }')
+health-connections = Verbindungen: { $open } offen, { $idle } unbenutzt, höchstens { $max }
+connection-retry = ⏳ Die Datenbank ist noch nicht erreichbar, neuer Versuch in { $seconds } s ({ $attempt }/{ $retries })…
+connection-lost = ‼ Die Verbindung zur Datenbank ist abgebrochen, verbinde neu…
+connection-restored = ✅ Wieder verbunden! Dein Befehl wird noch einmal ausgeführt.
+connection-restored-not-retried = ✅ Wieder verbunden! Dein Befehl ändert Daten und wurde vielleicht schon ausgeführt, deshalb wird er nicht wiederholt. Bitte prüfe das und führe ihn bei Bedarf noch einmal aus.
+connection-unavailable = ‼ Die Datenbank ist weiterhin nicht erreichbar. Dein Befehl wurde nicht ausgeführt, bitte versuche es später noch einmal.
+
+## migrieren
+
+command-migrate = migrieren
+migrate-usage = ‼ Bitte nutze 'migrieren status', 'migrieren up' oder 'migrieren down <version>'. Mit '--dry-run' siehst du, was passieren würde, ohne etwas zu ändern.
+migrate-example = Zum Beispiel: 'migrieren down 12 --dry-run'
+migrate-invalid-version = ‼ Bitte gib die Version, zu der du zurück willst, als Zahl an, etwa '12'. Ich habe '{ $input }' erhalten.
+migrate-status = 🗄 Migrationen des Profils '{ $profile }' (↕ kann rückgängig gemacht werden):
+migrate-state-applied = angewendet { $date }
+migrate-state-pending = ausstehend
+migrate-state-failed = ‼ auf halbem Weg fehlgeschlagen
+migrate-state-changed = ‼ nach dem Anwenden geändert
+migrate-state-unknown = ‼ von einer neueren Version des Programms angewendet
+migrate-up-to-date = ✅ Die Datenbank ist auf dem neuesten Stand.
+migrate-pending = { $count } Migration(en) ausstehend. Wende sie mit 'migrieren up' an.
+migrate-pending-startup = ‼ Der Datenbank fehlen { $count } Migration(en) und DB_AUTO_MIGRATE ist aus. Befehle können fehlschlagen, bis du 'migrieren up' ausführst.
+migrate-ahead = Die Datenbank wurde von einer neueren Version des Programms auf Version { $version } migriert, diese kennt nur Versionen bis { $latest }. Bitte aktualisiere das Programm.
+migrate-failed = Migration { $version } ist auf halbem Weg fehlgeschlagen. Bitte repariere die Datenbank von Hand und lösche ihre Zeile aus _sqlx_migrations.
+migrate-changed = Migration { $version } wurde nach dem Anwenden geändert. Bitte stelle die ursprüngliche Datei wieder her.
+migrate-dry-run-up = Diese { $count } Migration(en) würden angewendet:
+migrate-up-done = ✅ { $count } Migration(en) angewendet, die Datenbank ist jetzt auf Version { $version }.
+migrate-irreversible = ‼ Migration { $version } kann nicht rückgängig gemacht werden, es gibt keine .down.sql dafür.
+migrate-down-nothing = Die Datenbank ist nicht über Version { $version } hinaus, es gibt nichts rückgängig zu machen.
+migrate-dry-run-down = Diese { $count } Migration(en) würden rückgängig gemacht, um zu Version { $version } zurückzukehren:
+migrate-down-confirm = ‼ Das löscht Tabellen und Spalten des Profils '{ $profile }' samt Inhalt. Gib zur Bestätigung noch einmal { $version } ein:
+migrate-down-cancelled = Okay, ich habe nichts geändert.
+migrate-down-done = ✅ { $count } Migration(en) rückgängig gemacht, die Datenbank ist jetzt auf Version { $version }.
+migrate-error = ‼ Die Migration ist fehlgeschlagen:
+migrator-needed = ‼ Der Datenbanknutzer darf nur Zeilen lesen und schreiben. Setze DB_MIGRATOR_USER und DB_MIGRATOR_PASSWORD auf einen Nutzer, der Tabellen und Datenbanken anlegen, ändern und löschen darf, etwa den von setup_db.
+
+command-stats = statistik
+stats-cache-off = 📊 Der Cache ist aus. Setze cache.ttl (CACHE_TTL) auf eine Anzahl Sekunden, um ihn einzuschalten.
+stats-cache = 📊 Figuren im Cache: { $entries }, jede wird { $ttl } s behalten
+stats-lookups = Treffer: { $hits }, Fehlgriffe: { $misses } ({ $rate } % Treffer)
+stats-invalidations = Verworfen, weil sie sich geändert haben: { $count }
+
+## kalender-exportieren
+
+calendar-usage = ‼ Bitte gib die Datei für den Export an, optional gefolgt davon, wie Spieltage auf echte Tage verteilt werden:
+calendar-usage-year = 'kalender-exportieren geburtstage.ics' oder 'kalender-exportieren geburtstage.ics year 03-01' verteilt das Spieljahr über ein echtes Jahr, der Frühling beginnt am angegebenen Tag.
+calendar-usage-play = 'kalender-exportieren geburtstage.ics play 2024-03-20 frühling 1' macht aus einem Spieltag einen echten Tag, beginnend mit den angegebenen Daten. Ohne Spieldatum wird das Datum des aktiven Spielstands verwendet.
+calendar-invalid-spring-start = ‼ Bitte gib den Tag, an dem der Frühling beginnt, als MM-TT an, zum Beispiel: '03-20'.
+calendar-invalid-real-date = ‼ Bitte gib das echte Datum als JJJJ-MM-TT an, zum Beispiel: '2024-03-20'. Ich habe '{ $input }' bekommen.
+calendar-name = Stardew-Valley-Geburtstage
+calendar-can-marry = kann geheiratet werden
+calendar-cannot-marry = kann NICHT geheiratet werden
+calendar-loved-gifts = Geliebte Geschenke: { $gifts }
+calendar-shops-closed = Die Läden sind geschlossen!
+calendar-exported = 📅 { $birthdays } Geburtstage und { $festivals } Feste nach { $file } exportiert! Du kannst die Datei in jede Kalender-App importieren.
+calendar-write-error = Der Kalender konnte nicht nach { $file } geschrieben werden!
+
+## feste und demnächst
+
+festival-conflict = ⚠ { $name }s Geburtstag fällt auf das { $festival }! Die Läden sind geschlossen, also besorg das Geschenk vorher und such { $name } in { $location }.
+festivals-title = 🎪 Feste:
+festivals-shops-closed = (die Läden sind geschlossen)
+festivals-add-usage = ‼ Bitte gib den Namen des Fests, die Jahreszeit, den ersten und letzten Tag an, dann 'at' und den Ort.
+festivals-add-example = Zum Beispiel: 'feste add Erntepicknick herbst 20 21 at cindersap-wald'
+festivals-add-days-usage = ‼ Bitte gib den Namen des Fests, die Jahreszeit, den ersten und letzten Tag an, zum Beispiel: 'feste add Erntepicknick herbst 20 21 at cindersap-wald'
+festivals-end-before-start = ‼ Der letzte Tag des Fests kann nicht vor dem ersten liegen!
+festivals-added = ✅ { $name } wurde in den Kalender eingetragen!
+festivals-add-error = Das Fest { $name } konnte nicht hinzugefügt werden!
+upcoming-no-date = ‼ Bitte gib das Spieldatum an, ab dem gesucht werden soll, zum Beispiel: 'demnächst frühling 10' oder 'demnächst frühling 10 14' für die nächsten 14 Tage.
+upcoming-invalid-days = ‼ Bitte gib eine Anzahl Tage zwischen 1 und { $max } an.
+upcoming-title = 📅 Die nächsten { $days } Tage ab dem { $date }:
+upcoming-nothing = Es steht nichts Besonderes an. 🌾
+
+## spielstand, schenken, reden und status
+
+save-usage = ‼ Bitte nutze 'spielstand create <farm_name>', 'spielstand use <farm_name>', 'spielstand list' oder 'spielstand date <jahreszeit> <tag> <jahr>'.
+save-none-active = ‼ Es gibt noch keinen aktiven Spielstand! Lege zuerst einen mit 'spielstand create <farm_name>' an.
+save-date-hint = Wenn du mit 'spielstand create <farm_name>' einen Spielstand anlegst, nehme ich stattdessen dessen aktuelles Datum.
+save-list-empty = Es gibt noch keine Spielstände! Lege einen mit 'spielstand create <farm_name>' an.
+save-list-title = 🌱 Deine Spielstände:
+save-line = { $name } - { $date }, Jahr { $year }
+save-created = 🌱 Der Spielstand '{ $name }' wurde angelegt und ist jetzt aktiv! Er beginnt am 1. Frühling, Jahr 1.
+save-create-error = Der Spielstand '{ $name }' konnte nicht angelegt werden!
+save-switched = 🌱 Der Spielstand '{ $name }' ist jetzt aktiv!
+save-not-found = Tut mir leid, ich kann den Spielstand '{ $name }' nicht finden!
+save-invalid-year = ‼ Bitte gib ein richtiges Jahr an. Ich habe '{ $input }' bekommen.
+save-date-changed = 📅 In { $name } ist jetzt der { $date }, Jahr { $year }.
+friendship-points = { $name }: { $hearts } ({ $points } Punkte)
+gift-usage = ‼ Bitte gib den Namen der Figur und das Geschenk an, zum Beispiel: 'schenken abigail amethyst'.
+gift-already-today = { $name } hat heute schon ein Geschenk bekommen! Dorfbewohner nehmen nur ein Geschenk pro Tag an.
+gift-week-limit = { $name } hat diese Woche schon { $count } Geschenke bekommen! Versuch es am nächsten Sonntag oder am Geburtstag wieder.
+gift-given = 🎁 Du hast { $name } { $item } geschenkt. { $reaction } ({ $points } Freundschaft)
+gift-given-birthday = 🎁 Du hast { $name } { $item } geschenkt. { $reaction } ({ $points } Freundschaft, Geburtstagsbonus!)
+talk-usage = ‼ Bitte gib den Namen der Figur an, mit der du geredet hast, zum Beispiel: 'reden abigail'.
+talk-already-today = Du hast heute schon mit { $name } geredet! Reden zählt nur einmal pro Tag.
+talk-done = 💬 Du hast mit { $name } geredet. (+{ $points } Freundschaft)
+status-line = { $name }: { $hearts } ({ $points } Punkte, { $status }) - Geschenke diese Woche: { $gifts }/{ $max }
+status-talked-today = heute geredet 💬
+status-usage = ‼ Bitte gib den Namen der Figur und ihren neuen Status an (friend, dating oder married), zum Beispiel: 'status abigail dating'.
+status-invalid = ‼ Als Status verstehe ich nur 'friend', 'dating' oder 'married'. Ich habe '{ $input }' bekommen.
+status-changed = ✅ { $name } ist jetzt { $role }!
+status-friend = befreundet
+status-dating = zusammen
+status-married = verheiratet
+status-role-friend = mit dir befreundet
+status-role-dating = mit dir zusammen
+status-role-married = mit dir verheiratet
+reaction-love = Das liebt { $name }!
+reaction-like = Das mag { $name }!
+reaction-neutral = Das ist { $name } egal.
+reaction-dislike = Das mag { $name } nicht!
+reaction-hate = Das hasst { $name }!
+
+## empfehlen, inventar und geschmack
+
+recommend-no-date = ‼ Bitte gib das Spieldatum an, für das du Geschenkempfehlungen willst, zum Beispiel: 'empfehlen herbst 13'.
+recommend-title = 🎁 Geschenkplan für den { $date }:
+recommend-birthday-today = 🎂 Heute hat { $name } Geburtstag!
+recommend-birthday-soon = 🎂 { $name } hat bald Geburtstag, am { $date } (in { $days } Tagen).
+recommend-inventory-empty = Dein Inventar ist leer! Füge mit 'inventar add <gegenstand> <anzahl>' Gegenstände hinzu, um Empfehlungen zu bekommen.
+recommend-nothing = Über nichts in deinem Inventar würde sich gerade jemand freuen. 😔
+recommend-give = ➡ Schenk { $name } { $item } ({ $taste }, +{ $points } Freundschaft)
+recommend-give-birthday = ➡ Schenk { $name } { $item } ({ $taste }, +{ $points } Freundschaft, Geburtstagsbonus!)
+recommend-keep = ⏳ Heb { $item } für { $name }s Geburtstag am { $date } auf ({ $taste }, +{ $points } Freundschaft)
+recommend-total = Freundschaft insgesamt: { $points } Punkte
+inventory-empty = 🎒 Dein Inventar ist leer!
+inventory-title = 🎒 Dein Inventar:
+inventory-usage = ‼ Bitte nutze 'inventar', 'inventar add <gegenstand> [anzahl]' oder 'inventar remove <gegenstand> [anzahl]'.
+inventory-example = Zum Beispiel: 'inventar add pizza 3'
+inventory-added = 🎒 { $item } x{ $quantity } zum Inventar hinzugefügt!
+inventory-removed = 🎒 { $item } x{ $quantity } aus dem Inventar entfernt!
+inventory-missing = Es gibt kein { $item } in deinem Inventar!
+inventory-invalid-quantity = ‼ Bitte gib eine richtige Anzahl an. Ich habe '{ $input }' bekommen.
+taste-usage = ‼ Bitte gib die Argumente in dieser Reihenfolge an: Name der Figur, ihr Geschmack (love, like, neutral, dislike oder hate), dann der Gegenstand.
+taste-example = Zum Beispiel: 'geschmack abigail love kürbis'
+taste-invalid = ‼ Als Geschmack verstehe ich nur 'love', 'like', 'neutral', 'dislike' oder 'hate'. Ich habe '{ $input }' bekommen.
+taste-saved = ✅ Notiert: { $name } findet { $item } '{ $taste }'!
+taste-error = Der Geschmack von '{ $name }' konnte nicht gespeichert werden!
+taste-love = liebt es
+taste-like = mag es
+taste-neutral = egal
+taste-dislike = mag es nicht
+taste-hate = hasst es
+
+## spielstand-importieren
+
+import-usage = ‼ Bitte gib den Pfad zu einer Stardew-Valley-Speicherdatei oder einem Speicherordner an, zum Beispiel: 'spielstand-importieren ~/.config/StardewValley/Saves/Sunny_123456789'.
+import-invalid-xml = Das ist kein gültiges XML: { $error }
+import-not-a-save = Das ist keine Stardew-Valley-Speicherdatei, sie sollte mit <SaveGame> beginnen.
+import-missing-field = Die Speicherdatei enthält kein richtiges { $field }.
+import-folder-without-name = Der Speicherordner hat keinen Namen.
+import-read-error = Die Speicherdatei { $path } konnte nicht gelesen werden!
+import-error = { $path } konnte nicht importiert werden!
+import-not-activated = Der importierte Spielstand konnte nicht aktiviert werden.
+import-done = 📥 { $save } importiert und aktiviert!
+import-friendships = Importierte Freundschaften: { $count }
+import-unknown-characters = Diese Dorfbewohner sind nicht in der Datenbank, deshalb habe ich sie übersprungen: { $names }
+import-unknown-hint = Füge sie mit 'hinzufügen' hinzu und importiere noch einmal, um sie auch zu verfolgen.
+
+## mod-importieren und mod-entfernen
+
+mod-usage = ‼ Bitte gib den Ordner eines Content-Patcher-Inhaltspakets an, den mit manifest.json und content.json, zum Beispiel: 'mod-importieren Mods/[CP] Claire'.
+mod-read-error = { $path } konnte nicht gelesen werden: { $error }
+mod-invalid-json = { $path } ist kein gültiges JSON: { $error }
+mod-missing-unique-id = Die manifest.json enthält keine UniqueID.
+mod-invalid-id = '{ $id }' kann nicht als Mod-ID verwendet werden.
+mod-missing-changes = Die content.json enthält keine Liste von Changes.
+mod-import-error = Die Mod in { $path } konnte nicht importiert werden!
+mod-added-by = { $name } (schon von { $source } hinzugefügt)
+mod-imported = 🧩 { $name } ({ $id }) importiert
+mod-characters-added = Hinzugefügte Figuren: { $names }
+mod-none = keine
+mod-tastes-added = Hinzugefügte Geschmäcker: { $count }
+mod-conflicts = Diese Dorfbewohner gibt es schon, deshalb habe ich sie übersprungen: { $names }
+mod-incomplete = Diese Dorfbewohner haben in der Mod keinen Geburtstag, deshalb habe ich sie übersprungen: { $names }
+mod-unknown-characters = Geschmäcker von Dorfbewohnern, die nicht in der Datenbank sind, wurden übersprungen: { $names }
+mod-item-ids = Geschmäcker aus Mods verwenden die Gegenstands-IDs des Spiels, etwa 72 für einen Diamanten.
+uninstall-vanilla = ‼ Die Figuren des Grundspiels können nicht entfernt werden.
+uninstall-usage = ‼ Bitte gib die eindeutige ID der Mod an, zum Beispiel: 'mod-entfernen Example.ClaireNPC'.
+uninstall-nothing = Tut mir leid, ich finde nichts von der Mod { $id } in der Datenbank!
+uninstall-done = 🗑 { $id } entfernt!
+uninstall-characters = Entfernte Figuren: { $count }
+uninstall-tastes = Entfernte Geschmäcker: { $count }
+
+## profil
+
+profile-usage = ‼ Bitte nutze 'profil list', 'profil create <name>', 'profil use <name>' oder 'profil delete <name>'.
+profile-list = 🗂 Profile:
+profile-invalid-name = ‼ Profilnamen dürfen nur Kleinbuchstaben, Ziffern und '_' enthalten, höchstens 32 Zeichen lang sein und nicht '{ $default }' heißen.
+profile-exists = Das Profil '{ $name }' gibt es schon!
+profile-not-found = Tut mir leid, ich kann das Profil '{ $name }' nicht finden!
+profile-not-found-create = Tut mir leid, ich kann das Profil '{ $name }' nicht finden! Lege es mit 'profil create { $name }' an.
+profile-switched = 🗂 Das Profil '{ $name }' ist jetzt aktiv! Alles, was du jetzt machst, betrifft nur dieses Profil.
+profile-delete-default = ‼ Das Standardprofil kann nicht gelöscht werden.
+profile-delete-active = ‼ Das Profil, das du gerade benutzt, kannst du nicht löschen. Wechsle zuerst mit 'profil use <name>' zu einem anderen.
+profile-delete-confirm = ‼ Damit werden alle Figuren, Spielstände und alles andere im Profil '{ $name }' gelöscht. Gib zur Bestätigung den Namen des Profils noch einmal ein:
+profile-deleted = 🗑 Das Profil '{ $name }' wurde gelöscht.
+
+## verbinden, familie und graph
+
+relate-usage = ‼ Bitte gib die Argumente in dieser Reihenfolge an: erste Figur, Art der Beziehung (parent, sibling, spouse, friend oder rival), zweite Figur.
+relate-example = Caroline ist zum Beispiel Abigails Mutter: 'verbinden caroline parent abigail'
+relate-invalid-kind = ‼ Als Beziehung verstehe ich nur 'parent', 'sibling', 'spouse', 'friend' oder 'rival'. Ich habe '{ $input }' bekommen.
+relate-self = ‼ Eine Figur kann nicht mit sich selbst verbunden sein!
+relate-done = ✅ Notiert: { $from } ist { $kind } von { $to }!
+relate-known = Dass { $from } { $kind } von { $to } ist, wusste ich schon.
+relate-error = { $from } und { $to } konnten nicht verbunden werden! Stell sicher, dass beide in der Datenbank sind.
+relationship-parent = Elternteil
+relationship-sibling = Geschwister
+relationship-spouse = Ehepartner
+relationship-friend = befreundet
+relationship-rival = Rivale
+family-usage = ‼ Bitte gib den Namen der Figur an, zum Beispiel: 'familie abigail'.
+family-title = Familie und Freunde von { $name }:
+family-none = Ich kenne noch niemanden, der mit { $name } verbunden ist. Füge jemanden mit 'verbinden' hinzu!
+family-child = 👶 Kind: { $name }
+family-parent = 👪 Elternteil: { $name }
+family-sibling = 🧑‍🤝‍🧑 Geschwister: { $name }
+family-spouse = 💍 Ehepartner: { $name }
+family-friend = 🤝 Freundschaft: { $name }
+family-rival = ⚔ Rivale: { $name }
+graph-usage = ‼ Bitte gib die Datei an, in die der Beziehungsgraph exportiert werden soll, zum Beispiel: 'graph --dot dorfbewohner.dot'.
+graph-example = Mit Graphviz wird daraus ein Bild: 'dot -Tpng dorfbewohner.dot -o dorfbewohner.png'
+graph-exported = 🕸 { $characters } Figuren und { $relationships } Beziehungen nach { $file } exportiert!
+graph-write-error = Der Graph konnte nicht nach { $file } geschrieben werden!
+
+## bericht
+
+report-invalid-format = ‼ Ich kann nur Berichte mit '--format html' oder '--format md' erstellen.
+report-usage = ‼ Bitte gib die Datei für den Bericht an, zum Beispiel: 'bericht --format html dorf.html'.
+report-template-hint = Mit '--template <datei>' kannst du deine eigene Vorlage verwenden. Sie kann { $placeholders } enthalten.
+report-template-error = Die Vorlage { $file } konnte nicht gelesen werden!
+report-title = Die Dorfbewohner von Stardew Valley
+report-heading-calendar = Geburtstagskalender
+report-heading-candidates = Heiratskandidaten
+report-heading-gifts = Geschenke
+report-heading-characters = Dorfbewohner nach Jahreszeit
+report-written = 📜 Ein { $format }-Bericht mit { $count } Figuren wurde nach { $file } geschrieben!
+report-write-error = Der Bericht konnte nicht nach { $file } geschrieben werden!
+report-no-candidates = Niemand kann geheiratet werden! 💔
+report-candidate = ❤ { $name } (Geburtstag: { $date })
+report-column-character = Figur
+report-column-best-gift = Lieblingsgeschenk
+report-column-loved-gifts = Geliebte Geschenke
+
+## romanze und nächstes-ereignis
+
+heart-event = { $hearts }♥ in { $location } ({ $time }): { $conditions }
+romance-usage = ‼ Bitte gib den Namen einer heiratbaren Figur an, zum Beispiel: 'romanze abigail'.
+romance-seen-hint = Um ein Herzereignis im aktiven Spielstand als gesehen zu markieren, füge 'seen' und seine Herzstufe an: 'romanze abigail seen 4'
+romance-title = 💘 Romanze mit { $name }
+romance-in-save = In { $save }: { $hearts } ({ $points } Punkte, { $status })
+romance-proposal = 💍 Für den Antrag: { $requirements }
+romance-spouse-room = 🛏 Zimmer nach der Hochzeit: { $room }
+romance-details-unknown = Ich kenne die Voraussetzungen für den Antrag und das Zimmer von { $name } noch nicht.
+romance-no-events = Ich kenne noch keine Herzereignisse.
+romance-events = Herzereignisse:
+romance-seen-usage = ‼ Bitte gib die Herzstufe des Ereignisses an, das du gesehen hast, zum Beispiel: 'romanze abigail seen 4'.
+romance-unknown-event = Ich kenne kein { $hearts }♥-Ereignis für { $name }!
+romance-marked-seen = ✔ { $name }s { $hearts }♥-Ereignis ist in { $save } als gesehen markiert!
+next-event-usage = ‼ Bitte gib den Namen einer heiratbaren Figur an, zum Beispiel: 'nächstes-ereignis abigail'.
+next-event-ready = ✨ Jetzt bereit! { $event }
+next-event-after-dating = 💐 Als Nächstes: { $event }
+next-event-date-first = Du musst zuerst mit { $name } zusammenkommen: Schenk ab { $hearts } Herzen einen Blumenstrauß.
+next-event-upcoming = ⏳ Als Nächstes: { $event }
+next-event-points-needed = Dafür brauchst du noch { $points } Freundschaftspunkte.
+next-event-none = Ich kenne noch keine Herzereignisse für { $name }.
+next-event-all-seen = Du hast alle Herzereignisse gesehen! 💕
+
+## zeitplan, wo und wer-ist-in
+
+time-invalid = ‼ Bitte gib eine richtige Uhrzeit wie '14:30' oder '1430' an. Ich habe '{ $input }' bekommen.
+time-out-of-range = ‼ Bitte gib eine Uhrzeit zwischen 6:00 und 26:00 (2 Uhr nachts) an, genau wie die Uhr im Spiel. Ich habe '{ $input }' bekommen.
+moment-day-missing = ‼ Bitte gib nach der Jahreszeit den Tag an, zum Beispiel: 'frühling 10'.
+moment-not-understood = ‼ '{ $input }' verstehe ich nicht. Bitte gib eine Jahreszeit und einen Tag, eine Uhrzeit und/oder das Wetter (sonnig oder regnerisch) an.
+moment-no-date = ‼ Bitte gib das Spieldatum an, zum Beispiel: 'frühling 10 14:00 regnerisch'.
+where-usage = ‼ Bitte gib den Namen der Figur an, optional gefolgt von Jahreszeit, Tag, Uhrzeit und Wetter.
+where-example = Zum Beispiel: 'wo abigail herbst 13 14:00 sonnig'
+where-found = 📍 Am { $date } ({ $weekday }, { $weather }) um { $time } ist { $name } in { $location }.
+where-unknown = Tut mir leid, ich kenne den Zeitplan von { $name } nicht! Füge ihn mit 'zeitplan' hinzu.
+who-is-at-usage = ‼ Bitte gib den Ort und die Uhrzeit an, optional gefolgt von Jahreszeit, Tag und Wetter.
+who-is-at-example = Zum Beispiel: 'wer-ist-in stardrop saloon 19:00 herbst 13'
+who-is-at-title = 📍 Um { $time } am { $date } ({ $weekday }, { $weather }):
+who-is-at-line = { $name } ist in { $location }
+who-is-at-nobody = Soweit ich weiß, ist dann niemand in { $location }.
+schedule-usage = ‼ Bitte gib die Argumente in dieser Reihenfolge an: Name, Jahreszeit, Wochentag, Wetter, Uhrzeit, Ort. Nutze 'any' für eine Jahreszeit, einen Wochentag oder ein Wetter, die egal sind.
+schedule-example = Leah verbringt zum Beispiel die Freitagabende im Saloon: 'zeitplan leah any friday any 19:00 stardrop saloon'
+schedule-invalid-weekday = ‼ Bitte gib einen Wochentag wie 'monday' oder 'any' an. Ich habe '{ $input }' bekommen.
+schedule-invalid-weather = ‼ Als Wetter verstehe ich nur 'sonnig', 'regnerisch' oder 'any'. Ich habe '{ $input }' bekommen.
+schedule-added = ✅ Notiert: ab { $time } ist { $name } in { $location }!
+schedule-error = Der Zeitplan von { $name } konnte nicht ergänzt werden!
+weather-sunny = sonnig
+weather-rainy = regnerisch
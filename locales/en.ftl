@@ -0,0 +1,496 @@
+## General
+
+prompt = Type your command here:
+command-unknown = ❓ Command does not exist.
+quitting = Quitting the program.
+try-it = Give it a try! :)
+argument-all = all
+value-true = true
+value-false = false
+
+## Commands
+
+command-add = add
+command-read = read
+command-change = change
+command-delete = delete
+command-alias = alias
+command-search = search
+command-note = note
+command-tag = tag
+command-untag = untag
+command-recommend = recommend
+command-inventory = inventory
+command-taste = taste
+command-save = save
+command-gift = gift
+command-talk = talk
+command-status = status
+command-export-calendar = export-calendar
+command-report = report
+command-relate = relate
+command-family = family
+command-graph = graph
+command-schedule = schedule
+command-where = where
+command-who-is-at = who-is-at
+command-festivals = festivals
+command-upcoming = upcoming
+command-romance = romance
+command-next-event = next-event
+command-profile = profile
+command-config = config
+command-health = health
+command-ping = ping
+command-migrate = migrate
+command-stats = stats
+command-import-save = import-save
+command-import-mod = import-mod
+command-uninstall-mod = uninstall-mod
+command-language = language
+command-translate = translate
+command-quit = quit
+
+## Seasons
+
+season-spring = Spring
+season-summer = Summer
+season-fall = Fall
+season-winter = Winter
+
+## Dates
+
+date = { $season } { $day }
+weekday-monday = Monday
+weekday-tuesday = Tuesday
+weekday-wednesday = Wednesday
+weekday-thursday = Thursday
+weekday-friday = Friday
+weekday-saturday = Saturday
+weekday-sunday = Sunday
+birthday-of = 🎂 { $name }'s birthday
+at-location = { $what } at { $location }
+
+## Characters
+
+character-added = { $name } was successfully added to the database! :)
+character-add-error = An error occured when adding character { $name }! { $error }
+character-birthday = { $name }' birthday: { $season } { $day }
+character-best-gift = { $name }'s favourite gift: { $gift }
+character-can-marry = { $name } can get married to the player! ❤
+character-cannot-marry = { $name } can NOT get married to the player! 💔
+character-from-mod = { $name } was added by the mod { $source }
+character-tags = 🏷 Tags: { $tags }
+character-not-found = Sorry, I can't find { $name } in the database!
+
+## read
+
+read-usage = Provide an argument, like 'all' to read all characters or 'Abigail' to read specific character.
+read-source-usage = ‼ Please provide 'vanilla' or the unique id of a mod, for example: 'read --source Example.ClaireNPC'.
+read-source-empty = Sorry, I can't find any characters from { $source } in the database!
+read-tag-empty = Sorry, I can't find any characters tagged '{ $tag }'!
+read-more = — { $count } shown. Press Enter for more, or type 'q' to stop. —
+
+## add
+
+add-usage = ‼ Please provide arguments in the following order: name, birthday season, birthday day, bachelor status, best gift. Right now, I see you only entered { $count } arguments, whereas I need 5.
+add-example = For example for bachelorette Abigail this would be: abigail fall 13 true amethyst
+
+## change
+
+change-usage = ‼ Please provide arguments in the following order: name of the character you want to change, name of the value you want to change, then the value you want to set it to. Right now, I see you only entered { $count } arguments, whereas I need 3.
+change-example = For example , if you want to change Abigail's birthday season, you need to write the following arguments: 'abigail birthday_season summer'.
+change-values = The following value names are available: name, birthday_season, birthday_day, is_bachelor, best_gift
+change-unknown-value = Couldn't recognize value name you typed!
+change-done = ✅ The change took place! Try the command 'read' with the character's name to check out your changes.
+change-not-found = Can't find character with the name '{ $name }' in the database!
+change-error = Error happened when trying to change character in database!
+
+## Input
+
+invalid-season = ‼ Please provide a proper argument for 'birthday_season' value. I received '{ $input }', meanwhile I can only interpret 'spring', 'summer', 'fall' or 'winter'.
+invalid-day = ‼ Please provide a proper number for the 'birthday_day' value. Remember that Stardew Valley Seasons only have 28 days! (I received { $input } from you.)
+invalid-bachelor = ‼ Please provide a proper argument for 'is_bachelor' value. I received '{ $input }', meanwhile I can only interpret 'true' or 'false'.
+
+## language and translate
+
+language-current = 🌍 The language is { $locale }.
+language-available = Available languages: { $locales }
+language-switched = 🌍 Switched the language to { $locale }!
+language-unknown = ‼ I don't know the language '{ $locale }'. Available languages: { $locales }
+translate-usage = ‼ Please provide 'character' or 'item', its name (with _ instead of spaces), the language and the translated name.
+translate-example = For example: 'translate item Goat_Cheese de Ziegenkäse'. Leave out the language and name to list the translations.
+translate-list = 🌍 Translations of { $name }:
+translate-none = There are none yet.
+translate-saved = ✅ { $name } is called { $display } in { $locale } now!
+
+## Finding characters
+
+lookup-ambiguous = 🔎 There is more than one character that could be '{ $input }':
+lookup-pick = Type the number of the one you mean, or anything else to cancel:
+lookup-cancelled = Okay, I didn't do anything.
+
+## delete and alias
+
+delete-usage = ‼ Please provide the name of the character you want to delete, for example: 'delete sebastian'.
+delete-confirm = ‼ This deletes { $name } along with their gift tastes, friendships, schedules and everything else. Type the name again to confirm:
+delete-cancelled = Okay, I didn't delete anything.
+delete-done = 🗑 { $name } was deleted from the database.
+alias-usage = ‼ Please provide the name of the character and the nickname you want to use for them.
+alias-example = For example: 'alias abigail abby'. Leave out the nickname to see all of them.
+alias-none = { $name } has no nicknames yet.
+alias-list = { $name } is also known as: { $aliases }
+alias-added = ✅ You can call { $name } '{ $alias }' now!
+alias-error = Couldn't add the nickname '{ $alias }'! Maybe it already belongs to someone else.
+
+## search
+
+search-usage = ‼ Please provide what you are looking for, with at least one word of { $length } letters or more.
+search-example = For example: 'search gem' or 'search saloon'.
+search-results = 🔎 Characters matching '{ $text }':
+search-none = Sorry, I couldn't find anything matching '{ $text }'!
+search-more = … and { $count } more.
+search-field-name = name
+search-field-best-gift = favourite gift
+search-field-gift-taste = gift taste
+search-field-alias = nickname
+search-field-note = note
+search-field-tag = tag
+search-field-schedule = schedule
+search-field-heart-event = heart event
+search-field-spouse-room = spouse room
+
+## note, tag and untag
+
+note-usage = ‼ Please provide the name of the character and your note. Leave out the note to see all notes about them.
+note-example = For example: 'note leah give on Sundays, at the saloon after 5pm'
+note-list = 📝 Notes about { $name }:
+note-none = There are none yet.
+note-too-long = ‼ Notes can be up to { $length } characters long.
+note-added = ✅ Noted down for { $name }!
+tag-usage = ‼ Please provide the name of the character and one or more tags.
+tag-example = For example: 'tag abigail gems adventurous'. Find everyone with a tag using 'read tag=gems'.
+tag-added = 🏷 { $name } is tagged with: { $tags }
+tag-known = { $name } already has these tags.
+untag-usage = ‼ Please provide the name of the character and the tags to remove, for example: 'untag abigail gems'.
+untag-none = { $name } doesn't have any of these tags.
+untag-removed = 🏷 Removed from { $name }: { $tags }
+
+## config
+
+config-usage = ‼ Use 'config show' to see the settings the application is running with.
+config-missing = The setting '{ $key }' is missing. Set { $env } in your .env file, pass { $flag } or add it to the config file.
+config-invalid = The setting '{ $key }' can't be '{ $value }': { $reason }
+config-file-error = The config file { $path } can't be read: { $reason }
+config-unknown-flag = I don't know the option '{ $flag }'. Use { $flags } or --config <file>.
+config-needs-value = it needs a value
+config-unknown-protocol = only mysql, mariadb and postgres are supported
+config-no-such-file = there is no such file
+config-mysql-only = it only works with MySQL, Postgres always uses UTF-8
+config-no-connections = at least one connection is needed
+config-settings-file = ⚙ Settings (config file: { $path }):
+config-settings-no-file = ⚙ Settings (no config file):
+config-setting = { $key } = { $value } ({ $origin })
+config-not-set = { $key } is not set
+config-origin-default = default
+config-origin-file = config file
+config-origin-env = environment
+config-origin-flag = command line
+
+## health and connection
+
+health-failed = ‼ The database can't be reached right now:
+health-ok = 🩺 The database is reachable, the ping took { $milliseconds } ms.
+health-server = Server version: { $version }
+health-database = Database: { $name } (profile '{ $profile }')
+health-connections = Connections: { $open } open, { $idle } idle, at most { $max }
+connection-retry = ⏳ The database isn't reachable yet, trying again in { $seconds } s ({ $attempt }/{ $retries })…
+connection-lost = ‼ The connection to the database was lost, reconnecting…
+connection-restored = ✅ Reconnected! Trying your command once more.
+connection-restored-not-retried = ✅ Reconnected! Your command changes data and might already have been carried out, so it isn't run again. Please check and repeat it if needed.
+connection-unavailable = ‼ The database is still unreachable. Your command wasn't carried out, please try again later.
+
+## migrate
+
+migrate-usage = ‼ Please use 'migrate status', 'migrate up' or 'migrate down <version>'. Add '--dry-run' to see what would happen without changing anything.
+migrate-example = For example: 'migrate down 12 --dry-run'
+migrate-invalid-version = ‼ Please provide the version to go back to as a number, like '12'. I received '{ $input }'.
+migrate-status = 🗄 Migrations of the profile '{ $profile }' (↕ can be reverted):
+migrate-state-applied = applied { $date }
+migrate-state-pending = pending
+migrate-state-failed = ‼ failed halfway
+migrate-state-changed = ‼ changed after it was applied
+migrate-state-unknown = ‼ applied by a newer version of the application
+migrate-up-to-date = ✅ The database is up to date.
+migrate-pending = { $count } migration(s) pending. Apply them with 'migrate up'.
+migrate-pending-startup = ‼ The database is missing { $count } migration(s) and DB_AUTO_MIGRATE is off. Commands may fail until you run 'migrate up'.
+migrate-ahead = The database was migrated to version { $version } by a newer version of the application, but this one only knows versions up to { $latest }. Please update the application.
+migrate-failed = Migration { $version } failed halfway. Please fix the database by hand and delete its row from _sqlx_migrations.
+migrate-changed = Migration { $version } was changed after it had been applied. Please restore the original file.
+migrate-dry-run-up = These { $count } migration(s) would be applied:
+migrate-up-done = ✅ Applied { $count } migration(s), the database is now at version { $version }.
+migrate-irreversible = ‼ Migration { $version } can't be reverted, there is no .down.sql for it.
+migrate-down-nothing = The database isn't past version { $version }, there is nothing to revert.
+migrate-dry-run-down = These { $count } migration(s) would be reverted to get back to version { $version }:
+migrate-down-confirm = ‼ This drops tables and columns of the profile '{ $profile }' along with everything in them. Type { $version } again to confirm:
+migrate-down-cancelled = Okay, I didn't change anything.
+migrate-down-done = ✅ Reverted { $count } migration(s), the database is now at version { $version }.
+migrate-error = ‼ The migration failed:
+migrator-needed = ‼ The database user may only read and write rows. Set DB_MIGRATOR_USER and DB_MIGRATOR_PASSWORD to a user that may create, change and drop tables and databases, such as the one setup_db creates.
+
+stats-cache-off = 📊 The cache is off. Set cache.ttl (CACHE_TTL) to a number of seconds to turn it on.
+stats-cache = 📊 Cached characters: { $entries }, each kept for { $ttl } s
+stats-lookups = Hits: { $hits }, misses: { $misses } ({ $rate } % hits)
+stats-invalidations = Dropped because they changed: { $count }
+
+## export-calendar
+
+calendar-usage = ‼ Please provide the file to export to, optionally followed by how in-game days map to real dates:
+calendar-usage-year = 'export-calendar birthdays.ics' or 'export-calendar birthdays.ics year 03-01' spreads the in-game year over a real year, with spring starting on the given day.
+calendar-usage-play = 'export-calendar birthdays.ics play 2024-03-20 spring 1' maps one in-game day to one real day, starting from the given dates. Without an in-game date, the active save's date is used.
+calendar-invalid-spring-start = ‼ Please provide the day spring starts on as MM-DD, for example: '03-20'.
+calendar-invalid-real-date = ‼ Please provide the real date as YYYY-MM-DD, for example: '2024-03-20'. I received '{ $input }'.
+calendar-name = Stardew Valley birthdays
+calendar-can-marry = can get married to the player
+calendar-cannot-marry = can NOT get married to the player
+calendar-loved-gifts = Loved gifts: { $gifts }
+calendar-shops-closed = The shops are closed!
+calendar-exported = 📅 Exported { $birthdays } birthdays and { $festivals } festivals to { $file }! You can import it in any calendar app.
+calendar-write-error = Couldn't write the calendar to { $file }!
+
+## festivals and upcoming
+
+festival-conflict = ⚠ { $name }'s birthday falls on the { $festival }! The shops are closed, so get the gift beforehand and find them at { $location }.
+festivals-title = 🎪 Festivals:
+festivals-shops-closed = (shops are closed)
+festivals-add-usage = ‼ Please provide the festival's name, season, first and last day, then 'at' and the location.
+festivals-add-example = For example: 'festivals add Harvest Picnic fall 20 21 at cindersap forest'
+festivals-add-days-usage = ‼ Please provide the festival's name, season, first and last day, for example: 'festivals add Harvest Picnic fall 20 21 at cindersap forest'
+festivals-end-before-start = ‼ The last day of the festival can't be before the first one!
+festivals-added = ✅ { $name } was added to the calendar!
+festivals-add-error = Couldn't add the festival { $name }!
+upcoming-no-date = ‼ Please provide the in-game date to start from, for example: 'upcoming spring 10' or 'upcoming spring 10 14' for the next 14 days.
+upcoming-invalid-days = ‼ Please provide a number of days between 1 and { $max }.
+upcoming-title = 📅 The next { $days } days, starting on { $date }:
+upcoming-nothing = Nothing special is coming up. 🌾
+
+## save, gift, talk and status
+
+save-usage = ‼ Please use 'save create <farm_name>', 'save use <farm_name>', 'save list' or 'save date <season> <day> <year>'.
+save-none-active = ‼ There is no active save yet! Create one with 'save create <farm_name>' first.
+save-date-hint = If you create a save with 'save create <farm_name>', I will use its current date instead.
+save-list-empty = There are no saves yet! Create one with 'save create <farm_name>'.
+save-list-title = 🌱 Your saves:
+save-line = { $name } - { $date }, year { $year }
+save-created = 🌱 Created the save '{ $name }' and switched to it! It starts on Spring 1, year 1.
+save-create-error = Couldn't create the save '{ $name }'!
+save-switched = 🌱 Switched to the save '{ $name }'!
+save-not-found = Sorry, I can't find the save '{ $name }'!
+save-invalid-year = ‼ Please provide a proper year. I received '{ $input }'.
+save-date-changed = 📅 It is now { $date }, year { $year } on { $name }.
+friendship-points = { $name }: { $hearts } ({ $points } points)
+gift-usage = ‼ Please provide the name of the character and the item you gave them, for example: 'gift abigail amethyst'.
+gift-already-today = { $name } already got a gift today! Villagers only accept one gift per day.
+gift-week-limit = { $name } already got { $count } gifts this week! Try again on the next Sunday or on their birthday.
+gift-given = 🎁 You gave { $item } to { $name }. { $reaction } ({ $points } friendship)
+gift-given-birthday = 🎁 You gave { $item } to { $name }. { $reaction } ({ $points } friendship, birthday bonus!)
+talk-usage = ‼ Please provide the name of the character you talked to, for example: 'talk abigail'.
+talk-already-today = You already talked to { $name } today! Talking only counts once per day.
+talk-done = 💬 You talked to { $name }. (+{ $points } friendship)
+status-line = { $name }: { $hearts } ({ $points } points, { $status }) - gifts this week: { $gifts }/{ $max }
+status-talked-today = talked today 💬
+status-usage = ‼ Please provide the name of the character and their new status (friend, dating or married), for example: 'status abigail dating'.
+status-invalid = ‼ I can only interpret 'friend', 'dating' or 'married' as a status. I received '{ $input }'.
+status-changed = ✅ { $name } is now your { $role }!
+status-friend = friend
+status-dating = dating
+status-married = married
+status-role-friend = friend
+status-role-dating = partner
+status-role-married = spouse
+reaction-love = They love it!
+reaction-like = They like it!
+reaction-neutral = They don't mind it.
+reaction-dislike = They dislike it!
+reaction-hate = They hate it!
+
+## recommend, inventory and taste
+
+recommend-no-date = ‼ Please provide the in-game date you want gift recommendations for, for example: 'recommend fall 13'.
+recommend-title = 🎁 Gift plan for { $date }:
+recommend-birthday-today = 🎂 Today is { $name }'s birthday!
+recommend-birthday-soon = 🎂 { $name }'s birthday is coming up on { $date } (in { $days } days).
+recommend-inventory-empty = Your inventory is empty! Add items with 'inventory add <item> <quantity>' to get recommendations.
+recommend-nothing = Nobody would be happy about anything in your inventory right now. 😔
+recommend-give = ➡ Give { $item } to { $name } ({ $taste }, +{ $points } friendship)
+recommend-give-birthday = ➡ Give { $item } to { $name } ({ $taste }, +{ $points } friendship, birthday bonus!)
+recommend-keep = ⏳ Keep { $item } for { $name }'s birthday on { $date } ({ $taste }, +{ $points } friendship)
+recommend-total = Total friendship gain: { $points } points
+inventory-empty = 🎒 Your inventory is empty!
+inventory-title = 🎒 Your inventory:
+inventory-usage = ‼ Please use 'inventory', 'inventory add <item> [quantity]' or 'inventory remove <item> [quantity]'.
+inventory-example = For example: 'inventory add pizza 3'
+inventory-added = 🎒 Added { $item } x{ $quantity } to your inventory!
+inventory-removed = 🎒 Removed { $item } x{ $quantity } from your inventory!
+inventory-missing = There is no { $item } in your inventory!
+inventory-invalid-quantity = ‼ Please provide a proper quantity. I received '{ $input }'.
+taste-usage = ‼ Please provide arguments in the following order: name of the character, their taste (love, like, neutral, dislike or hate), then the item.
+taste-example = For example: 'taste abigail love pumpkin'
+taste-invalid = ‼ I can only interpret 'love', 'like', 'neutral', 'dislike' or 'hate' as a taste. I received '{ $input }'.
+taste-saved = ✅ Noted: { $name }'s taste for { $item } is '{ $taste }'!
+taste-error = Couldn't save the gift taste for '{ $name }'!
+taste-love = love
+taste-like = like
+taste-neutral = neutral
+taste-dislike = dislike
+taste-hate = hate
+
+## import-save
+
+import-usage = ‼ Please provide the path to a Stardew Valley save file or save folder, for example: 'import-save ~/.config/StardewValley/Saves/Sunny_123456789'.
+import-invalid-xml = This is not valid XML: { $error }
+import-not-a-save = This is not a Stardew Valley save file, it should start with <SaveGame>.
+import-missing-field = The save file doesn't contain a proper { $field }.
+import-folder-without-name = The save folder has no name.
+import-read-error = Couldn't read the save file { $path }!
+import-error = Couldn't import { $path }!
+import-not-activated = The imported save could not be activated.
+import-done = 📥 Imported { $save } and switched to it!
+import-friendships = Friendships imported: { $count }
+import-unknown-characters = These villagers aren't in the database, so I skipped them: { $names }
+import-unknown-hint = Add them with the 'add' command and import again to track them too.
+
+## import-mod and uninstall-mod
+
+mod-usage = ‼ Please provide the folder of a Content Patcher content pack, the one with the manifest.json and content.json, for example: 'import-mod Mods/[CP] Claire'.
+mod-read-error = Couldn't read { $path }: { $error }
+mod-invalid-json = { $path } is not valid JSON: { $error }
+mod-missing-unique-id = The manifest.json doesn't contain a UniqueID.
+mod-invalid-id = '{ $id }' can't be used as a mod id.
+mod-missing-changes = The content.json doesn't contain a list of Changes.
+mod-import-error = Couldn't import the mod in { $path }!
+mod-added-by = { $name } (already added by { $source })
+mod-imported = 🧩 Imported { $name } ({ $id })
+mod-characters-added = Characters added: { $names }
+mod-none = none
+mod-tastes-added = Gift tastes added: { $count }
+mod-conflicts = These villagers already exist, so I skipped them: { $names }
+mod-incomplete = These villagers have no birthday in the mod, so I skipped them: { $names }
+mod-unknown-characters = Gift tastes for villagers that aren't in the database were skipped: { $names }
+mod-item-ids = Gift tastes from mods use the game's item ids, like 72 for a diamond.
+uninstall-vanilla = ‼ The vanilla characters can't be uninstalled.
+uninstall-usage = ‼ Please provide the unique id of the mod, for example: 'uninstall-mod Example.ClaireNPC'.
+uninstall-nothing = Sorry, I can't find anything from the mod { $id } in the database!
+uninstall-done = 🗑 Uninstalled { $id }!
+uninstall-characters = Characters removed: { $count }
+uninstall-tastes = Gift tastes removed: { $count }
+
+## profile
+
+profile-usage = ‼ Please use 'profile list', 'profile create <name>', 'profile use <name>' or 'profile delete <name>'.
+profile-list = 🗂 Profiles:
+profile-invalid-name = ‼ Profile names can only contain lowercase letters, numbers and '_', can be up to 32 characters long and can't be '{ $default }'.
+profile-exists = The profile '{ $name }' already exists!
+profile-not-found = Sorry, I can't find the profile '{ $name }'!
+profile-not-found-create = Sorry, I can't find the profile '{ $name }'! Create it with 'profile create { $name }'.
+profile-switched = 🗂 Switched to the profile '{ $name }'! Everything you do now only affects this profile.
+profile-delete-default = ‼ The default profile can't be deleted.
+profile-delete-active = ‼ You can't delete the profile you are using. Switch to another one with 'profile use <name>' first.
+profile-delete-confirm = ‼ This deletes all characters, saves and everything else in the profile '{ $name }'. Type the profile's name again to confirm:
+profile-deleted = 🗑 The profile '{ $name }' was deleted.
+
+## relate, family and graph
+
+relate-usage = ‼ Please provide arguments in the following order: first character, kind of relationship (parent, sibling, spouse, friend or rival), second character.
+relate-example = For example, Caroline is Abigail's mother: 'relate caroline parent abigail'
+relate-invalid-kind = ‼ I can only interpret 'parent', 'sibling', 'spouse', 'friend' or 'rival' as a relationship. I received '{ $input }'.
+relate-self = ‼ A character can't be related to themselves!
+relate-done = ✅ Noted: { $from } is { $to }'s { $kind }!
+relate-known = I already knew that { $from } is { $to }'s { $kind }.
+relate-error = Couldn't relate { $from } and { $to }! Make sure both are in the database.
+relationship-parent = parent
+relationship-sibling = sibling
+relationship-spouse = spouse
+relationship-friend = friend
+relationship-rival = rival
+family-usage = ‼ Please provide the name of the character, for example: 'family abigail'.
+family-title = { $name }'s family and friends:
+family-none = I don't know anyone related to { $name } yet. Add someone with 'relate'!
+family-child = 👶 Child: { $name }
+family-parent = 👪 Parent: { $name }
+family-sibling = 🧑‍🤝‍🧑 Sibling: { $name }
+family-spouse = 💍 Spouse: { $name }
+family-friend = 🤝 Friend: { $name }
+family-rival = ⚔ Rival: { $name }
+graph-usage = ‼ Please provide the file to export the relationship graph to, for example: 'graph --dot villagers.dot'.
+graph-example = You can turn it into a picture with Graphviz: 'dot -Tpng villagers.dot -o villagers.png'
+graph-exported = 🕸 Exported { $characters } characters and { $relationships } relationships to { $file }!
+graph-write-error = Couldn't write the graph to { $file }!
+
+## report
+
+report-invalid-format = ‼ I can only create reports with '--format html' or '--format md'.
+report-usage = ‼ Please provide the file to write the report to, for example: 'report --format html roster.html'.
+report-template-hint = You can use your own template with '--template <file>'. It can contain { $placeholders }.
+report-template-error = Couldn't read the template { $file }!
+report-title = Stardew Valley roster
+report-heading-calendar = Birthday calendar
+report-heading-candidates = Marriage candidates
+report-heading-gifts = Gifts
+report-heading-characters = Characters by season
+report-written = 📜 Wrote a { $format } report with { $count } characters to { $file }!
+report-write-error = Couldn't write the report to { $file }!
+report-no-candidates = Nobody can get married to the player! 💔
+report-candidate = ❤ { $name } (birthday: { $date })
+report-column-character = Character
+report-column-best-gift = Favourite gift
+report-column-loved-gifts = Loved gifts
+
+## romance and next-event
+
+heart-event = { $hearts }♥ at { $location } ({ $time }): { $conditions }
+romance-usage = ‼ Please provide the name of a marriage candidate, for example: 'romance abigail'.
+romance-seen-hint = To mark a heart event as seen in the active save, add 'seen' and its heart level: 'romance abigail seen 4'
+romance-title = 💘 Romancing { $name }
+romance-in-save = In { $save }: { $hearts } ({ $points } points, { $status })
+romance-proposal = 💍 To propose: { $requirements }
+romance-spouse-room = 🛏 Spouse room: { $room }
+romance-details-unknown = I don't know the proposal requirements or spouse room for { $name } yet.
+romance-no-events = I don't know any heart events yet.
+romance-events = Heart events:
+romance-seen-usage = ‼ Please provide the heart level of the event you saw, for example: 'romance abigail seen 4'.
+romance-unknown-event = I don't know a { $hearts }♥ event for { $name }!
+romance-marked-seen = ✔ Marked { $name }'s { $hearts }♥ event as seen in { $save }!
+next-event-usage = ‼ Please provide the name of a marriage candidate, for example: 'next-event abigail'.
+next-event-ready = ✨ Ready now! { $event }
+next-event-after-dating = 💐 Next up: { $event }
+next-event-date-first = You need to date { $name } first: give them a bouquet at { $hearts } hearts or more.
+next-event-upcoming = ⏳ Next up: { $event }
+next-event-points-needed = You need { $points } more friendship points to get there.
+next-event-none = I don't know any heart events for { $name } yet.
+next-event-all-seen = You have seen all the heart events! 💕
+
+## schedule, where and who-is-at
+
+time-invalid = ‼ Please provide a proper time like '14:30' or '1430'. I received '{ $input }'.
+time-out-of-range = ‼ Please provide a time between 6:00 and 26:00 (2am), just like the in-game clock. I received '{ $input }'.
+moment-day-missing = ‼ Please provide the day after the season, for example: 'spring 10'.
+moment-not-understood = ‼ I don't understand '{ $input }'. Please provide a season and day, a time and/or the weather (sunny or rainy).
+moment-no-date = ‼ Please provide the in-game date, for example: 'spring 10 14:00 rainy'.
+where-usage = ‼ Please provide the name of the character, optionally followed by the season, day, time and weather.
+where-example = For example: 'where abigail fall 13 14:00 sunny'
+where-found = 📍 On { $date } ({ $weekday }, { $weather }) at { $time }, { $name } is at { $location }.
+where-unknown = Sorry, I don't know { $name }'s schedule! Add it with the 'schedule' command.
+who-is-at-usage = ‼ Please provide the location and the time, optionally followed by the season, day and weather.
+who-is-at-example = For example: 'who-is-at stardrop saloon 19:00 fall 13'
+who-is-at-title = 📍 At { $time } on { $date } ({ $weekday }, { $weather }):
+who-is-at-line = { $name } is at { $location }
+who-is-at-nobody = Nobody I know of is at { $location } right then.
+schedule-usage = ‼ Please provide arguments in the following order: name, season, weekday, weather, time, location. Use 'any' for a season, weekday or weather that doesn't matter.
+schedule-example = For example, Leah spends Friday evenings in the saloon: 'schedule leah any friday any 19:00 stardrop saloon'
+schedule-invalid-weekday = ‼ Please provide a weekday like 'monday' or 'any'. I received '{ $input }'.
+schedule-invalid-weather = ‼ I can only interpret 'sunny', 'rainy' or 'any' as the weather. I received '{ $input }'.
+schedule-added = ✅ Noted: from { $time } on, { $name } is at { $location }!
+schedule-error = Couldn't add to { $name }'s schedule!
+weather-sunny = sunny
+weather-rainy = rainy
@@ -0,0 +1,496 @@
+## Général
+
+prompt = Tapez votre commande ici :
+command-unknown = ❓ Cette commande n'existe pas.
+quitting = Fermeture du programme.
+try-it = Essayez ! :)
+argument-all = tout
+value-true = oui
+value-false = non
+
+## Commandes
+
+command-add = ajouter
+command-read = lire
+command-change = modifier
+command-recommend = recommander
+command-inventory = inventaire
+command-taste = goût
+command-save = partie
+command-gift = offrir
+command-talk = parler
+command-status = statut
+command-export-calendar = exporter-calendrier
+command-report = rapport
+command-relate = relier
+command-family = famille
+command-graph = graphe
+command-schedule = horaire
+command-where = où
+command-who-is-at = qui-est-à
+command-festivals = festivals
+command-upcoming = prochainement
+command-romance = romance
+command-next-event = prochain-événement
+command-profile = profil
+command-import-save = importer-partie
+command-import-mod = importer-mod
+command-uninstall-mod = désinstaller-mod
+command-language = langue
+command-translate = traduire
+command-quit = quitter
+
+## Saisons
+
+season-spring = Printemps
+season-summer = Été
+season-fall = Automne
+season-winter = Hiver
+
+## Dates
+
+date = { $day } { $season }
+weekday-monday = lundi
+weekday-tuesday = mardi
+weekday-wednesday = mercredi
+weekday-thursday = jeudi
+weekday-friday = vendredi
+weekday-saturday = samedi
+weekday-sunday = dimanche
+birthday-of = 🎂 Anniversaire de { $name }
+at-location = { $what } à { $location }
+
+## Personnages
+
+character-added = { $name } a bien été ajouté à la base de données ! :)
+character-add-error = Une erreur s'est produite en ajoutant { $name } ! { $error }
+character-birthday = Anniversaire de { $name } : { $day } { $season }
+character-best-gift = Cadeau préféré de { $name } : { $gift }
+character-can-marry = { $name } peut épouser le joueur ! ❤
+character-cannot-marry = { $name } ne peut PAS épouser le joueur ! 💔
+character-from-mod = { $name } vient du mod { $source }
+character-tags = 🏷 Étiquettes : { $tags }
+character-not-found = Désolé, je ne trouve pas { $name } dans la base de données !
+
+## lire
+
+read-usage = Donnez un argument, comme 'tout' pour lire tous les personnages ou 'Abigail' pour un personnage précis.
+read-source-usage = ‼ Donnez 'vanilla' ou l'identifiant unique d'un mod, par exemple : 'lire --source Example.ClaireNPC'.
+read-source-empty = Désolé, je ne trouve aucun personnage de { $source } dans la base de données !
+read-tag-empty = Désolé, je ne trouve aucun personnage avec l'étiquette '{ $tag }' !
+read-more = — { $count } affichés. Appuyez sur Entrée pour la suite, ou tapez 'q' pour arrêter. —
+
+## ajouter
+
+add-usage = ‼ Donnez les arguments dans cet ordre : nom, saison d'anniversaire, jour d'anniversaire, célibataire ou non, cadeau préféré. Vous n'avez donné que { $count } arguments, il m'en faut 5.
+add-example = Pour Abigail, ce serait par exemple : abigail automne 13 oui améthyste
+
+## modifier
+
+change-usage = ‼ Donnez les arguments dans cet ordre : le nom du personnage, le nom de la valeur à modifier, puis la nouvelle valeur. Vous n'avez donné que { $count } arguments, il m'en faut 3.
+change-example = Par exemple, pour changer la saison d'anniversaire d'Abigail : 'abigail birthday_season été'.
+change-values = Les valeurs disponibles sont : name, birthday_season, birthday_day, is_bachelor, best_gift
+change-unknown-value = Je ne reconnais pas cette valeur !
+change-done = ✅ La modification est faite ! Vérifiez-la avec 'lire' et le nom du personnage.
+change-not-found = Je ne trouve pas de personnage nommé '{ $name }' dans la base de données !
+change-error = Une erreur s'est produite en modifiant le personnage dans la base de données !
+
+## Saisie
+
+invalid-season = ‼ Donnez une saison valide. J'ai reçu '{ $input }', mais je ne comprends que 'printemps', 'été', 'automne' ou 'hiver'.
+invalid-day = ‼ Donnez un jour valide. N'oubliez pas que les saisons de Stardew Valley n'ont que 28 jours ! (J'ai reçu { $input }.)
+invalid-bachelor = ‼ Indiquez si le personnage est célibataire. J'ai reçu '{ $input }', mais je ne comprends que 'oui' ou 'non'.
+
+## langue et traduire
+
+language-current = 🌍 La langue est { $locale }.
+language-available = Langues disponibles : { $locales }
+language-switched = 🌍 La langue est maintenant { $locale } !
+language-unknown = ‼ Je ne connais pas la langue '{ $locale }'. Langues disponibles : { $locales }
+translate-usage = ‼ Donnez 'character' ou 'item', son nom (avec _ au lieu des espaces), la langue et le nom traduit.
+translate-example = Par exemple : 'traduire item Goat_Cheese fr Fromage de chèvre'. Sans langue ni nom, les traductions sont listées.
+translate-list = 🌍 Traductions de { $name } :
+translate-none = Il n'y en a pas encore.
+translate-saved = ✅ { $name } s'appelle maintenant { $display } en { $locale } !
+
+## Trouver les personnages
+
+lookup-ambiguous = 🔎 Plusieurs personnages pourraient être '{ $input }' :
+lookup-pick = Tapez le numéro du bon, ou autre chose pour annuler :
+lookup-cancelled = D'accord, je n'ai rien fait.
+
+## supprimer et surnom
+
+command-delete = supprimer
+command-alias = surnom
+delete-usage = ‼ Donnez le nom du personnage à supprimer, par exemple : 'supprimer sebastian'.
+delete-confirm = ‼ Cela supprime { $name } avec ses goûts, amitiés, horaires et tout le reste. Tapez à nouveau le nom pour confirmer :
+delete-cancelled = D'accord, je n'ai rien supprimé.
+delete-done = 🗑 { $name } a été supprimé de la base de données.
+alias-usage = ‼ Donnez le nom du personnage et le surnom que vous voulez utiliser.
+alias-example = Par exemple : 'surnom abigail abby'. Sans surnom, ils sont tous listés.
+alias-none = { $name } n'a pas encore de surnom.
+alias-list = { $name } est aussi appelé : { $aliases }
+alias-added = ✅ Vous pouvez appeler { $name } '{ $alias }' maintenant !
+alias-error = Impossible d'ajouter le surnom '{ $alias }' ! Il appartient peut-être déjà à quelqu'un d'autre.
+
+## chercher
+
+command-search = chercher
+search-usage = ‼ Indiquez ce que vous cherchez, avec au moins un mot de { $length } lettres ou plus.
+search-example = Par exemple : 'chercher gem' ou 'chercher saloon'.
+search-results = 🔎 Personnages correspondant à '{ $text }' :
+search-none = Désolé, je n'ai rien trouvé pour '{ $text }' !
+search-more = … et { $count } de plus.
+search-field-name = nom
+search-field-best-gift = cadeau préféré
+search-field-gift-taste = goût
+search-field-alias = surnom
+search-field-note = note
+search-field-tag = étiquette
+search-field-schedule = horaire
+search-field-heart-event = événement de cœur
+search-field-spouse-room = chambre d'époux
+
+## note, étiqueter et désétiqueter
+
+command-note = note
+command-tag = étiqueter
+command-untag = désétiqueter
+note-usage = ‼ Donnez le nom du personnage et votre note. Sans note, toutes ses notes sont affichées.
+note-example = Par exemple : 'note leah offrir le dimanche, au saloon après 17h'
+note-list = 📝 Notes sur { $name } :
+note-none = Il n'y en a pas encore.
+note-too-long = ‼ Les notes peuvent faire jusqu'à { $length } caractères.
+note-added = ✅ Noté pour { $name } !
+tag-usage = ‼ Donnez le nom du personnage et une ou plusieurs étiquettes.
+tag-example = Par exemple : 'étiqueter abigail gemmes aventureuse'. Trouvez tous ceux qui ont une étiquette avec 'lire tag=gemmes'.
+tag-added = 🏷 Étiquettes de { $name } : { $tags }
+tag-known = { $name } a déjà ces étiquettes.
+untag-usage = ‼ Donnez le nom du personnage et les étiquettes à retirer, par exemple : 'désétiqueter abigail gemmes'.
+untag-none = { $name } n'a aucune de ces étiquettes.
+untag-removed = 🏷 Retiré de { $name } : { $tags }
+
+## configuration
+
+command-config = configuration
+config-usage = ‼ Utilisez 'configuration show' pour voir les réglages avec lesquels le programme tourne.
+config-missing = Le réglage '{ $key }' manque. Définissez { $env } dans votre fichier .env, passez { $flag } ou ajoutez-le au fichier de configuration.
+config-invalid = Le réglage '{ $key }' ne peut pas valoir '{ $value }' : { $reason }
+config-file-error = Le fichier de configuration { $path } est illisible : { $reason }
+config-unknown-flag = Je ne connais pas l'option '{ $flag }'. Utilisez { $flags } ou --config <fichier>.
+config-needs-value = il faut lui donner une valeur
+config-unknown-protocol = seuls mysql, mariadb et postgres sont pris en charge
+config-no-such-file = ce fichier n'existe pas
+config-mysql-only = cela ne marche qu'avec MySQL, Postgres utilise toujours l'UTF-8
+config-no-connections = il faut au moins une connexion
+config-settings-file = ⚙ Réglages (fichier de configuration : { $path }) :
+config-settings-no-file = ⚙ Réglages (pas de fichier de configuration) :
+config-setting = { $key } = { $value } ({ $origin })
+config-not-set = { $key } n'est pas défini
+config-origin-default = par défaut
+config-origin-file = fichier de configuration
+config-origin-env = environnement
+config-origin-flag = ligne de commande
+
+## santé et connexion
+
+command-health = santé
+command-ping = ping
+health-failed = ‼ La base de données est injoignable pour le moment :
+health-ok = 🩺 La base de données est joignable, le ping a pris { $milliseconds } ms.
+health-server = Version du serveur : { $version }
+health-database = Base de données : { $name } (profil '{ $profile }')
+health-connections = Connexions : { $open } ouvertes, { $idle } inactives, au plus { $max }
+connection-retry = ⏳ La base de données n'est pas encore joignable, nouvel essai dans { $seconds } s ({ $attempt }/{ $retries })…
+connection-lost = ‼ La connexion à la base de données a été perdue, reconnexion…
+connection-restored = ✅ Reconnecté ! Votre commande est relancée.
+connection-restored-not-retried = ✅ Reconnecté ! Votre commande modifie des données et a peut-être déjà été exécutée, elle n'est donc pas relancée. Vérifiez et relancez-la si nécessaire.
+connection-unavailable = ‼ La base de données reste injoignable. Votre commande n'a pas été exécutée, réessayez plus tard.
+
+## migrer
+
+command-migrate = migrer
+migrate-usage = ‼ Utilisez 'migrer status', 'migrer up' ou 'migrer down <version>'. Ajoutez '--dry-run' pour voir ce qui se passerait sans rien modifier.
+migrate-example = Par exemple : 'migrer down 12 --dry-run'
+migrate-invalid-version = ‼ Donnez la version à laquelle revenir sous forme de nombre, comme '12'. J'ai reçu '{ $input }'.
+migrate-status = 🗄 Migrations du profil '{ $profile }' (↕ réversible) :
+migrate-state-applied = appliquée le { $date }
+migrate-state-pending = en attente
+migrate-state-failed = ‼ échouée en cours de route
+migrate-state-changed = ‼ modifiée après avoir été appliquée
+migrate-state-unknown = ‼ appliquée par une version plus récente de l'application
+migrate-up-to-date = ✅ La base de données est à jour.
+migrate-pending = { $count } migration(s) en attente. Appliquez-les avec 'migrer up'.
+migrate-pending-startup = ‼ Il manque { $count } migration(s) à la base de données et DB_AUTO_MIGRATE est désactivé. Des commandes peuvent échouer tant que vous n'avez pas lancé 'migrer up'.
+migrate-ahead = La base de données a été migrée à la version { $version } par une version plus récente de l'application, mais celle-ci ne connaît que les versions jusqu'à { $latest }. Mettez l'application à jour.
+migrate-failed = La migration { $version } a échoué en cours de route. Réparez la base de données à la main et supprimez sa ligne de _sqlx_migrations.
+migrate-changed = La migration { $version } a été modifiée après avoir été appliquée. Restaurez le fichier d'origine.
+migrate-dry-run-up = Ces { $count } migration(s) seraient appliquées :
+migrate-up-done = ✅ { $count } migration(s) appliquée(s), la base de données est maintenant à la version { $version }.
+migrate-irreversible = ‼ La migration { $version } ne peut pas être annulée, il n'y a pas de .down.sql.
+migrate-down-nothing = La base de données n'a pas dépassé la version { $version }, il n'y a rien à annuler.
+migrate-dry-run-down = Ces { $count } migration(s) seraient annulées pour revenir à la version { $version } :
+migrate-down-confirm = ‼ Cela supprime des tables et des colonnes du profil '{ $profile }' avec tout leur contenu. Tapez { $version } à nouveau pour confirmer :
+migrate-down-cancelled = D'accord, je n'ai rien modifié.
+migrate-down-done = ✅ { $count } migration(s) annulée(s), la base de données est maintenant à la version { $version }.
+migrate-error = ‼ La migration a échoué :
+migrator-needed = ‼ L'utilisateur de la base de données ne peut que lire et écrire des lignes. Définissez DB_MIGRATOR_USER et DB_MIGRATOR_PASSWORD pour un utilisateur qui peut créer, modifier et supprimer des tables et des bases de données, comme celui que crée setup_db.
+
+command-stats = statistiques
+stats-cache-off = 📊 Le cache est désactivé. Donnez à cache.ttl (CACHE_TTL) un nombre de secondes pour l'activer.
+stats-cache = 📊 Personnages en cache : { $entries }, chacun gardé { $ttl } s
+stats-lookups = Trouvés : { $hits }, manqués : { $misses } ({ $rate } % trouvés)
+stats-invalidations = Retirés parce qu'ils ont changé : { $count }
+
+## exporter-calendrier
+
+calendar-usage = ‼ Donnez le fichier à exporter, éventuellement suivi de la façon dont les jours du jeu correspondent aux vraies dates :
+calendar-usage-year = 'exporter-calendrier anniversaires.ics' ou 'exporter-calendrier anniversaires.ics year 03-01' étale l'année du jeu sur une vraie année, le printemps commençant le jour donné.
+calendar-usage-play = 'exporter-calendrier anniversaires.ics play 2024-03-20 printemps 1' fait correspondre un jour du jeu à un vrai jour, à partir des dates données. Sans date du jeu, celle de la partie active est utilisée.
+calendar-invalid-spring-start = ‼ Donnez le jour où commence le printemps au format MM-JJ, par exemple : '03-20'.
+calendar-invalid-real-date = ‼ Donnez la vraie date au format AAAA-MM-JJ, par exemple : '2024-03-20'. J'ai reçu '{ $input }'.
+calendar-name = Anniversaires de Stardew Valley
+calendar-can-marry = peut épouser le joueur
+calendar-cannot-marry = ne peut PAS épouser le joueur
+calendar-loved-gifts = Cadeaux adorés : { $gifts }
+calendar-shops-closed = Les magasins sont fermés !
+calendar-exported = 📅 { $birthdays } anniversaires et { $festivals } festivals exportés dans { $file } ! Vous pouvez l'importer dans n'importe quelle application de calendrier.
+calendar-write-error = Impossible d'écrire le calendrier dans { $file } !
+
+## festivals et prochainement
+
+festival-conflict = ⚠ L'anniversaire de { $name } tombe pendant { $festival } ! Les magasins sont fermés, achetez donc le cadeau avant et retrouvez { $name } à { $location }.
+festivals-title = 🎪 Festivals :
+festivals-shops-closed = (les magasins sont fermés)
+festivals-add-usage = ‼ Donnez le nom du festival, la saison, le premier et le dernier jour, puis 'at' et le lieu.
+festivals-add-example = Par exemple : 'festivals add Pique-nique des récoltes automne 20 21 at forêt cindersap'
+festivals-add-days-usage = ‼ Donnez le nom du festival, la saison, le premier et le dernier jour, par exemple : 'festivals add Pique-nique des récoltes automne 20 21 at forêt cindersap'
+festivals-end-before-start = ‼ Le dernier jour du festival ne peut pas être avant le premier !
+festivals-added = ✅ { $name } a été ajouté au calendrier !
+festivals-add-error = Impossible d'ajouter le festival { $name } !
+upcoming-no-date = ‼ Donnez la date du jeu à partir de laquelle chercher, par exemple : 'prochainement printemps 10' ou 'prochainement printemps 10 14' pour les 14 prochains jours.
+upcoming-invalid-days = ‼ Donnez un nombre de jours entre 1 et { $max }.
+upcoming-title = 📅 Les { $days } prochains jours, à partir du { $date } :
+upcoming-nothing = Rien de spécial à venir. 🌾
+
+## partie, offrir, parler et statut
+
+save-usage = ‼ Utilisez 'partie create <nom_de_la_ferme>', 'partie use <nom_de_la_ferme>', 'partie list' ou 'partie date <saison> <jour> <année>'.
+save-none-active = ‼ Il n'y a pas encore de partie active ! Créez-en une d'abord avec 'partie create <nom_de_la_ferme>'.
+save-date-hint = Si vous créez une partie avec 'partie create <nom_de_la_ferme>', j'utiliserai plutôt sa date actuelle.
+save-list-empty = Il n'y a pas encore de partie ! Créez-en une avec 'partie create <nom_de_la_ferme>'.
+save-list-title = 🌱 Vos parties :
+save-line = { $name } - { $date }, année { $year }
+save-created = 🌱 La partie '{ $name }' a été créée et est maintenant active ! Elle commence le 1 Printemps, année 1.
+save-create-error = Impossible de créer la partie '{ $name }' !
+save-switched = 🌱 La partie '{ $name }' est maintenant active !
+save-not-found = Désolé, je ne trouve pas la partie '{ $name }' !
+save-invalid-year = ‼ Donnez une année valide. J'ai reçu '{ $input }'.
+save-date-changed = 📅 Dans { $name }, nous sommes maintenant le { $date }, année { $year }.
+friendship-points = { $name } : { $hearts } ({ $points } points)
+gift-usage = ‼ Donnez le nom du personnage et l'objet offert, par exemple : 'offrir abigail améthyste'.
+gift-already-today = { $name } a déjà reçu un cadeau aujourd'hui ! Les villageois n'acceptent qu'un cadeau par jour.
+gift-week-limit = { $name } a déjà reçu { $count } cadeaux cette semaine ! Réessayez dimanche prochain ou le jour de son anniversaire.
+gift-given = 🎁 Vous avez offert { $item } à { $name }. { $reaction } ({ $points } amitié)
+gift-given-birthday = 🎁 Vous avez offert { $item } à { $name }. { $reaction } ({ $points } amitié, bonus d'anniversaire !)
+talk-usage = ‼ Donnez le nom du personnage à qui vous avez parlé, par exemple : 'parler abigail'.
+talk-already-today = Vous avez déjà parlé à { $name } aujourd'hui ! Parler ne compte qu'une fois par jour.
+talk-done = 💬 Vous avez parlé à { $name }. (+{ $points } amitié)
+status-line = { $name } : { $hearts } ({ $points } points, { $status }) - cadeaux cette semaine : { $gifts }/{ $max }
+status-talked-today = a parlé aujourd'hui 💬
+status-usage = ‼ Donnez le nom du personnage et son nouveau statut (friend, dating ou married), par exemple : 'statut abigail dating'.
+status-invalid = ‼ Je ne comprends que 'friend', 'dating' ou 'married' comme statut. J'ai reçu '{ $input }'.
+status-changed = ✅ { $name } est maintenant { $role } !
+status-friend = ami
+status-dating = en couple
+status-married = marié
+status-role-friend = votre ami
+status-role-dating = votre partenaire
+status-role-married = votre époux
+reaction-love = { $name } adore !
+reaction-like = { $name } aime bien !
+reaction-neutral = Ça laisse { $name } indifférent.
+reaction-dislike = { $name } n'aime pas !
+reaction-hate = { $name } déteste !
+
+## recommander, inventaire et goût
+
+recommend-no-date = ‼ Donnez la date du jeu pour laquelle vous voulez des idées de cadeaux, par exemple : 'recommander automne 13'.
+recommend-title = 🎁 Plan de cadeaux pour le { $date } :
+recommend-birthday-today = 🎂 C'est l'anniversaire de { $name } aujourd'hui !
+recommend-birthday-soon = 🎂 L'anniversaire de { $name } approche, le { $date } (dans { $days } jours).
+recommend-inventory-empty = Votre inventaire est vide ! Ajoutez des objets avec 'inventaire add <objet> <quantité>' pour avoir des recommandations.
+recommend-nothing = Rien dans votre inventaire ne ferait plaisir à qui que ce soit pour l'instant. 😔
+recommend-give = ➡ Offrez { $item } à { $name } ({ $taste }, +{ $points } amitié)
+recommend-give-birthday = ➡ Offrez { $item } à { $name } ({ $taste }, +{ $points } amitié, bonus d'anniversaire !)
+recommend-keep = ⏳ Gardez { $item } pour l'anniversaire de { $name } le { $date } ({ $taste }, +{ $points } amitié)
+recommend-total = Gain d'amitié total : { $points } points
+inventory-empty = 🎒 Votre inventaire est vide !
+inventory-title = 🎒 Votre inventaire :
+inventory-usage = ‼ Utilisez 'inventaire', 'inventaire add <objet> [quantité]' ou 'inventaire remove <objet> [quantité]'.
+inventory-example = Par exemple : 'inventaire add pizza 3'
+inventory-added = 🎒 { $item } x{ $quantity } ajouté à votre inventaire !
+inventory-removed = 🎒 { $item } x{ $quantity } retiré de votre inventaire !
+inventory-missing = Il n'y a pas de { $item } dans votre inventaire !
+inventory-invalid-quantity = ‼ Donnez une quantité valide. J'ai reçu '{ $input }'.
+taste-usage = ‼ Donnez les arguments dans cet ordre : le nom du personnage, son goût (love, like, neutral, dislike ou hate), puis l'objet.
+taste-example = Par exemple : 'goût abigail love citrouille'
+taste-invalid = ‼ Je ne comprends que 'love', 'like', 'neutral', 'dislike' ou 'hate' comme goût. J'ai reçu '{ $input }'.
+taste-saved = ✅ Noté : le goût de { $name } pour { $item } est '{ $taste }' !
+taste-error = Impossible d'enregistrer le goût de '{ $name }' !
+taste-love = adore
+taste-like = aime
+taste-neutral = indifférent
+taste-dislike = n'aime pas
+taste-hate = déteste
+
+## importer-partie
+
+import-usage = ‼ Donnez le chemin d'un fichier ou d'un dossier de sauvegarde de Stardew Valley, par exemple : 'importer-partie ~/.config/StardewValley/Saves/Sunny_123456789'.
+import-invalid-xml = Ce n'est pas du XML valide : { $error }
+import-not-a-save = Ce n'est pas une sauvegarde de Stardew Valley, elle devrait commencer par <SaveGame>.
+import-missing-field = La sauvegarde ne contient pas de { $field } valide.
+import-folder-without-name = Le dossier de sauvegarde n'a pas de nom.
+import-read-error = Impossible de lire la sauvegarde { $path } !
+import-error = Impossible d'importer { $path } !
+import-not-activated = La partie importée n'a pas pu être activée.
+import-done = 📥 { $save } importée et activée !
+import-friendships = Amitiés importées : { $count }
+import-unknown-characters = Ces villageois ne sont pas dans la base de données, je les ai donc ignorés : { $names }
+import-unknown-hint = Ajoutez-les avec la commande 'ajouter' et importez à nouveau pour les suivre aussi.
+
+## importer-mod et désinstaller-mod
+
+mod-usage = ‼ Donnez le dossier d'un pack de contenu Content Patcher, celui qui contient manifest.json et content.json, par exemple : 'importer-mod Mods/[CP] Claire'.
+mod-read-error = Impossible de lire { $path } : { $error }
+mod-invalid-json = { $path } n'est pas du JSON valide : { $error }
+mod-missing-unique-id = Le manifest.json ne contient pas de UniqueID.
+mod-invalid-id = '{ $id }' ne peut pas servir d'identifiant de mod.
+mod-missing-changes = Le content.json ne contient pas de liste de Changes.
+mod-import-error = Impossible d'importer le mod dans { $path } !
+mod-added-by = { $name } (déjà ajouté par { $source })
+mod-imported = 🧩 { $name } ({ $id }) importé
+mod-characters-added = Personnages ajoutés : { $names }
+mod-none = aucun
+mod-tastes-added = Goûts ajoutés : { $count }
+mod-conflicts = Ces villageois existent déjà, je les ai donc ignorés : { $names }
+mod-incomplete = Ces villageois n'ont pas d'anniversaire dans le mod, je les ai donc ignorés : { $names }
+mod-unknown-characters = Les goûts des villageois absents de la base de données ont été ignorés : { $names }
+mod-item-ids = Les goûts des mods utilisent les identifiants d'objets du jeu, comme 72 pour un diamant.
+uninstall-vanilla = ‼ Les personnages du jeu de base ne peuvent pas être désinstallés.
+uninstall-usage = ‼ Donnez l'identifiant unique du mod, par exemple : 'désinstaller-mod Example.ClaireNPC'.
+uninstall-nothing = Désolé, je ne trouve rien du mod { $id } dans la base de données !
+uninstall-done = 🗑 { $id } désinstallé !
+uninstall-characters = Personnages supprimés : { $count }
+uninstall-tastes = Goûts supprimés : { $count }
+
+## profil
+
+profile-usage = ‼ Utilisez 'profil list', 'profil create <nom>', 'profil use <nom>' ou 'profil delete <nom>'.
+profile-list = 🗂 Profils :
+profile-invalid-name = ‼ Les noms de profil ne peuvent contenir que des minuscules, des chiffres et '_', faire au plus 32 caractères et ne peuvent pas être '{ $default }'.
+profile-exists = Le profil '{ $name }' existe déjà !
+profile-not-found = Désolé, je ne trouve pas le profil '{ $name }' !
+profile-not-found-create = Désolé, je ne trouve pas le profil '{ $name }' ! Créez-le avec 'profil create { $name }'.
+profile-switched = 🗂 Le profil '{ $name }' est maintenant actif ! Tout ce que vous faites n'affecte plus que ce profil.
+profile-delete-default = ‼ Le profil par défaut ne peut pas être supprimé.
+profile-delete-active = ‼ Vous ne pouvez pas supprimer le profil que vous utilisez. Passez d'abord à un autre avec 'profil use <nom>'.
+profile-delete-confirm = ‼ Cela supprime tous les personnages, parties et tout le reste du profil '{ $name }'. Tapez à nouveau le nom du profil pour confirmer :
+profile-deleted = 🗑 Le profil '{ $name }' a été supprimé.
+
+## relier, famille et graphe
+
+relate-usage = ‼ Donnez les arguments dans cet ordre : premier personnage, type de relation (parent, sibling, spouse, friend ou rival), second personnage.
+relate-example = Par exemple, Caroline est la mère d'Abigail : 'relier caroline parent abigail'
+relate-invalid-kind = ‼ Je ne comprends que 'parent', 'sibling', 'spouse', 'friend' ou 'rival' comme relation. J'ai reçu '{ $input }'.
+relate-self = ‼ Un personnage ne peut pas être relié à lui-même !
+relate-done = ✅ Noté : { $from } est { $kind } de { $to } !
+relate-known = Je savais déjà que { $from } est { $kind } de { $to }.
+relate-error = Impossible de relier { $from } et { $to } ! Vérifiez que les deux sont dans la base de données.
+relationship-parent = parent
+relationship-sibling = frère ou sœur
+relationship-spouse = conjoint
+relationship-friend = ami
+relationship-rival = rival
+family-usage = ‼ Donnez le nom du personnage, par exemple : 'famille abigail'.
+family-title = Famille et amis de { $name } :
+family-none = Je ne connais encore personne relié à { $name }. Ajoutez quelqu'un avec 'relier' !
+family-child = 👶 Enfant : { $name }
+family-parent = 👪 Parent : { $name }
+family-sibling = 🧑‍🤝‍🧑 Frère ou sœur : { $name }
+family-spouse = 💍 Conjoint : { $name }
+family-friend = 🤝 Ami : { $name }
+family-rival = ⚔ Rival : { $name }
+graph-usage = ‼ Donnez le fichier dans lequel exporter le graphe des relations, par exemple : 'graphe --dot villageois.dot'.
+graph-example = Graphviz peut en faire une image : 'dot -Tpng villageois.dot -o villageois.png'
+graph-exported = 🕸 { $characters } personnages et { $relationships } relations exportés dans { $file } !
+graph-write-error = Impossible d'écrire le graphe dans { $file } !
+
+## rapport
+
+report-invalid-format = ‼ Je ne peux créer des rapports qu'avec '--format html' ou '--format md'.
+report-usage = ‼ Donnez le fichier dans lequel écrire le rapport, par exemple : 'rapport --format html villageois.html'.
+report-template-hint = Vous pouvez utiliser votre propre modèle avec '--template <fichier>'. Il peut contenir { $placeholders }.
+report-template-error = Impossible de lire le modèle { $file } !
+report-title = Les villageois de Stardew Valley
+report-heading-calendar = Calendrier des anniversaires
+report-heading-candidates = Candidats au mariage
+report-heading-gifts = Cadeaux
+report-heading-characters = Villageois par saison
+report-written = 📜 Rapport { $format } avec { $count } personnages écrit dans { $file } !
+report-write-error = Impossible d'écrire le rapport dans { $file } !
+report-no-candidates = Personne ne peut épouser le joueur ! 💔
+report-candidate = ❤ { $name } (anniversaire : { $date })
+report-column-character = Personnage
+report-column-best-gift = Cadeau préféré
+report-column-loved-gifts = Cadeaux adorés
+
+## romance et prochain-événement
+
+heart-event = { $hearts }♥ à { $location } ({ $time }) : { $conditions }
+romance-usage = ‼ Donnez le nom d'un personnage à épouser, par exemple : 'romance abigail'.
+romance-seen-hint = Pour marquer un événement de cœur comme vu dans la partie active, ajoutez 'seen' et son niveau de cœur : 'romance abigail seen 4'
+romance-title = 💘 Romance avec { $name }
+romance-in-save = Dans { $save } : { $hearts } ({ $points } points, { $status })
+romance-proposal = 💍 Pour demander en mariage : { $requirements }
+romance-spouse-room = 🛏 Chambre après le mariage : { $room }
+romance-details-unknown = Je ne connais pas encore les conditions de demande en mariage ni la chambre de { $name }.
+romance-no-events = Je ne connais pas encore d'événements de cœur.
+romance-events = Événements de cœur :
+romance-seen-usage = ‼ Donnez le niveau de cœur de l'événement que vous avez vu, par exemple : 'romance abigail seen 4'.
+romance-unknown-event = Je ne connais pas d'événement à { $hearts }♥ pour { $name } !
+romance-marked-seen = ✔ L'événement à { $hearts }♥ de { $name } est marqué comme vu dans { $save } !
+next-event-usage = ‼ Donnez le nom d'un personnage à épouser, par exemple : 'prochain-événement abigail'.
+next-event-ready = ✨ Prêt maintenant ! { $event }
+next-event-after-dating = 💐 Prochain : { $event }
+next-event-date-first = Vous devez d'abord sortir avec { $name } : offrez un bouquet à partir de { $hearts } cœurs.
+next-event-upcoming = ⏳ Prochain : { $event }
+next-event-points-needed = Il vous faut encore { $points } points d'amitié pour y arriver.
+next-event-none = Je ne connais pas encore d'événements de cœur pour { $name }.
+next-event-all-seen = Vous avez vu tous les événements de cœur ! 💕
+
+## horaire, où et qui-est-à
+
+time-invalid = ‼ Donnez une heure valide comme '14:30' ou '1430'. J'ai reçu '{ $input }'.
+time-out-of-range = ‼ Donnez une heure entre 6:00 et 26:00 (2 h du matin), comme l'horloge du jeu. J'ai reçu '{ $input }'.
+moment-day-missing = ‼ Donnez le jour après la saison, par exemple : 'printemps 10'.
+moment-not-understood = ‼ Je ne comprends pas '{ $input }'. Donnez une saison et un jour, une heure et/ou la météo (ensoleillé ou pluvieux).
+moment-no-date = ‼ Donnez la date du jeu, par exemple : 'printemps 10 14:00 pluvieux'.
+where-usage = ‼ Donnez le nom du personnage, éventuellement suivi de la saison, du jour, de l'heure et de la météo.
+where-example = Par exemple : 'où abigail automne 13 14:00 ensoleillé'
+where-found = 📍 Le { $date } ({ $weekday }, { $weather }) à { $time }, { $name } est à { $location }.
+where-unknown = Désolé, je ne connais pas l'emploi du temps de { $name } ! Ajoutez-le avec la commande 'horaire'.
+who-is-at-usage = ‼ Donnez le lieu et l'heure, éventuellement suivis de la saison, du jour et de la météo.
+who-is-at-example = Par exemple : 'qui-est-à stardrop saloon 19:00 automne 13'
+who-is-at-title = 📍 À { $time } le { $date } ({ $weekday }, { $weather }) :
+who-is-at-line = { $name } est à { $location }
+who-is-at-nobody = À ma connaissance, personne n'est à { $location } à ce moment-là.
+schedule-usage = ‼ Donnez les arguments dans cet ordre : nom, saison, jour de la semaine, météo, heure, lieu. Utilisez 'any' pour une saison, un jour ou une météo qui n'a pas d'importance.
+schedule-example = Par exemple, Leah passe ses vendredis soir au saloon : 'horaire leah any friday any 19:00 stardrop saloon'
+schedule-invalid-weekday = ‼ Donnez un jour de la semaine comme 'monday' ou 'any'. J'ai reçu '{ $input }'.
+schedule-invalid-weather = ‼ Je ne comprends que 'ensoleillé', 'pluvieux' ou 'any' comme météo. J'ai reçu '{ $input }'.
+schedule-added = ✅ Noté : à partir de { $time }, { $name } est à { $location } !
+schedule-error = Impossible de compléter l'emploi du temps de { $name } !
+weather-sunny = ensoleillé
+weather-rainy = pluvieux
@@ -0,0 +1,12 @@
+CREATE TABLE gift_tastes (
+    character_name VARCHAR(50) NOT NULL,
+    item VARCHAR(50) NOT NULL,
+    taste VARCHAR(10) NOT NULL,
+    PRIMARY KEY (character_name, item),
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE TABLE inventory (
+    item VARCHAR(50) PRIMARY KEY NOT NULL,
+    quantity INTEGER NOT NULL
+);
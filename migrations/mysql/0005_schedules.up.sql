@@ -0,0 +1,11 @@
+CREATE TABLE schedules (
+    id INTEGER PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    character_name VARCHAR(50) NOT NULL,
+    season VARCHAR(10),
+    weekday VARCHAR(10),
+    weather VARCHAR(10),
+    time INTEGER NOT NULL,
+    location VARCHAR(100) NOT NULL,
+    INDEX (character_name, time),
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+DROP TABLE seen_heart_events;
+DROP TABLE heart_events;
+DROP TABLE romance;
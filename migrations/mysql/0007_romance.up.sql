@@ -0,0 +1,25 @@
+CREATE TABLE romance (
+    character_name VARCHAR(50) PRIMARY KEY NOT NULL,
+    proposal_requirements VARCHAR(255) NOT NULL,
+    spouse_room VARCHAR(255) NOT NULL,
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE TABLE heart_events (
+    id INTEGER PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    character_name VARCHAR(50) NOT NULL,
+    hearts INTEGER NOT NULL,
+    location VARCHAR(100) NOT NULL,
+    time_window VARCHAR(50) NOT NULL,
+    conditions VARCHAR(255) NOT NULL,
+    UNIQUE (character_name, hearts),
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE TABLE seen_heart_events (
+    save_id INTEGER NOT NULL,
+    event_id INTEGER NOT NULL,
+    PRIMARY KEY (save_id, event_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (event_id) REFERENCES heart_events(id) ON DELETE CASCADE
+);
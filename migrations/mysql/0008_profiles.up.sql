@@ -0,0 +1,5 @@
+-- only the default database keeps track of the profiles, every profile has a database of its own
+CREATE TABLE profiles (
+    name VARCHAR(32) PRIMARY KEY NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT FALSE
+);
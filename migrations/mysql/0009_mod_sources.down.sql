@@ -0,0 +1,5 @@
+DROP INDEX gift_tastes_source ON gift_tastes;
+DROP INDEX characters_source ON characters;
+
+ALTER TABLE gift_tastes DROP COLUMN source;
+ALTER TABLE characters DROP COLUMN source;
@@ -0,0 +1,5 @@
+ALTER TABLE characters ADD COLUMN source VARCHAR(100) NOT NULL DEFAULT 'vanilla';
+ALTER TABLE gift_tastes ADD COLUMN source VARCHAR(100) NOT NULL DEFAULT 'vanilla';
+
+CREATE INDEX characters_source ON characters (source);
+CREATE INDEX gift_tastes_source ON gift_tastes (source);
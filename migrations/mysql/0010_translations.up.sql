@@ -0,0 +1,33 @@
+CREATE TABLE translations (
+    kind VARCHAR(20) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    locale VARCHAR(10) NOT NULL,
+    display_name VARCHAR(100) NOT NULL,
+    PRIMARY KEY (kind, name, locale)
+);
+
+INSERT INTO translations (kind, name, locale, display_name) VALUES
+    ('item', 'Amethyst', 'de', 'Amethyst'),
+    ('item', 'Amethyst', 'fr', 'Améthyste'),
+    ('item', 'Pumpkin', 'de', 'Kürbis'),
+    ('item', 'Pumpkin', 'fr', 'Citrouille'),
+    ('item', 'Coconut', 'de', 'Kokosnuss'),
+    ('item', 'Coconut', 'fr', 'Noix de coco'),
+    ('item', 'Fish Taco', 'de', 'Fisch-Taco'),
+    ('item', 'Fish Taco', 'fr', 'Taco au poisson'),
+    ('item', 'Autumn''s Beauty', 'de', 'Herbstschönheit'),
+    ('item', 'Autumn''s Beauty', 'fr', 'Beauté automnale'),
+    ('item', 'Goat Cheese', 'de', 'Ziegenkäse'),
+    ('item', 'Goat Cheese', 'fr', 'Fromage de chèvre'),
+    ('item', 'Green Tea', 'de', 'Grüner Tee'),
+    ('item', 'Green Tea', 'fr', 'Thé vert'),
+    ('item', 'Sunflower', 'de', 'Sonnenblume'),
+    ('item', 'Sunflower', 'fr', 'Tournesol'),
+    ('item', 'Daffodil', 'de', 'Narzisse'),
+    ('item', 'Daffodil', 'fr', 'Jonquille'),
+    ('item', 'Clay', 'de', 'Lehm'),
+    ('item', 'Clay', 'fr', 'Argile'),
+    ('item', 'Holly', 'de', 'Stechpalme'),
+    ('item', 'Holly', 'fr', 'Houx'),
+    ('item', 'Pizza', 'de', 'Pizza'),
+    ('item', 'Pizza', 'fr', 'Pizza');
@@ -0,0 +1,5 @@
+CREATE TABLE aliases (
+    alias VARCHAR(50) PRIMARY KEY NOT NULL,
+    character_name VARCHAR(50) NOT NULL,
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
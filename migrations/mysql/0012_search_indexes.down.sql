@@ -0,0 +1,8 @@
+DROP INDEX romance_spouse_room_search ON romance;
+DROP INDEX heart_events_conditions_search ON heart_events;
+DROP INDEX heart_events_location_search ON heart_events;
+DROP INDEX schedules_location_search ON schedules;
+DROP INDEX aliases_alias_search ON aliases;
+DROP INDEX gift_tastes_item_search ON gift_tastes;
+DROP INDEX characters_best_gift_search ON characters;
+DROP INDEX characters_name_search ON characters;
@@ -0,0 +1,8 @@
+CREATE FULLTEXT INDEX characters_name_search ON characters (name);
+CREATE FULLTEXT INDEX characters_best_gift_search ON characters (best_gift);
+CREATE FULLTEXT INDEX gift_tastes_item_search ON gift_tastes (item);
+CREATE FULLTEXT INDEX aliases_alias_search ON aliases (alias);
+CREATE FULLTEXT INDEX schedules_location_search ON schedules (location);
+CREATE FULLTEXT INDEX heart_events_location_search ON heart_events (location);
+CREATE FULLTEXT INDEX heart_events_conditions_search ON heart_events (conditions);
+CREATE FULLTEXT INDEX romance_spouse_room_search ON romance (spouse_room);
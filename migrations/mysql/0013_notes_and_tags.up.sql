@@ -0,0 +1,18 @@
+CREATE TABLE notes (
+    id INTEGER PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    character_name VARCHAR(50) NOT NULL,
+    text VARCHAR(500) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX (character_name),
+    FULLTEXT INDEX notes_text_search (text),
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE TABLE tags (
+    character_name VARCHAR(50) NOT NULL,
+    tag VARCHAR(50) NOT NULL,
+    PRIMARY KEY (character_name, tag),
+    INDEX (tag),
+    FULLTEXT INDEX tags_tag_search (tag),
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
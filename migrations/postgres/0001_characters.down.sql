@@ -0,0 +1,2 @@
+DROP TABLE characters;
+DROP COLLATION case_insensitive;
@@ -0,0 +1,10 @@
+-- MySQL compares text without case and accents, this collation does the same for every text column
+CREATE COLLATION IF NOT EXISTS case_insensitive (provider = icu, locale = 'und-u-ks-level1', deterministic = false);
+
+CREATE TABLE characters (
+    name VARCHAR(50) COLLATE case_insensitive PRIMARY KEY NOT NULL,
+    birthday_season VARCHAR(10) COLLATE case_insensitive NOT NULL,
+    birthday_day INTEGER NOT NULL,
+    is_bachelor BOOLEAN NOT NULL,
+    best_gift VARCHAR(50) COLLATE case_insensitive NOT NULL
+);
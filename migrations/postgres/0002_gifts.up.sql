@@ -0,0 +1,12 @@
+CREATE TABLE gift_tastes (
+    character_name VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    item VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    taste VARCHAR(10) COLLATE case_insensitive NOT NULL,
+    PRIMARY KEY (character_name, item),
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE TABLE inventory (
+    item VARCHAR(50) COLLATE case_insensitive PRIMARY KEY NOT NULL,
+    quantity INTEGER NOT NULL
+);
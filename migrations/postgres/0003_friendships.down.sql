@@ -0,0 +1,3 @@
+DROP TABLE gift_log;
+DROP TABLE friendships;
+DROP TABLE saves;
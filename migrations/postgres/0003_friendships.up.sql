@@ -0,0 +1,35 @@
+CREATE TABLE saves (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    name VARCHAR(50) COLLATE case_insensitive UNIQUE NOT NULL,
+    season VARCHAR(10) COLLATE case_insensitive NOT NULL,
+    day INTEGER NOT NULL,
+    year INTEGER NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE friendships (
+    save_id INTEGER NOT NULL,
+    character_name VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    points INTEGER NOT NULL DEFAULT 0,
+    status VARCHAR(10) COLLATE case_insensitive NOT NULL DEFAULT 'Friend',
+    last_talked INTEGER,
+    PRIMARY KEY (save_id, character_name),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE TABLE gift_log (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    save_id INTEGER NOT NULL,
+    character_name VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    item VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    taste VARCHAR(10) COLLATE case_insensitive NOT NULL,
+    points INTEGER NOT NULL,
+    is_birthday BOOLEAN NOT NULL,
+    season VARCHAR(10) COLLATE case_insensitive NOT NULL,
+    day INTEGER NOT NULL,
+    year INTEGER NOT NULL,
+    day_number INTEGER NOT NULL,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+CREATE TABLE relationships (
+    from_character VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    to_character VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    kind VARCHAR(10) COLLATE case_insensitive NOT NULL,
+    PRIMARY KEY (from_character, to_character, kind),
+    FOREIGN KEY (from_character) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE,
+    FOREIGN KEY (to_character) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
@@ -0,0 +1,12 @@
+CREATE TABLE schedules (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    character_name VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    season VARCHAR(10) COLLATE case_insensitive,
+    weekday VARCHAR(10) COLLATE case_insensitive,
+    weather VARCHAR(10) COLLATE case_insensitive,
+    time INTEGER NOT NULL,
+    location VARCHAR(100) COLLATE case_insensitive NOT NULL,
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE INDEX schedules_character_name_time ON schedules (character_name, time);
@@ -0,0 +1,22 @@
+CREATE TABLE festivals (
+    name VARCHAR(50) COLLATE case_insensitive PRIMARY KEY NOT NULL,
+    season VARCHAR(10) COLLATE case_insensitive NOT NULL,
+    start_day INTEGER NOT NULL,
+    end_day INTEGER NOT NULL,
+    location VARCHAR(100) COLLATE case_insensitive NOT NULL,
+    closes_shops BOOLEAN NOT NULL
+);
+
+INSERT INTO festivals (name, season, start_day, end_day, location, closes_shops) VALUES
+    ('Egg Festival', 'Spring', 13, 13, 'Town Square', TRUE),
+    ('Desert Festival', 'Spring', 15, 17, 'Calico Desert', FALSE),
+    ('Flower Dance', 'Spring', 24, 24, 'Cindersap Forest', TRUE),
+    ('Luau', 'Summer', 11, 11, 'The Beach', TRUE),
+    ('Trout Derby', 'Summer', 20, 21, 'Cindersap Forest', FALSE),
+    ('Dance of the Moonlight Jellies', 'Summer', 28, 28, 'The Beach', TRUE),
+    ('Stardew Valley Fair', 'Fall', 16, 16, 'Town Square', TRUE),
+    ('Spirit''s Eve', 'Fall', 27, 27, 'Town Square', TRUE),
+    ('Festival of Ice', 'Winter', 8, 8, 'Cindersap Forest', TRUE),
+    ('SquidFest', 'Winter', 12, 13, 'The Beach', FALSE),
+    ('Night Market', 'Winter', 15, 17, 'The Beach', FALSE),
+    ('Feast of the Winter Star', 'Winter', 25, 25, 'Town Square', TRUE);
@@ -0,0 +1,5 @@
+DROP INDEX gift_tastes_source;
+DROP INDEX characters_source;
+
+ALTER TABLE gift_tastes DROP COLUMN source;
+ALTER TABLE characters DROP COLUMN source;
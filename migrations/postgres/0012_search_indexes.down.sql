@@ -0,0 +1 @@
+-- nothing to revert, see 0012_search_indexes.up.sql
@@ -0,0 +1,2 @@
+-- Postgres has no FULLTEXT indexes, so 'search' always uses LIKE there.
+-- This migration is kept empty so that the versions match the MySQL ones.
@@ -0,0 +1,18 @@
+CREATE TABLE notes (
+    id INTEGER PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    character_name VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    text VARCHAR(500) COLLATE case_insensitive NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE INDEX notes_character_name ON notes (character_name);
+
+CREATE TABLE tags (
+    character_name VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    tag VARCHAR(50) COLLATE case_insensitive NOT NULL,
+    PRIMARY KEY (character_name, tag),
+    FOREIGN KEY (character_name) REFERENCES characters(name) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE INDEX tags_tag ON tags (tag);
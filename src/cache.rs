@@ -0,0 +1,184 @@
+pub mod cache_commands {
+    use crate::localization::localization_commands::tr;
+    use crate::lookup::lookup_commands::normalize;
+    use crate::profiles::profile_commands::Session;
+    use crate::{print_aesthetic_message, Character};
+    use std::collections::HashMap;
+    use std::error::Error;
+    use std::time::{Duration, Instant};
+
+    // characters as 'read' last found them, with their notes and tags, so that reading one again
+    // doesn't need the database. It's off unless cache.ttl is set, and entries are dropped when they
+    // get older than that or when a command changes the character.
+    pub struct CharacterCache {
+        ttl: Option<Duration>,
+        entries: HashMap<String, (Instant, Character)>,
+        // what else was typed to find a cached character, like an alias, a translated name or a
+        // typo, and the key of its entry
+        lookups: HashMap<String, String>,
+        hits: u64,
+        misses: u64,
+        invalidations: u64,
+    }
+
+    // "Abigail", "abigail " and "A-bi-gail" are the same entry, just like they are the same name
+    // when it's looked up
+    fn cache_key(name: &str) -> String {
+        normalize(name)
+    }
+
+    impl CharacterCache {
+        pub fn new(ttl: Option<Duration>) -> CharacterCache {
+            CharacterCache {
+                ttl,
+                entries: HashMap::new(),
+                lookups: HashMap::new(),
+                hits: 0,
+                misses: 0,
+                invalidations: 0,
+            }
+        }
+
+        pub fn get(&mut self, name: &str) -> Option<Character> {
+            let ttl = self.ttl?;
+            let key = cache_key(name);
+            let key = self.lookups.get(&key).cloned().unwrap_or(key);
+            match self.entries.get(&key) {
+                Some((cached_at, character)) if cached_at.elapsed() < ttl => {
+                    self.hits += 1;
+                    Some(character.clone())
+                }
+                Some(_) => {
+                    self.entries.remove(&key);
+                    self.misses += 1;
+                    None
+                }
+                None => {
+                    self.misses += 1;
+                    None
+                }
+            }
+        }
+
+        // input is what was typed to find the character, so that typing it again finds the entry
+        pub fn insert(&mut self, input: &str, character: &Character) {
+            if self.ttl.is_some() {
+                let key = cache_key(&character.name);
+                let input = cache_key(input);
+                if input != key {
+                    self.lookups.insert(input, key.clone());
+                }
+                self.entries
+                    .insert(key, (Instant::now(), character.clone()));
+            }
+        }
+
+        // a new character may now be what a name that used to lead elsewhere means, so those go too
+        pub fn invalidate(&mut self, name: &str) {
+            let key = cache_key(name);
+            self.lookups
+                .retain(|input, entry| *input != key && *entry != key);
+            if self.entries.remove(&key).is_some() {
+                self.invalidations += 1;
+            }
+        }
+
+        pub fn clear(&mut self) {
+            self.invalidations += self.entries.len() as u64;
+            self.entries.clear();
+            self.lookups.clear();
+        }
+    }
+
+    pub async fn handle_stats_command(
+        session: &Session,
+        _arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let cache = &session.cache;
+        let ttl = match cache.ttl {
+            Some(ttl) => ttl,
+            None => {
+                print_aesthetic_message(vec![tr!("stats-cache-off")]);
+                return Ok(());
+            }
+        };
+
+        let lookups = cache.hits + cache.misses;
+        let hit_rate = (cache.hits * 100).checked_div(lookups).unwrap_or(0);
+        print_aesthetic_message(vec![
+            tr!(
+                "stats-cache",
+                entries = cache.entries.len(),
+                ttl = ttl.as_secs()
+            ),
+            tr!(
+                "stats-lookups",
+                hits = cache.hits,
+                misses = cache.misses,
+                rate = hit_rate
+            ),
+            tr!("stats-invalidations", count = cache.invalidations),
+        ]);
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{CharacterRecord, VANILLA_SOURCE};
+
+        fn cache_with(input: &str, name: &str) -> CharacterCache {
+            let mut cache = CharacterCache::new(Some(Duration::from_secs(60)));
+            let character = Character::try_from(CharacterRecord {
+                name: name.to_string(),
+                birthday_season: "fall".to_string(),
+                birthday_day: 13,
+                is_bachelor: true,
+                best_gift: "Amethyst".to_string(),
+                source: VANILLA_SOURCE.to_string(),
+            })
+            .unwrap();
+            cache.insert(input, &character);
+            cache
+        }
+
+        #[test]
+        fn spellings_of_the_name_find_the_entry() {
+            let mut cache = cache_with("Abigail", "Abigail");
+            for input in ["abigail", " ABIGAIL ", "A-bi-gail"] {
+                assert_eq!(
+                    cache.get(input).map(|c| c.name),
+                    Some("Abigail".to_string())
+                );
+            }
+        }
+
+        #[test]
+        fn what_was_typed_finds_the_entry_again() {
+            let mut cache = cache_with("abby", "Abigail");
+            assert_eq!(
+                cache.get("Abby").map(|c| c.name),
+                Some("Abigail".to_string())
+            );
+            assert_eq!(
+                cache.get("abigail").map(|c| c.name),
+                Some("Abigail".to_string())
+            );
+            assert!(cache.get("abi").is_none());
+        }
+
+        #[test]
+        fn invalidating_forgets_the_names_that_led_to_it() {
+            let mut cache = cache_with("abby", "Abigail");
+            cache.invalidate("Abigail");
+            assert!(cache.get("abby").is_none());
+            assert!(cache.lookups.is_empty());
+
+            // a character added under a name that used to lead to someone else
+            let mut cache = cache_with("abby", "Abigail");
+            cache.invalidate("Abby");
+            assert!(cache.get("abby").is_none());
+            assert!(cache.get("abigail").is_some());
+        }
+    }
+}
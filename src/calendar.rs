@@ -0,0 +1,402 @@
+pub mod calendar_commands {
+    use crate::commands::terminal_commands::{string_to_day, string_to_season};
+    use crate::festivals::festival_commands::{
+        conflict_warning, conflicting_festival, read_festivals,
+    };
+    use crate::friendships::friendship_commands::read_active_save;
+    use crate::gifts::gift_commands::read_loved_gifts;
+    use crate::localization::localization_commands::{date_name, tr};
+    use crate::storage::backend::{checked_query_as, Pool};
+    use crate::{print_aesthetic_message, Character, CharacterRecord, Season, StardewDate};
+    use chrono::{Datelike, Days, Months, NaiveDate, Utc};
+    use std::{error::Error, fs};
+
+    // how the 112 days of a Stardew year are placed on the real calendar
+    enum CalendarMapping {
+        // the in-game year is stretched over a real year, every season lasts three months
+        Year {
+            spring_start: NaiveDate,
+        },
+        // one in-game day per real day, starting from the given in-game date
+        Play {
+            real_start: NaiveDate,
+            in_game_start: StardewDate,
+        },
+    }
+
+    impl CalendarMapping {
+        fn real_date(&self, date: &StardewDate) -> Option<NaiveDate> {
+            match self {
+                CalendarMapping::Year { spring_start } => {
+                    let season_start = spring_start
+                        .checked_add_months(Months::new(date.season.index() as u32 * 3))?;
+                    let next_season_start = season_start.checked_add_months(Months::new(3))?;
+                    let season_length = (next_season_start - season_start).num_days() as u64;
+                    let offset = (date.day.max(1) as u64 - 1) * season_length
+                        / StardewDate::DAYS_PER_SEASON as u64;
+                    season_start.checked_add_days(Days::new(offset))
+                }
+                CalendarMapping::Play {
+                    real_start,
+                    in_game_start,
+                } => real_start.checked_add_days(Days::new(in_game_start.days_until(date) as u64)),
+            }
+        }
+
+        fn recurrence_rule(&self) -> String {
+            match self {
+                CalendarMapping::Year { .. } => "FREQ=YEARLY".to_string(),
+                CalendarMapping::Play { .. } => {
+                    format!("FREQ=DAILY;INTERVAL={}", StardewDate::DAYS_PER_YEAR)
+                }
+            }
+        }
+    }
+
+    pub async fn handle_export_calendar_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            print_export_calendar_help();
+            return Ok(());
+        }
+
+        let file_name = arguments[0];
+        let mapping = match parse_mapping(pool, &arguments[1..]).await? {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        let characters: Vec<Character> = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters ORDER BY name"
+        )
+        .fetch_all(pool)
+        .await?;
+        let loved_gifts = read_loved_gifts(pool).await?;
+        let festivals = read_festivals(pool).await?;
+
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//rust_mysql_example//Stardew Valley birthdays//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            format!("X-WR-CALNAME:{}", escape_text(&tr!("calendar-name"))),
+        ];
+        let timestamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut exported = 0;
+        let mut exported_festivals = 0;
+
+        for character in &characters {
+            let birthday = StardewDate {
+                season: character.birthday_season,
+                day: character.birthday_day,
+            };
+            let real_date = match mapping.real_date(&birthday) {
+                Some(d) => d,
+                None => continue,
+            };
+            let gifts = loved_gifts
+                .get(&character.name.to_lowercase())
+                .cloned()
+                .unwrap_or_default();
+
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!(
+                "UID:{}-birthday@rust-mysql-example",
+                character.name.to_lowercase().replace(' ', "-")
+            ));
+            lines.push(format!("DTSTAMP:{}", timestamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", real_date.format("%Y%m%d")));
+            lines.push(format!(
+                "DTEND;VALUE=DATE:{}",
+                (real_date + Days::new(1)).format("%Y%m%d")
+            ));
+            lines.push(format!("RRULE:{}", mapping.recurrence_rule()));
+            lines.push(format!(
+                "SUMMARY:{}",
+                escape_text(&tr!("birthday-of", name = character.name.as_str()))
+            ));
+            let mut description = format!(
+                "{} ({})\n{}",
+                date_name(&birthday),
+                if character.is_bachelor {
+                    tr!("calendar-can-marry")
+                } else {
+                    tr!("calendar-cannot-marry")
+                },
+                tr!("calendar-loved-gifts", gifts = gifts.join(", "))
+            );
+            if let Some(festival) = conflicting_festival(&festivals, &birthday) {
+                description.push('\n');
+                description.push_str(&conflict_warning(&character.name, festival));
+            }
+            lines.push(format!("DESCRIPTION:{}", escape_text(&description)));
+            lines.push("TRANSP:TRANSPARENT".to_string());
+            lines.push("END:VEVENT".to_string());
+            exported += 1;
+        }
+        for festival in &festivals {
+            let (first_day, last_day) = match (
+                mapping.real_date(&festival.start()),
+                mapping.real_date(&festival.end()),
+            ) {
+                (Some(first), Some(last)) => (first, last),
+                _ => continue,
+            };
+
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!(
+                "UID:{}-festival@rust-mysql-example",
+                festival.name.to_lowercase().replace(' ', "-")
+            ));
+            lines.push(format!("DTSTAMP:{}", timestamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", first_day.format("%Y%m%d")));
+            lines.push(format!(
+                "DTEND;VALUE=DATE:{}",
+                (last_day + Days::new(1)).format("%Y%m%d")
+            ));
+            lines.push(format!("RRULE:{}", mapping.recurrence_rule()));
+            lines.push(format!(
+                "SUMMARY:{}",
+                escape_text(&format!("🎪 {}", &festival.name))
+            ));
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                escape_text(&format!(
+                    "{}{}",
+                    tr!(
+                        "at-location",
+                        what = date_name(&festival.start()),
+                        location = festival.location.as_str()
+                    ),
+                    if festival.closes_shops {
+                        format!("\n{}", tr!("calendar-shops-closed"))
+                    } else {
+                        String::new()
+                    }
+                ))
+            ));
+            lines.push(format!("LOCATION:{}", escape_text(&festival.location)));
+            lines.push("TRANSP:TRANSPARENT".to_string());
+            lines.push("END:VEVENT".to_string());
+            exported_festivals += 1;
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        let content: String = lines.iter().map(|line| fold_line(line) + "\r\n").collect();
+        match fs::write(file_name, content) {
+            Ok(_) => print_aesthetic_message(vec![tr!(
+                "calendar-exported",
+                birthdays = exported,
+                festivals = exported_festivals,
+                file = file_name
+            )]),
+            Err(e) => print_aesthetic_message(vec![
+                tr!("calendar-write-error", file = file_name),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    async fn parse_mapping(
+        pool: &Pool,
+        arguments: &[&str],
+    ) -> Result<Option<CalendarMapping>, Box<dyn Error>> {
+        let this_year = Utc::now().year();
+
+        match arguments.first() {
+            None => Ok(Some(CalendarMapping::Year {
+                spring_start: NaiveDate::from_ymd_opt(this_year, 3, 1).unwrap(),
+            })),
+            Some(&"year") => {
+                let spring_start = match arguments.get(1) {
+                    None => NaiveDate::from_ymd_opt(this_year, 3, 1),
+                    Some(month_day) => NaiveDate::parse_from_str(
+                        &format!("{}-{}", this_year, month_day),
+                        "%Y-%m-%d",
+                    )
+                    .ok(),
+                };
+                match spring_start {
+                    Some(spring_start) => Ok(Some(CalendarMapping::Year { spring_start })),
+                    None => {
+                        println!("{}", tr!("calendar-invalid-spring-start"));
+                        Ok(None)
+                    }
+                }
+            }
+            Some(&"play") => {
+                let real_start = match arguments.get(1) {
+                    None => Utc::now().date_naive(),
+                    Some(date) => match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                        Ok(d) => d,
+                        Err(_) => {
+                            println!("{}", tr!("calendar-invalid-real-date", input = *date));
+                            return Ok(None);
+                        }
+                    },
+                };
+                let in_game_start = if arguments.len() >= 4 {
+                    let season = match string_to_season(arguments[2]) {
+                        Some(s) => s,
+                        None => return Ok(None),
+                    };
+                    let day = match string_to_day(arguments[3]) {
+                        Some(d) => d,
+                        None => return Ok(None),
+                    };
+                    StardewDate { season, day }
+                } else {
+                    match read_active_save(pool).await? {
+                        Some(save) => save.date,
+                        None => StardewDate {
+                            season: Season::Spring,
+                            day: 1,
+                        },
+                    }
+                };
+                Ok(Some(CalendarMapping::Play {
+                    real_start,
+                    in_game_start,
+                }))
+            }
+            Some(_) => {
+                print_export_calendar_help();
+                Ok(None)
+            }
+        }
+    }
+
+    fn print_export_calendar_help() {
+        println!("{}", tr!("calendar-usage"));
+        println!("{}", tr!("calendar-usage-year"));
+        println!("{}", tr!("calendar-usage-play"));
+    }
+
+    // RFC 5545 text values need backslashes, commas, semicolons and line breaks escaped,
+    // and a Windows line break is still a single one
+    fn escape_text(text: &str) -> String {
+        text.replace('\\', "\\\\")
+            .replace(';', "\\;")
+            .replace(',', "\\,")
+            .replace("\r\n", "\\n")
+            .replace(['\r', '\n'], "\\n")
+    }
+
+    // RFC 5545 lines should not be longer than 75 octets, longer ones continue on lines starting with a space
+    fn fold_line(line: &str) -> String {
+        let mut folded = String::new();
+        let mut line_length = 0;
+        for c in line.chars() {
+            if line_length + c.len_utf8() > 75 {
+                folded.push_str("\r\n ");
+                line_length = 1;
+            }
+            folded.push(c);
+            line_length += c.len_utf8();
+        }
+        folded
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn date(season: Season, day: u8) -> StardewDate {
+            StardewDate { season, day }
+        }
+
+        fn real(year: i32, month: u32, day: u32) -> NaiveDate {
+            NaiveDate::from_ymd_opt(year, month, day).unwrap()
+        }
+
+        #[test]
+        fn special_characters_and_line_breaks_are_escaped() {
+            assert_eq!(
+                escape_text("Gems; Amethyst, Topaz"),
+                "Gems\\; Amethyst\\, Topaz"
+            );
+            assert_eq!(escape_text("C:\\Saves"), "C:\\\\Saves");
+            assert_eq!(
+                escape_text("one\ntwo\r\nthree\rfour"),
+                "one\\ntwo\\nthree\\nfour"
+            );
+        }
+
+        #[test]
+        fn lines_are_folded_after_75_octets() {
+            let exact = "a".repeat(75);
+            assert_eq!(fold_line(&exact), exact);
+            assert_eq!(
+                fold_line(&"a".repeat(76)),
+                format!("{}\r\n a", "a".repeat(75))
+            );
+        }
+
+        #[test]
+        fn multi_byte_characters_are_never_split() {
+            // 'é' is two octets and would end at octet 76
+            assert_eq!(
+                fold_line(&format!("{}é", "a".repeat(74))),
+                format!("{}\r\n é", "a".repeat(74))
+            );
+
+            let line = "SUMMARY:🎂 Émily's birthday ".repeat(10);
+            let folded = fold_line(&line);
+            assert!(folded.split("\r\n").all(|l| l.len() <= 75));
+            assert!(folded.split("\r\n").skip(1).all(|l| l.starts_with(' ')));
+            assert_eq!(folded.replace("\r\n ", ""), line);
+        }
+
+        #[test]
+        fn a_stardew_year_is_stretched_over_a_real_year() {
+            let mapping = CalendarMapping::Year {
+                spring_start: real(2026, 3, 1),
+            };
+            assert_eq!(
+                mapping.real_date(&date(Season::Spring, 1)),
+                Some(real(2026, 3, 1))
+            );
+            // 92 real days for 28 in-game ones
+            assert_eq!(
+                mapping.real_date(&date(Season::Spring, 15)),
+                Some(real(2026, 4, 16))
+            );
+            assert_eq!(
+                mapping.real_date(&date(Season::Summer, 1)),
+                Some(real(2026, 6, 1))
+            );
+            assert_eq!(
+                mapping.real_date(&date(Season::Winter, 28)),
+                Some(real(2027, 2, 25))
+            );
+            assert_eq!(mapping.recurrence_rule(), "FREQ=YEARLY");
+        }
+
+        #[test]
+        fn play_mode_counts_one_real_day_per_in_game_day() {
+            let mapping = CalendarMapping::Play {
+                real_start: real(2026, 10, 18),
+                in_game_start: date(Season::Summer, 5),
+            };
+            assert_eq!(
+                mapping.real_date(&date(Season::Summer, 5)),
+                Some(real(2026, 10, 18))
+            );
+            assert_eq!(
+                mapping.real_date(&date(Season::Summer, 6)),
+                Some(real(2026, 10, 19))
+            );
+            // a day that has passed this year comes round again next year
+            assert_eq!(
+                mapping.real_date(&date(Season::Spring, 5)),
+                Some(real(2027, 1, 10))
+            );
+            assert_eq!(mapping.recurrence_rule(), "FREQ=DAILY;INTERVAL=112");
+        }
+    }
+}
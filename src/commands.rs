@@ -1,7 +1,30 @@
 pub mod terminal_commands {
-    use crate::{print_aesthetic_message, Character, Season};
-    use sqlx::{mysql::*, query, Row};
-    use std::{error::Error, str::FromStr};
+    use crate::cache::cache_commands::*;
+    use crate::calendar::calendar_commands::*;
+    use crate::festivals::festival_commands::*;
+    use crate::friendships::friendship_commands::*;
+    use crate::gifts::gift_commands::*;
+    use crate::health::health_commands::*;
+    use crate::import::import_commands::*;
+    use crate::localization::localization_commands::*;
+    use crate::lookup::lookup_commands::*;
+    use crate::migrations::migration_commands::*;
+    use crate::mods::mod_commands::*;
+    use crate::notes::note_commands::*;
+    use crate::profiles::profile_commands::*;
+    use crate::relationships::relationship_commands::*;
+    use crate::report::report_commands::*;
+    use crate::romance::romance_commands::*;
+    use crate::schedules::schedule_commands::*;
+    use crate::search::search_commands::*;
+    use crate::storage::backend::{
+        checked_query, checked_query_as, Pool, Query, QueryAs, ROWS_AHEAD,
+    };
+    use crate::{
+        print_aesthetic_message, read_notes_and_tags_of, Character, CharacterRecord, Pager, Season,
+        VANILLA_SOURCE,
+    };
+    use std::{error::Error, io, str::FromStr};
     use strum_macros::{AsRefStr, EnumString};
 
     #[derive(PartialEq)]
@@ -9,6 +32,41 @@ pub mod terminal_commands {
         Add,
         Read,
         Change,
+        Delete,
+        Alias,
+        Search,
+        Note,
+        Tag,
+        Untag,
+        Recommend,
+        Inventory,
+        Taste,
+        Save,
+        Gift,
+        Talk,
+        Status,
+        ExportCalendar,
+        Report,
+        Relate,
+        Family,
+        Graph,
+        Schedule,
+        Where,
+        WhoIsAt,
+        Festivals,
+        Upcoming,
+        Romance,
+        NextEvent,
+        Profile,
+        Config,
+        Health,
+        Migrate,
+        Stats,
+        ImportSave,
+        ImportMod,
+        UninstallMod,
+        Language,
+        Translate,
         Quit,
         None,
     }
@@ -29,126 +87,482 @@ pub mod terminal_commands {
     }
 
     pub async fn execute_command(
-        pool: &MySqlPool,
+        session: &mut Session,
         command: &str,
         arguments: Vec<&str>,
     ) -> Result<Command, Box<dyn Error>> {
+        let pool = &session.pool;
         match command {
             "add" => {
-                let executed_command = handle_adding_character(pool, arguments).await;
+                let executed_command =
+                    handle_adding_character(pool, &mut session.cache, arguments).await;
                 match executed_command {
                     Ok(_) => Ok(Command::Add),
                     Err(e) => Err(e),
                 }
             }
             "read" => {
-                let executed_command = handle_read_command(pool, arguments).await;
+                let executed_command =
+                    handle_read_command(pool, &mut session.cache, arguments).await;
                 match executed_command {
                     Ok(_) => Ok(Command::Read),
                     Err(e) => Err(e),
                 }
             }
             "change" => {
-                let executed_command = handle_changing_character(pool, arguments).await;
+                let executed_command =
+                    handle_changing_character(pool, &mut session.cache, arguments).await;
                 match executed_command {
                     Ok(_) => Ok(Command::Change),
                     Err(e) => Err(e),
                 }
             }
+            "delete" => {
+                let executed_command =
+                    handle_deleting_character(pool, &mut session.cache, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Delete),
+                    Err(e) => Err(e),
+                }
+            }
+            "alias" => {
+                let executed_command = handle_alias_command(pool, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::Alias),
+                    Err(e) => Err(e),
+                }
+            }
+            "search" => {
+                let executed_command = handle_search_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Search),
+                    Err(e) => Err(e),
+                }
+            }
+            "note" => {
+                let executed_command = handle_note_command(pool, arguments).await;
+                // the cache keeps notes and tags too, and can't tell which character these commands,
+                // mods, profiles and migrations change, so it starts over
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::Note),
+                    Err(e) => Err(e),
+                }
+            }
+            "tag" => {
+                let executed_command = handle_tag_command(pool, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::Tag),
+                    Err(e) => Err(e),
+                }
+            }
+            "untag" => {
+                let executed_command = handle_untag_command(pool, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::Untag),
+                    Err(e) => Err(e),
+                }
+            }
+            "recommend" => {
+                let executed_command = handle_recommend_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Recommend),
+                    Err(e) => Err(e),
+                }
+            }
+            "inventory" => {
+                let executed_command = handle_inventory_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Inventory),
+                    Err(e) => Err(e),
+                }
+            }
+            "taste" => {
+                let executed_command = handle_taste_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Taste),
+                    Err(e) => Err(e),
+                }
+            }
+            "save" => {
+                let executed_command = handle_save_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Save),
+                    Err(e) => Err(e),
+                }
+            }
+            "gift" => {
+                let executed_command = handle_gift_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Gift),
+                    Err(e) => Err(e),
+                }
+            }
+            "talk" => {
+                let executed_command = handle_talk_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Talk),
+                    Err(e) => Err(e),
+                }
+            }
+            "status" => {
+                let executed_command = handle_status_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Status),
+                    Err(e) => Err(e),
+                }
+            }
+            "export-calendar" => {
+                let executed_command = handle_export_calendar_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::ExportCalendar),
+                    Err(e) => Err(e),
+                }
+            }
+            "report" => {
+                let executed_command = handle_report_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Report),
+                    Err(e) => Err(e),
+                }
+            }
+            "relate" => {
+                let executed_command = handle_relate_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Relate),
+                    Err(e) => Err(e),
+                }
+            }
+            "family" => {
+                let executed_command = handle_family_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Family),
+                    Err(e) => Err(e),
+                }
+            }
+            "graph" => {
+                let executed_command = handle_graph_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Graph),
+                    Err(e) => Err(e),
+                }
+            }
+            "schedule" => {
+                let executed_command = handle_schedule_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Schedule),
+                    Err(e) => Err(e),
+                }
+            }
+            "where" => {
+                let executed_command = handle_where_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Where),
+                    Err(e) => Err(e),
+                }
+            }
+            "who-is-at" => {
+                let executed_command = handle_who_is_at_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::WhoIsAt),
+                    Err(e) => Err(e),
+                }
+            }
+            "festivals" => {
+                let executed_command = handle_festivals_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Festivals),
+                    Err(e) => Err(e),
+                }
+            }
+            "upcoming" => {
+                let executed_command = handle_upcoming_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Upcoming),
+                    Err(e) => Err(e),
+                }
+            }
+            "romance" => {
+                let executed_command = handle_romance_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Romance),
+                    Err(e) => Err(e),
+                }
+            }
+            "next-event" => {
+                let executed_command = handle_next_event_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::NextEvent),
+                    Err(e) => Err(e),
+                }
+            }
+            "profile" => {
+                let executed_command = handle_profile_command(session, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::Profile),
+                    Err(e) => Err(e),
+                }
+            }
+            "config" => {
+                let executed_command = handle_config_command(session, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Config),
+                    Err(e) => Err(e),
+                }
+            }
+            "health" | "ping" => {
+                let executed_command = handle_health_command(session, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Health),
+                    Err(e) => Err(e),
+                }
+            }
+            "migrate" => {
+                let executed_command = handle_migrate_command(session, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::Migrate),
+                    Err(e) => Err(e),
+                }
+            }
+            "stats" => {
+                let executed_command = handle_stats_command(session, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Stats),
+                    Err(e) => Err(e),
+                }
+            }
+            "import-save" => {
+                let executed_command = handle_import_save_command(pool, arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::ImportSave),
+                    Err(e) => Err(e),
+                }
+            }
+            "import-mod" => {
+                let executed_command = handle_import_mod_command(pool, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::ImportMod),
+                    Err(e) => Err(e),
+                }
+            }
+            "uninstall-mod" => {
+                let executed_command = handle_uninstall_mod_command(pool, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::UninstallMod),
+                    Err(e) => Err(e),
+                }
+            }
+            "language" => {
+                let executed_command = handle_language_command(arguments).await;
+                match executed_command {
+                    Ok(_) => Ok(Command::Language),
+                    Err(e) => Err(e),
+                }
+            }
+            "translate" => {
+                let executed_command = handle_translate_command(pool, arguments).await;
+                session.cache.clear();
+                match executed_command {
+                    Ok(_) => Ok(Command::Translate),
+                    Err(e) => Err(e),
+                }
+            }
             "quit" => {
-                println!("Quitting the program.");
+                println!("{}", tr!("quitting"));
                 Ok(Command::Quit)
             }
             _ => {
-                println!("❓ Command does not exist.");
+                println!("{}", tr!("command-unknown"));
                 Ok(Command::None)
             }
         }
     }
 
-    async fn convert_row_to_character(row: MySqlRow) -> Character {
-        let name = row.get::<String, _>("name");
-        let birthday_season = Season::from_str(&row.get::<String, _>("birthday_season")).unwrap();
-        let birthday_day = row.get::<i32, _>("birthday_day") as u8;
-        let is_bachelor = row.get::<bool, _>("is_bachelor");
-        let best_gift = row.get::<String, _>("best_gift");
-        Character {
-            name,
-            birthday_season,
-            birthday_day,
-            is_bachelor,
-            best_gift,
+    // a command that only reads can safely run again after a lost connection; one that writes
+    // might already have been carried out before the connection dropped
+    pub fn is_read_only(command: &str, arguments: &[&str]) -> bool {
+        match command {
+            "read" | "search" | "recommend" | "export-calendar" | "report" | "family" | "graph"
+            | "where" | "who-is-at" | "upcoming" | "next-event" | "config" | "health" | "ping"
+            | "stats" | "language" | "quit" => true,
+            "alias" | "note" => arguments.len() <= 1,
+            "translate" => arguments.len() < 4,
+            "status" | "inventory" => arguments.is_empty(),
+            "festivals" => arguments.first() != Some(&"add"),
+            "romance" => arguments.get(1) != Some(&"seen"),
+            "save" | "profile" => arguments.first() == Some(&"list"),
+            "migrate" => arguments.first() == Some(&"status") || arguments.contains(&"--dry-run"),
+            _ => false,
         }
     }
 
     async fn handle_read_command(
-        pool: &MySqlPool,
+        pool: &Pool,
+        cache: &mut CharacterCache,
         arguments: Vec<&str>,
     ) -> Result<(), Box<dyn Error>> {
         if arguments.is_empty() {
-            println!("Provide an argument, like 'all' to read all characters or 'Abigail' to read specific character.");
+            println!("{}", tr!("read-usage"));
             return Ok(());
         }
-        if arguments[0] == "all" {
+        if matches_text(arguments[0], "argument-all") {
             read_all(pool).await
+        } else if let Some(tag) = arguments[0].strip_prefix("tag=") {
+            read_by_tag(pool, tag).await
+        } else if arguments[0] == "--source" {
+            match arguments.get(1) {
+                Some(source) => read_by_source(pool, source).await,
+                None => {
+                    println!("{}", tr!("read-source-usage"));
+                    Ok(())
+                }
+            }
+        } else if let Some(character) = cache.get(&arguments.join(" ")) {
+            character.print_info();
+            Ok(())
         } else {
-            read_character(pool, arguments.join("")).await
+            let input = arguments.join(" ");
+            match resolve_character(pool, &input).await? {
+                Some(character_name) => read_character(pool, cache, &input, character_name).await,
+                None => Ok(()),
+            }
+        }
+    }
+
+    // characters are read READ_PAGE_SIZE at a time, each page starting after the last name of the
+    // one before, so even tens of thousands of them never have to be in memory at once. The notes
+    // and tags of a page are read together once its query is done and has given back its connection,
+    // so this works even if the pool only has one.
+    const READ_PAGE_SIZE: i64 = ROWS_AHEAD as i64;
+
+    // prints the characters page by page and returns how many there were.
+    // next_page gets the name the page should start after, "" for the first one.
+    async fn read_pages(
+        pool: &Pool,
+        next_page: impl Fn(String) -> QueryAs<CharacterRecord, Character>,
+    ) -> Result<usize, Box<dyn Error>> {
+        let mut pager = Pager::new();
+        let mut after = String::new();
+        let mut count = 0;
+        loop {
+            let mut rows = next_page(after.clone()).fetch(pool);
+            let mut page = vec![];
+            while let Some(character) = rows.next().await {
+                page.push(character?);
+            }
+            read_notes_and_tags_of(pool, &mut page).await?;
+            for character in &page {
+                character.print_info();
+            }
+            let on_page = page.len();
+            if let Some(last) = page.pop() {
+                after = last.name;
+            }
+            count += on_page;
+            if on_page < READ_PAGE_SIZE as usize || !pager.next_page(on_page) {
+                return Ok(count);
+            }
         }
     }
 
-    async fn read_all(pool: &MySqlPool) -> Result<(), Box<dyn Error>> {
-        let read_query = "SELECT * FROM characters";
+    async fn read_all(pool: &Pool) -> Result<(), Box<dyn Error>> {
+        read_pages(pool, |after| {
+            checked_query_as!(
+                CharacterRecord => Character,
+                "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters WHERE name > $1 ORDER BY name LIMIT $2",
+                after,
+                READ_PAGE_SIZE
+            )
+        })
+        .await?;
 
-        let rows = query(read_query).fetch_all(pool).await?;
+        Ok(())
+    }
 
-        let mut characters: Vec<Character> = vec![];
+    async fn read_by_source(pool: &Pool, source: &str) -> Result<(), Box<dyn Error>> {
+        let count = read_pages(pool, |after| {
+            checked_query_as!(
+                CharacterRecord => Character,
+                "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters WHERE source = $1 AND name > $2 ORDER BY name LIMIT $3",
+                source,
+                after,
+                READ_PAGE_SIZE
+            )
+        })
+        .await?;
 
-        for row in rows {
-            characters.push(convert_row_to_character(row).await);
+        if count == 0 {
+            print_aesthetic_message(vec![tr!("read-source-empty", source = source)]);
         }
 
-        for character in characters {
-            character.print_info();
+        Ok(())
+    }
+
+    async fn read_by_tag(pool: &Pool, tag: &str) -> Result<(), Box<dyn Error>> {
+        // the same way 'tag' saved it, so 'tag=#Gems' finds 'gems'
+        let normalized = normalize_tag(tag).unwrap_or_default();
+        let count = read_pages(pool, |after| {
+            checked_query_as!(
+                CharacterRecord => Character,
+                "SELECT c.name, c.birthday_season, c.birthday_day, c.is_bachelor, c.best_gift, c.source FROM characters c JOIN tags t ON t.character_name = c.name WHERE t.tag = $1 AND c.name > $2 ORDER BY c.name LIMIT $3",
+                &normalized,
+                after,
+                READ_PAGE_SIZE
+            )
+        })
+        .await?;
+
+        if count == 0 {
+            print_aesthetic_message(vec![tr!("read-tag-empty", tag = tag)]);
         }
 
         Ok(())
     }
 
     async fn read_character(
-        pool: &MySqlPool,
+        pool: &Pool,
+        cache: &mut CharacterCache,
+        input: &str,
         character_name: String,
     ) -> Result<(), Box<dyn Error>> {
-        let read_query = "SELECT * FROM characters WHERE name = ?";
-
-        let row = query(read_query)
-            .bind(&character_name)
-            .fetch_optional(pool)
-            .await?;
+        let row = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters WHERE name = $1",
+            &character_name
+        )
+        .fetch_optional(pool)
+        .await?;
 
         match row {
-            Some(existing_row) => {
-                let character = convert_row_to_character(existing_row).await;
+            Some(mut character) => {
+                character.read_notes_and_tags(pool).await?;
+                cache.insert(input, &character);
                 character.print_info();
             }
-            None => print_aesthetic_message(vec![format!(
-                "Sorry, I can't find {} in the database!",
-                &character_name
-            )]),
+            None => {
+                print_aesthetic_message(vec![tr!("character-not-found", name = &character_name)])
+            }
         }
 
         Ok(())
     }
 
     async fn handle_adding_character(
-        pool: &MySqlPool,
+        pool: &Pool,
+        cache: &mut CharacterCache,
         arguments: Vec<&str>,
     ) -> Result<(), Box<dyn Error>> {
         if arguments.is_empty() || arguments.len() < 5 {
-            println!("‼ Please provide arguments in the following order: name, birthday season, birthday day, bachelor status, best gift. Right now, I see you only entered {} arguments, whereas I need 5.", arguments.len());
-            println!(
-                "For example for bachelorette Abigail this would be: abigail fall 13 true amethyst"
-            );
-            println!("Give it a try! :)");
+            println!("{}", tr!("add-usage", count = arguments.len()));
+            println!("{}", tr!("add-example"));
+            println!("{}", tr!("try-it"));
             return Ok(());
         }
 
@@ -172,7 +586,7 @@ pub mod terminal_commands {
             None => return Ok(()),
         };
 
-        let best_gift = arguments[4..].join(" ");
+        let best_gift = canonical_name(&TranslationKind::Item, &arguments[4..].join(" "));
 
         let character_to_add = Character {
             name,
@@ -180,144 +594,370 @@ pub mod terminal_commands {
             birthday_day,
             is_bachelor,
             best_gift,
+            source: VANILLA_SOURCE.to_string(),
+            notes: vec![],
+            tags: vec![],
         };
         character_to_add.add_to_database(pool, true, true).await?;
+        cache.invalidate(&character_to_add.name);
 
         Ok(())
     }
 
     async fn handle_changing_character(
-        pool: &MySqlPool,
+        pool: &Pool,
+        cache: &mut CharacterCache,
         arguments: Vec<&str>,
     ) -> Result<(), Box<dyn Error>> {
         if arguments.is_empty() || arguments.len() < 3 {
-            println!("‼ Please provide arguments in the following order: name of the character you want to change, name of the value you want to change, then the value you want to set it to. Right now, I see you only entered {} arguments, whereas I need 3.", arguments.len());
-            println!("For example , if you want to change Abigail's birthday season, you need to write the following arguments: 'abigail birthday_season summer'.");
-            println!("The following value names are available: name, birthday_season, birthday_day, is_bachelor, best_gift");
-            println!("Give it a try! :)");
+            println!("{}", tr!("change-usage", count = arguments.len()));
+            println!("{}", tr!("change-example"));
+            println!("{}", tr!("change-values"));
+            println!("{}", tr!("try-it"));
             return Ok(());
         }
 
-        let character_name = arguments[0].to_string();
+        let character_name = match resolve_character(pool, arguments[0]).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
 
         let value_name_result = DbValue::from_str(&arguments[1].to_lowercase());
         let value_name = match value_name_result {
             Ok(v) => v,
             Err(_) => {
-                println!("Couldn't recognize value name you typed!");
-                println!("The following value names are available: name, birthday_season, birthday_day, is_bachelor, best_gift");
+                println!("{}", tr!("change-unknown-value"));
+                println!("{}", tr!("change-values"));
                 return Ok(());
             }
         };
 
-        let change_query: query::Query<MySql, MySqlArguments>;
-        let query_string;
-        match value_name {
-            DbValue::Name | DbValue::Best_Gift => {
-                query_string = format!(
-                    "UPDATE characters SET {} = ? WHERE name = ?",
-                    value_name.as_ref().to_lowercase()
-                );
-                change_query = query(&query_string)
-                    .bind(arguments[2..].join(" "))
-                    .bind(&character_name);
-            }
-            DbValue::Birthday_Season => {
-                let birthday_season_result = string_to_season(arguments[2]);
-                match birthday_season_result {
-                    Some(s) => {
-                        query_string = format!(
-                            "UPDATE characters SET {} = ? WHERE name = ?",
-                            value_name.as_ref().to_lowercase()
-                        );
-                        change_query = query(&query_string)
-                            .bind(s.as_ref().to_lowercase())
-                            .bind(&character_name);
-                    }
-                    None => return Ok(()),
-                }
-            }
-            DbValue::Birthday_Day => {
-                let day = string_to_day(arguments[2]);
-                match day {
-                    Some(d) => {
-                        query_string = format!(
-                            "UPDATE characters SET {} = ? WHERE name = ?",
-                            value_name.as_ref().to_lowercase()
-                        );
-                        change_query = query(&query_string).bind(d).bind(&character_name);
-                    }
-                    None => return Ok(()),
-                }
-            }
-            DbValue::Is_Bachelor => {
-                let bool = string_to_bachelor_bool(arguments[2]);
-                match bool {
-                    Some(b) => {
-                        query_string = format!(
-                            "UPDATE characters SET {} = ? WHERE name = ?",
-                            value_name.as_ref().to_lowercase()
-                        );
-                        change_query = query(&query_string).bind(b).bind(&character_name);
-                    }
-                    None => return Ok(()),
-                }
-            }
-        }
+        // one statement per column, so that each of them is checked like any other query
+        let change_query: Query = match value_name {
+            DbValue::Name => checked_query!(
+                "UPDATE characters SET name = $1 WHERE name = $2",
+                arguments[2..].join(" "),
+                &character_name
+            ),
+            DbValue::Best_Gift => checked_query!(
+                "UPDATE characters SET best_gift = $1 WHERE name = $2",
+                canonical_name(&TranslationKind::Item, &arguments[2..].join(" ")),
+                &character_name
+            ),
+            DbValue::Birthday_Season => match string_to_season(arguments[2]) {
+                Some(s) => checked_query!(
+                    "UPDATE characters SET birthday_season = $1 WHERE name = $2",
+                    s.as_ref().to_lowercase(),
+                    &character_name
+                ),
+                None => return Ok(()),
+            },
+            DbValue::Birthday_Day => match string_to_day(arguments[2]) {
+                Some(d) => checked_query!(
+                    "UPDATE characters SET birthday_day = $1 WHERE name = $2",
+                    i32::from(d),
+                    &character_name
+                ),
+                None => return Ok(()),
+            },
+            DbValue::Is_Bachelor => match string_to_bachelor_bool(arguments[2]) {
+                Some(b) => checked_query!(
+                    "UPDATE characters SET is_bachelor = $1 WHERE name = $2",
+                    b,
+                    &character_name
+                ),
+                None => return Ok(()),
+            },
+        };
 
         let result = change_query.execute(pool).await;
+        cache.invalidate(&character_name);
         match result {
-            Ok(_) => {
-                print_aesthetic_message(vec!["✅ The change took place! Try the command 'read' with the character's name to check out your changes."]);
-                Ok(())
+            // the character was deleted or renamed since it was looked up
+            Ok(result) if result.rows_affected() == 0 => {
+                print_aesthetic_message(vec![tr!("change-not-found", name = &character_name)]);
             }
-            Err(_e) if matches!(sqlx::Error::RowNotFound, _e) => {
-                print_aesthetic_message(vec![format!(
-                    "Can't find character with the name '{}' in the database!",
-                    &character_name
-                )]);
-                Ok(())
-            }
-            Err(e) => {
-                print_aesthetic_message(vec![
-                    "Error happened when trying to change character in database!",
-                    &format!("{e}"),
-                ]);
-                Ok(())
+            Ok(_) => print_aesthetic_message(vec![tr!("change-done")]),
+            // e.g. a new name that another character already has
+            Err(sqlx::Error::Database(e)) => {
+                print_aesthetic_message(vec![tr!("change-error"), format!("{e}")]);
             }
+            Err(e) => return Err(e.into()),
+        }
+        Ok(())
+    }
+
+    async fn handle_config_command(
+        session: &Session,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        match arguments.first() {
+            Some(&"show") | None => print_aesthetic_message(session.config.describe()),
+            Some(_) => println!("{}", tr!("config-usage")),
         }
+        Ok(())
     }
 
-    fn string_to_season(string: &str) -> Option<Season> {
-        let birthday_season_result = Season::from_str(string);
+    async fn handle_deleting_character(
+        pool: &Pool,
+        cache: &mut CharacterCache,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("delete-usage"));
+            return Ok(());
+        }
+
+        let character_name = match resolve_character(pool, &arguments.join(" ")).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        println!("{}", tr!("delete-confirm", name = &character_name));
+        let mut confirmation = String::new();
+        io::stdin()
+            .read_line(&mut confirmation)
+            .expect("Failed to read input!");
+        if !confirmation.trim().eq_ignore_ascii_case(&character_name) {
+            println!("{}", tr!("delete-cancelled"));
+            return Ok(());
+        }
+
+        delete_character(pool, &character_name).await?;
+        cache.invalidate(&character_name);
+        print_aesthetic_message(vec![tr!("delete-done", name = &character_name)]);
+
+        Ok(())
+    }
+
+    async fn delete_character(pool: &Pool, character_name: &str) -> Result<(), sqlx::Error> {
+        checked_query!("DELETE FROM characters WHERE name = $1", character_name)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) fn string_to_season(string: &str) -> Option<Season> {
+        let birthday_season_result = parse_season(string);
         match birthday_season_result {
-            Ok(season) => Some(season),
-            Err(_) => {
-                println!("‼ Please provide a proper argument for 'birthday_season' value. I received '{}', meanwhile I can only interpret 'spring', 'summer', 'fall' or 'winter'.", string);
+            Some(season) => Some(season),
+            None => {
+                println!("{}", tr!("invalid-season", input = string));
                 None
             }
         }
     }
 
-    fn string_to_day(string: &str) -> Option<u8> {
+    pub(crate) fn string_to_day(string: &str) -> Option<u8> {
         let birthday_day_result = string.parse::<u8>();
         match birthday_day_result {
             Ok(number) if number <= 28 => Some(number),
             _ => {
-                println!("‼ Please provide a proper number for the 'birthday_day' value. Remember that Stardew Valley Seasons only have 28 days! (I received {} from you.)", string);
+                println!("{}", tr!("invalid-day", input = string));
                 None
             }
         }
     }
 
     fn string_to_bachelor_bool(string: &str) -> Option<bool> {
-        if string.to_lowercase() == "true" {
+        if string.to_lowercase() == "true" || matches_text(string, "value-true") {
             Some(true)
-        } else if string.to_lowercase() == "false" {
+        } else if string.to_lowercase() == "false" || matches_text(string, "value-false") {
             Some(false)
         } else {
-            println!("‼ Please provide a proper argument for 'is_bachelor' value. I received '{}', meanwhile I can only interpret 'true' or 'false'.", string);
+            println!("{}", tr!("invalid-bachelor", input = string));
             None
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::friendships::friendship_commands::read_character;
+        use crate::storage::backend::query;
+        use sqlx::mysql::MySqlPool;
+        use sqlx::postgres::PgPool;
+        use std::env;
+
+        #[test]
+        fn commands_that_change_nothing_are_read_only() {
+            assert!(is_read_only("read", &["Abigail"]));
+            assert!(is_read_only("alias", &["Abigail"]));
+            assert!(is_read_only("festivals", &[]));
+            assert!(is_read_only("save", &["list"]));
+            assert!(is_read_only("migrate", &["up", "--dry-run"]));
+            assert!(is_read_only("romance", &["Abigail"]));
+        }
+
+        #[test]
+        fn commands_that_write_are_not_read_only() {
+            assert!(!is_read_only("add", &["Abigail"]));
+            assert!(!is_read_only("alias", &["Abigail", "Abby"]));
+            assert!(!is_read_only("festivals", &["add", "Luau"]));
+            assert!(!is_read_only("save", &["new", "Farm"]));
+            assert!(!is_read_only("migrate", &["up"]));
+            assert!(!is_read_only("romance", &["Abigail", "seen"]));
+            assert!(!is_read_only("status", &["Abigail"]));
+            assert!(!is_read_only("unknown", &[]));
+        }
+
+        // a database of its own on the server in TEST_POSTGRES_URL or TEST_MYSQL_URL, with every
+        // migration of that server applied. The tests that need one are ignored unless they are
+        // asked for: TEST_POSTGRES_URL=postgres://... cargo test -- --ignored postgres
+        struct TestDatabase {
+            server: Pool,
+            pool: Pool,
+            name: String,
+        }
+
+        impl TestDatabase {
+            async fn create(variable: &str, test: &str) -> TestDatabase {
+                let url = env::var(variable)
+                    .unwrap_or_else(|_| panic!("{} has to be the URL of a server", variable));
+                let name = format!("stardew_test_{}_{}", test, std::process::id());
+                let server = if url.starts_with("postgres") {
+                    Pool::Postgres(PgPool::connect(&url).await.unwrap())
+                } else {
+                    Pool::MySql(MySqlPool::connect(&url).await.unwrap())
+                };
+                query(&format!("DROP DATABASE IF EXISTS {}", name))
+                    .execute(&server)
+                    .await
+                    .unwrap();
+                query(&format!("CREATE DATABASE {}", name))
+                    .execute(&server)
+                    .await
+                    .unwrap();
+
+                let pool = match &server {
+                    Pool::MySql(p) => {
+                        let options = (*p.connect_options()).clone().database(&name);
+                        Pool::MySql(MySqlPool::connect_with(options).await.unwrap())
+                    }
+                    Pool::Postgres(p) => {
+                        let options = (*p.connect_options()).clone().database(&name);
+                        Pool::Postgres(PgPool::connect_with(options).await.unwrap())
+                    }
+                };
+                match &pool {
+                    Pool::MySql(p) => migrator(pool.backend()).run(p).await.unwrap(),
+                    Pool::Postgres(p) => migrator(pool.backend()).run(p).await.unwrap(),
+                }
+
+                TestDatabase { server, pool, name }
+            }
+
+            async fn drop(self) {
+                self.pool.close().await;
+                // Postgres may not have noticed yet that the connections are gone
+                let force = match self.server {
+                    Pool::MySql(_) => "",
+                    Pool::Postgres(_) => " WITH (FORCE)",
+                };
+                query(&format!("DROP DATABASE {}{}", self.name, force))
+                    .execute(&self.server)
+                    .await
+                    .unwrap();
+                self.server.close().await;
+            }
+        }
+
+        async fn add_read_change_and_delete(pool: &Pool) {
+            let mut cache = CharacterCache::new(None);
+            handle_adding_character(
+                pool,
+                &mut cache,
+                vec!["Krobus", "winter", "1", "false", "Void", "Egg"],
+            )
+            .await
+            .unwrap();
+            let krobus = read_character(pool, "krobus").await.unwrap().unwrap();
+            assert_eq!(krobus.name, "Krobus");
+            assert_eq!(krobus.birthday_season, Season::Winter);
+            assert_eq!(krobus.birthday_day, 1);
+            assert!(!krobus.is_bachelor);
+            assert_eq!(krobus.best_gift, "Void Egg");
+
+            for (value, input) in [
+                ("best_gift", "Pumpkin"),
+                ("birthday_season", "fall"),
+                ("birthday_day", "28"),
+                ("is_bachelor", "true"),
+            ] {
+                handle_changing_character(pool, &mut cache, vec!["Krobus", value, input])
+                    .await
+                    .unwrap();
+            }
+            let krobus = read_character(pool, "Krobus").await.unwrap().unwrap();
+            assert_eq!(krobus.best_gift, "Pumpkin");
+            assert_eq!(krobus.birthday_season, Season::Fall);
+            assert_eq!(krobus.birthday_day, 28);
+            assert!(krobus.is_bachelor);
+
+            delete_character(pool, "Krobus").await.unwrap();
+            assert!(read_character(pool, "Krobus").await.unwrap().is_none());
+        }
+
+        // the INSERT that ends in ON CONFLICT DO NOTHING, which is an INSERT IGNORE on MySQL
+        async fn add_a_relationship_twice(pool: &Pool) {
+            let mut cache = CharacterCache::new(None);
+            for arguments in [
+                vec!["Sam", "summer", "17", "true", "Pizza"],
+                vec!["Sebastian", "winter", "10", "true", "Frozen", "Tear"],
+            ] {
+                handle_adding_character(pool, &mut cache, arguments)
+                    .await
+                    .unwrap();
+            }
+
+            let friends = RelationshipKind::Friend;
+            assert!(add_relationship(pool, "Sebastian", friends, "Sam")
+                .await
+                .unwrap());
+            assert!(!add_relationship(pool, "Sebastian", friends, "Sam")
+                .await
+                .unwrap());
+            assert!(!add_relationship(pool, "sam", friends, "sebastian")
+                .await
+                .unwrap());
+            let stored = checked_query!("SELECT from_character, to_character FROM relationships")
+                .fetch_all(pool)
+                .await
+                .unwrap();
+            assert_eq!(stored.len(), 1);
+            assert_eq!(stored[0].get::<String, _>("from_character"), "Sam");
+        }
+
+        #[tokio::test]
+        #[ignore = "needs a PostgreSQL server in TEST_POSTGRES_URL"]
+        async fn characters_are_added_read_changed_and_deleted_on_postgres() {
+            let database = TestDatabase::create("TEST_POSTGRES_URL", "characters").await;
+            add_read_change_and_delete(&database.pool).await;
+            database.drop().await;
+        }
+
+        #[tokio::test]
+        #[ignore = "needs a MySQL or MariaDB server in TEST_MYSQL_URL"]
+        async fn characters_are_added_read_changed_and_deleted_on_mysql() {
+            let database = TestDatabase::create("TEST_MYSQL_URL", "characters").await;
+            add_read_change_and_delete(&database.pool).await;
+            database.drop().await;
+        }
+
+        #[tokio::test]
+        #[ignore = "needs a PostgreSQL server in TEST_POSTGRES_URL"]
+        async fn known_rows_are_skipped_on_postgres() {
+            let database = TestDatabase::create("TEST_POSTGRES_URL", "upsert").await;
+            add_a_relationship_twice(&database.pool).await;
+            database.drop().await;
+        }
+
+        #[tokio::test]
+        #[ignore = "needs a MySQL or MariaDB server in TEST_MYSQL_URL"]
+        async fn known_rows_are_skipped_on_mysql() {
+            let database = TestDatabase::create("TEST_MYSQL_URL", "upsert").await;
+            add_a_relationship_twice(&database.pool).await;
+            database.drop().await;
+        }
+    }
 }
@@ -0,0 +1,371 @@
+pub mod festival_commands {
+    use crate::commands::terminal_commands::{string_to_day, string_to_season};
+    use crate::friendships::friendship_commands::read_active_save;
+    use crate::health::health_commands::is_connection_error;
+    use crate::localization::localization_commands::{date_name, tr, value_name};
+    use crate::storage::backend::{checked_query, checked_query_as, from_row, Pool};
+    use crate::{print_aesthetic_message, Character, CharacterRecord, Season, StardewDate};
+    use std::{error::Error, str::FromStr};
+
+    const DEFAULT_UPCOMING_DAYS: u16 = 7;
+
+    pub(crate) struct Festival {
+        pub(crate) name: String,
+        pub(crate) season: Season,
+        pub(crate) start_day: u8,
+        pub(crate) end_day: u8,
+        pub(crate) location: String,
+        pub(crate) closes_shops: bool,
+    }
+
+    // a row of the festivals table, as the checked queries select it
+    struct FestivalRecord {
+        name: String,
+        season: String,
+        start_day: i32,
+        end_day: i32,
+        location: String,
+        closes_shops: bool,
+    }
+
+    from_row!(FestivalRecord {
+        name,
+        season,
+        start_day,
+        end_day,
+        location,
+        closes_shops
+    });
+
+    impl TryFrom<FestivalRecord> for Festival {
+        type Error = String;
+
+        fn try_from(record: FestivalRecord) -> Result<Festival, String> {
+            let day = |day: i32| {
+                u8::try_from(day).map_err(|_| format!("{} has the day {}", record.name, day))
+            };
+            Ok(Festival {
+                season: Season::from_str(&record.season)
+                    .map_err(|_| format!("{} has the season '{}'", record.name, record.season))?,
+                start_day: day(record.start_day)?,
+                end_day: day(record.end_day)?,
+                name: record.name,
+                location: record.location,
+                closes_shops: record.closes_shops,
+            })
+        }
+    }
+
+    impl Festival {
+        pub(crate) fn includes(&self, date: &StardewDate) -> bool {
+            self.season == date.season && (self.start_day..=self.end_day).contains(&date.day)
+        }
+
+        pub(crate) fn start(&self) -> StardewDate {
+            StardewDate {
+                season: self.season,
+                day: self.start_day,
+            }
+        }
+
+        pub(crate) fn end(&self) -> StardewDate {
+            StardewDate {
+                season: self.season,
+                day: self.end_day,
+            }
+        }
+    }
+
+    pub(crate) async fn read_festivals(pool: &Pool) -> Result<Vec<Festival>, Box<dyn Error>> {
+        Ok(checked_query_as!(
+            FestivalRecord => Festival,
+            "SELECT name, season, start_day, end_day, location, closes_shops FROM festivals ORDER BY CASE season WHEN 'Spring' THEN 0 WHEN 'Summer' THEN 1 WHEN 'Fall' THEN 2 ELSE 3 END, start_day"
+        )
+        .fetch_all(pool)
+        .await?)
+    }
+
+    // a birthday on a festival day where the shops are closed, so the gift has to be bought beforehand
+    pub(crate) fn conflicting_festival<'a>(
+        festivals: &'a [Festival],
+        date: &StardewDate,
+    ) -> Option<&'a Festival> {
+        festivals
+            .iter()
+            .find(|festival| festival.closes_shops && festival.includes(date))
+    }
+
+    pub(crate) fn conflict_warning(character_name: &str, festival: &Festival) -> String {
+        tr!(
+            "festival-conflict",
+            name = character_name,
+            festival = festival.name.as_str(),
+            location = festival.location.as_str()
+        )
+    }
+
+    pub async fn handle_festivals_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.first() == Some(&"add") {
+            return add_festival(pool, &arguments[1..]).await;
+        }
+
+        let festivals = read_festivals(pool).await?;
+        let mut messages = vec![tr!("festivals-title")];
+        for festival in &festivals {
+            let days = if festival.start_day == festival.end_day {
+                date_name(&festival.start())
+            } else {
+                format!(
+                    "{} - {}",
+                    date_name(&festival.start()),
+                    date_name(&festival.end())
+                )
+            };
+            let line = format!(
+                "{}: {}",
+                &festival.name,
+                tr!(
+                    "at-location",
+                    what = days,
+                    location = festival.location.as_str()
+                )
+            );
+            if festival.closes_shops {
+                messages.push(format!("{} {}", line, tr!("festivals-shops-closed")));
+            } else {
+                messages.push(line);
+            }
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    // '<name> <season> <first day> [<last day>] at <location>'. None when the input doesn't fit,
+    // after saying why. Festivals added by hand never close the shops.
+    fn parse_festival(arguments: &[&str]) -> Option<Festival> {
+        let at_index = arguments.iter().position(|a| *a == "at");
+        let (name_and_days, location) = match at_index {
+            Some(i) if i >= 3 && i + 1 < arguments.len() => {
+                (&arguments[..i], arguments[i + 1..].join(" "))
+            }
+            _ => {
+                println!("{}", tr!("festivals-add-usage"));
+                println!("{}", tr!("festivals-add-example"));
+                return None;
+            }
+        };
+
+        // the last one or two words before 'at' are the days, the word before them is the season
+        let days: Vec<&str> = name_and_days
+            .iter()
+            .rev()
+            .take_while(|a| a.chars().all(|c| c.is_ascii_digit()))
+            .copied()
+            .collect();
+        if days.is_empty() || days.len() > 2 || name_and_days.len() < days.len() + 2 {
+            println!("{}", tr!("festivals-add-days-usage"));
+            return None;
+        }
+        let season_index = name_and_days.len() - days.len() - 1;
+        let season = string_to_season(name_and_days[season_index])?;
+        let start_day = string_to_day(days[days.len() - 1])?;
+        let end_day = match string_to_day(days[0])? {
+            d if d >= start_day => d,
+            _ => {
+                println!("{}", tr!("festivals-end-before-start"));
+                return None;
+            }
+        };
+
+        Some(Festival {
+            name: name_and_days[..season_index].join(" "),
+            season,
+            start_day,
+            end_day,
+            location,
+            closes_shops: false,
+        })
+    }
+
+    async fn add_festival(pool: &Pool, arguments: &[&str]) -> Result<(), Box<dyn Error>> {
+        let festival = match parse_festival(arguments) {
+            Some(f) => f,
+            None => return Ok(()),
+        };
+        let name = &festival.name;
+
+        let result = checked_query!("INSERT INTO festivals (name, season, start_day, end_day, location, closes_shops) VALUES ($1, $2, $3, $4, $5, FALSE)", name, festival.season.as_ref(), i32::from(festival.start_day), i32::from(festival.end_day), &festival.location)
+            .execute(pool)
+            .await;
+
+        match result {
+            Ok(_) => print_aesthetic_message(vec![tr!("festivals-added", name = name.as_str())]),
+            Err(e) if is_connection_error(&e) => return Err(e.into()),
+            Err(e) => print_aesthetic_message(vec![
+                tr!("festivals-add-error", name = name.as_str()),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    pub async fn handle_upcoming_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let (date, days_argument) = if arguments.len() >= 2 {
+            let season = match string_to_season(arguments[0]) {
+                Some(s) => s,
+                None => return Ok(()),
+            };
+            let day = match string_to_day(arguments[1]) {
+                Some(d) => d,
+                None => return Ok(()),
+            };
+            (StardewDate { season, day }, arguments.get(2))
+        } else {
+            match read_active_save(pool).await? {
+                Some(save) => (save.date, arguments.first()),
+                None => {
+                    println!("{}", tr!("upcoming-no-date"));
+                    println!("{}", tr!("save-date-hint"));
+                    return Ok(());
+                }
+            }
+        };
+        let days = match days_argument.map(|d| d.parse::<u16>()) {
+            None => DEFAULT_UPCOMING_DAYS,
+            Some(Ok(d)) if d > 0 && d <= StardewDate::DAYS_PER_YEAR => d,
+            Some(_) => {
+                println!(
+                    "{}",
+                    tr!("upcoming-invalid-days", max = StardewDate::DAYS_PER_YEAR)
+                );
+                return Ok(());
+            }
+        };
+
+        let characters: Vec<Character> = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters ORDER BY name"
+        )
+        .fetch_all(pool)
+        .await?;
+        let festivals = read_festivals(pool).await?;
+
+        let mut messages = vec![tr!("upcoming-title", days = days, date = date_name(&date))];
+        let mut found = false;
+        for offset in 0..days {
+            let day = date.add_days(offset);
+            let mut events = vec![];
+            for festival in festivals.iter().filter(|f| f.includes(&day)) {
+                events.push(format!(
+                    "🎪 {}",
+                    tr!(
+                        "at-location",
+                        what = festival.name.as_str(),
+                        location = festival.location.as_str()
+                    )
+                ));
+            }
+            for character in &characters {
+                if character.birthday_season == day.season && character.birthday_day == day.day {
+                    events.push(tr!("birthday-of", name = character.name.as_str()));
+                    if let Some(festival) = conflicting_festival(&festivals, &day) {
+                        events.push(conflict_warning(&character.name, festival));
+                    }
+                }
+            }
+            if !events.is_empty() {
+                found = true;
+                messages.push(format!(
+                    "{} ({}):",
+                    date_name(&day),
+                    value_name("weekday", &day.weekday())
+                ));
+                messages.extend(events.into_iter().map(|e| format!("  {}", e)));
+            }
+        }
+        if !found {
+            messages.push(tr!("upcoming-nothing"));
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn festival(season: Season, start_day: u8, end_day: u8, closes_shops: bool) -> Festival {
+            Festival {
+                name: format!("{} {}", season.as_ref(), start_day),
+                season,
+                start_day,
+                end_day,
+                location: "Pelican Town".to_string(),
+                closes_shops,
+            }
+        }
+
+        fn on(season: Season, day: u8) -> StardewDate {
+            StardewDate { season, day }
+        }
+
+        #[test]
+        fn every_day_of_a_festival_that_closes_the_shops_conflicts() {
+            let festivals = [
+                festival(Season::Spring, 13, 13, true),
+                festival(Season::Winter, 15, 17, true),
+                festival(Season::Summer, 28, 28, false),
+            ];
+            let conflict = |date| conflicting_festival(&festivals, &date).map(|f| f.name.as_str());
+
+            assert_eq!(conflict(on(Season::Winter, 14)), None);
+            assert_eq!(conflict(on(Season::Winter, 15)), Some("Winter 15"));
+            assert_eq!(conflict(on(Season::Winter, 16)), Some("Winter 15"));
+            assert_eq!(conflict(on(Season::Winter, 17)), Some("Winter 15"));
+            assert_eq!(conflict(on(Season::Winter, 18)), None);
+            assert_eq!(conflict(on(Season::Spring, 13)), Some("Spring 13"));
+            // the same day in another season, and a festival with the shops open
+            assert_eq!(conflict(on(Season::Fall, 16)), None);
+            assert_eq!(conflict(on(Season::Summer, 28)), None);
+        }
+
+        #[test]
+        fn festivals_are_read_with_one_or_two_days() {
+            let parsed = parse_festival(&[
+                "Night", "Market", "winter", "15", "17", "at", "the", "beach",
+            ])
+            .unwrap();
+            assert_eq!(parsed.name, "Night Market");
+            assert!(parsed.season == Season::Winter);
+            assert_eq!((parsed.start_day, parsed.end_day), (15, 17));
+            assert_eq!(parsed.location, "the beach");
+            assert!(!parsed.closes_shops);
+
+            let parsed = parse_festival(&["Luau", "summer", "11", "at", "Beach"]).unwrap();
+            assert_eq!(parsed.name, "Luau");
+            assert_eq!((parsed.start_day, parsed.end_day), (11, 11));
+        }
+
+        #[test]
+        fn festivals_that_dont_fit_are_refused() {
+            // no location, or nothing before 'at' but the days
+            assert!(parse_festival(&["Luau", "summer", "11"]).is_none());
+            assert!(parse_festival(&["Luau", "summer", "11", "at"]).is_none());
+            assert!(parse_festival(&["summer", "11", "12", "at", "beach"]).is_none());
+            // three days, a last day before the first, a day or season that doesn't exist
+            assert!(parse_festival(&["Fair", "fall", "1", "2", "3", "at", "town"]).is_none());
+            assert!(parse_festival(&["Fair", "fall", "16", "15", "at", "town"]).is_none());
+            assert!(parse_festival(&["Fair", "fall", "16", "29", "at", "town"]).is_none());
+            assert!(parse_festival(&["Fair", "autumn", "16", "at", "town"]).is_none());
+        }
+    }
+}
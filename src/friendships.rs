@@ -0,0 +1,681 @@
+pub mod friendship_commands {
+    use crate::commands::terminal_commands::{string_to_day, string_to_season};
+    use crate::gifts::gift_commands::{
+        read_gift_tastes, taste_of, BIRTHDAY_MULTIPLIER, GIFTS_PER_WEEK,
+    };
+    use crate::health::health_commands::is_connection_error;
+    use crate::localization::localization_commands::{
+        canonical_name, date_name, tr, value_name, TranslationKind,
+    };
+    use crate::lookup::lookup_commands::resolve_character;
+    use crate::storage::backend::{
+        checked_query, checked_query_as, from_row, query, Backend, Pool,
+    };
+    use crate::{print_aesthetic_message, Character, CharacterRecord, Season, StardewDate};
+    use std::{collections::HashMap, error::Error, str::FromStr};
+    use strum_macros::{AsRefStr, EnumString};
+
+    pub(crate) const POINTS_PER_HEART: i32 = 250;
+    const TALKING_POINTS: i32 = 20;
+
+    #[derive(Debug, Clone, Copy, PartialEq, AsRefStr, EnumString)]
+    pub(crate) enum FriendshipStatus {
+        #[strum(ascii_case_insensitive)]
+        Friend,
+        #[strum(ascii_case_insensitive)]
+        Dating,
+        #[strum(ascii_case_insensitive)]
+        Married,
+    }
+
+    impl FriendshipStatus {
+        // bachelors cap at 8 hearts until you date them, spouses can reach 14 hearts
+        fn max_points(&self, is_bachelor: bool) -> i32 {
+            match self {
+                FriendshipStatus::Friend if is_bachelor => 8 * POINTS_PER_HEART,
+                FriendshipStatus::Friend | FriendshipStatus::Dating => 10 * POINTS_PER_HEART,
+                FriendshipStatus::Married => 14 * POINTS_PER_HEART,
+            }
+        }
+    }
+
+    pub(crate) struct Save {
+        pub(crate) id: i32,
+        pub(crate) name: String,
+        pub(crate) date: StardewDate,
+        pub(crate) year: i32,
+    }
+
+    impl Save {
+        // counts days since the start of the farm, so gifts and talks can be compared across seasons and years
+        pub(crate) fn day_number(&self) -> i32 {
+            (self.year - 1) * StardewDate::DAYS_PER_YEAR as i32 + self.date.day_of_year() as i32
+        }
+
+        // day number of the Sunday the current week started on, when the game resets the weekly gifts.
+        // Every season starts on a Monday, so those are days 7, 14, 21 and 28, and the first days of a
+        // season still belong to the week that started on day 28 of the one before.
+        pub(crate) fn week_start(&self) -> i32 {
+            self.day_number() - (self.date.day.max(1) as i32 % 7)
+        }
+    }
+
+    // a row of the saves table, as the checked queries select it
+    struct SaveRecord {
+        id: i32,
+        name: String,
+        season: String,
+        day: i32,
+        year: i32,
+        is_active: bool,
+    }
+
+    from_row!(SaveRecord {
+        id,
+        name,
+        season,
+        day,
+        year,
+        is_active
+    });
+
+    impl TryFrom<SaveRecord> for Save {
+        type Error = String;
+
+        fn try_from(record: SaveRecord) -> Result<Save, String> {
+            Ok(Save {
+                date: StardewDate {
+                    season: Season::from_str(&record.season).map_err(|_| {
+                        format!(
+                            "The save {} has the season '{}'",
+                            record.name, record.season
+                        )
+                    })?,
+                    day: u8::try_from(record.day).map_err(|_| {
+                        format!("The save {} has the day {}", record.name, record.day)
+                    })?,
+                },
+                id: record.id,
+                name: record.name,
+                year: record.year,
+            })
+        }
+    }
+
+    // a save's friendship with one villager
+    struct FriendshipRecord {
+        points: i32,
+        status: String,
+        last_talked: Option<i32>,
+    }
+
+    from_row!(FriendshipRecord {
+        points,
+        status,
+        last_talked
+    });
+
+    // every villager with their friendship in a save, the ones without one yet count as new friends
+    struct FriendshipStatusRecord {
+        name: String,
+        points: Option<i32>,
+        status: Option<String>,
+        last_talked: Option<i32>,
+    }
+
+    from_row!(FriendshipStatusRecord {
+        name,
+        points,
+        status,
+        last_talked
+    });
+
+    // how many gifts each villager got this week in the active save, and whether they got one today
+    pub(crate) struct GiftHistory {
+        pub(crate) this_week: u8,
+        pub(crate) today: bool,
+    }
+
+    pub(crate) async fn read_active_save(pool: &Pool) -> Result<Option<Save>, Box<dyn Error>> {
+        Ok(checked_query_as!(
+            SaveRecord => Save,
+            "SELECT id, name, season, day, year, is_active FROM saves WHERE is_active = TRUE"
+        )
+        .fetch_optional(pool)
+        .await?)
+    }
+
+    pub(crate) async fn require_active_save(pool: &Pool) -> Result<Option<Save>, Box<dyn Error>> {
+        let save = read_active_save(pool).await?;
+        if save.is_none() {
+            println!("{}", tr!("save-none-active"));
+        }
+        Ok(save)
+    }
+
+    pub(crate) async fn read_gift_history(
+        pool: &Pool,
+        save: &Save,
+    ) -> Result<HashMap<String, GiftHistory>, Box<dyn Error>> {
+        let rows = checked_query!("SELECT character_name, COUNT(*) AS gifts, MAX(day_number) AS last_gift FROM gift_log WHERE save_id = $1 AND day_number >= $2 GROUP BY character_name", save.id, save.week_start())
+            .fetch_all(pool)
+            .await?;
+
+        let mut history = HashMap::new();
+        for row in rows {
+            history.insert(
+                row.get::<String, _>("character_name").to_lowercase(),
+                GiftHistory {
+                    this_week: row.get::<i64, _>("gifts") as u8,
+                    today: row.get::<i32, _>("last_gift") == save.day_number(),
+                },
+            );
+        }
+
+        Ok(history)
+    }
+
+    pub(crate) async fn read_character(
+        pool: &Pool,
+        character_name: &str,
+    ) -> Result<Option<Character>, Box<dyn Error>> {
+        let character_name = match resolve_character(pool, character_name).await? {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+        let row = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters WHERE name = $1",
+            &character_name
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        match row {
+            Some(character) => Ok(Some(character)),
+            None => {
+                print_aesthetic_message(vec![tr!("character-not-found", name = character_name)]);
+                Ok(None)
+            }
+        }
+    }
+
+    pub(crate) async fn read_friendship(
+        pool: &Pool,
+        save: &Save,
+        character_name: &str,
+    ) -> Result<(i32, FriendshipStatus, Option<i32>), Box<dyn Error>> {
+        let record = checked_query_as!(
+            FriendshipRecord,
+            "SELECT points, status, last_talked FROM friendships WHERE save_id = $1 AND character_name = $2",
+            save.id,
+            character_name
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(match record {
+            Some(record) => (
+                record.points,
+                FriendshipStatus::from_str(&record.status).unwrap_or(FriendshipStatus::Friend),
+                record.last_talked,
+            ),
+            None => (0, FriendshipStatus::Friend, None),
+        })
+    }
+
+    async fn add_friendship_points(
+        pool: &Pool,
+        save: &Save,
+        character: &Character,
+        points: i32,
+        talked: bool,
+    ) -> Result<i32, Box<dyn Error>> {
+        let (current_points, status, last_talked) =
+            read_friendship(pool, save, &character.name).await?;
+        let new_points =
+            (current_points + points).clamp(0, status.max_points(character.is_bachelor));
+        let last_talked = if talked {
+            Some(save.day_number())
+        } else {
+            last_talked
+        };
+
+        let upsert = match pool.backend() {
+            Backend::MySql => "INSERT INTO friendships (save_id, character_name, points, status, last_talked) VALUES (?, ?, ?, ?, ?) ON DUPLICATE KEY UPDATE points = VALUES(points), last_talked = VALUES(last_talked)",
+            Backend::Postgres => "INSERT INTO friendships (save_id, character_name, points, status, last_talked) VALUES (?, ?, ?, ?, ?) ON CONFLICT (save_id, character_name) DO UPDATE SET points = EXCLUDED.points, last_talked = EXCLUDED.last_talked",
+        };
+        query(upsert)
+            .bind(save.id)
+            .bind(&character.name)
+            .bind(new_points)
+            .bind(status.as_ref())
+            .bind(last_talked)
+            .execute(pool)
+            .await?;
+
+        Ok(new_points)
+    }
+
+    pub(crate) fn hearts(points: i32) -> String {
+        let hearts = points / POINTS_PER_HEART;
+        format!("{} {}", "❤".repeat(hearts as usize), hearts)
+    }
+
+    // "Abigail: ❤❤ 2 (540 points)"
+    pub(crate) fn friendship_line(character_name: &str, points: i32) -> String {
+        tr!(
+            "friendship-points",
+            name = character_name,
+            hearts = hearts(points),
+            points = points
+        )
+    }
+
+    pub async fn handle_save_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("save-usage"));
+            return Ok(());
+        }
+
+        match arguments[0] {
+            "list" => {
+                let saves = checked_query_as!(
+                    SaveRecord,
+                    "SELECT id, name, season, day, year, is_active FROM saves ORDER BY name"
+                )
+                .fetch_all(pool)
+                .await?;
+                if saves.is_empty() {
+                    print_aesthetic_message(vec![tr!("save-list-empty")]);
+                    return Ok(());
+                }
+                let mut messages = vec![tr!("save-list-title")];
+                for save in saves {
+                    let date = StardewDate {
+                        season: Season::from_str(&save.season)?,
+                        day: save.day as u8,
+                    };
+                    messages.push(format!(
+                        "{} {}",
+                        if save.is_active { "➡" } else { " " },
+                        tr!(
+                            "save-line",
+                            name = save.name,
+                            date = date_name(&date),
+                            year = save.year
+                        )
+                    ));
+                }
+                print_aesthetic_message(messages);
+            }
+            "create" if arguments.len() > 1 => {
+                let name = arguments[1..].join(" ");
+                let result = checked_query!("INSERT INTO saves (name, season, day, year, is_active) VALUES ($1, 'Spring', 1, 1, FALSE)", &name)
+                    .execute(pool)
+                    .await;
+                match result {
+                    Ok(_) => {
+                        activate_save(pool, &name).await?;
+                        print_aesthetic_message(vec![tr!("save-created", name = name.as_str())]);
+                    }
+                    Err(e) if is_connection_error(&e) => return Err(e.into()),
+                    Err(e) => print_aesthetic_message(vec![
+                        tr!("save-create-error", name = name.as_str()),
+                        format!("{e}"),
+                    ]),
+                }
+            }
+            "use" if arguments.len() > 1 => {
+                let name = arguments[1..].join(" ");
+                if activate_save(pool, &name).await? {
+                    print_aesthetic_message(vec![tr!("save-switched", name = name.as_str())]);
+                } else {
+                    print_aesthetic_message(vec![tr!("save-not-found", name = name.as_str())]);
+                }
+            }
+            "date" if arguments.len() > 2 => {
+                let save = match require_active_save(pool).await? {
+                    Some(s) => s,
+                    None => return Ok(()),
+                };
+                let season = match string_to_season(arguments[1]) {
+                    Some(s) => s,
+                    None => return Ok(()),
+                };
+                let day = match string_to_day(arguments[2]) {
+                    Some(d) => d,
+                    None => return Ok(()),
+                };
+                let year = match arguments.get(3).map(|y| y.parse::<i32>()) {
+                    None => save.year,
+                    Some(Ok(y)) if y > 0 => y,
+                    Some(_) => {
+                        println!("{}", tr!("save-invalid-year", input = arguments[3]));
+                        return Ok(());
+                    }
+                };
+                checked_query!(
+                    "UPDATE saves SET season = $1, day = $2, year = $3 WHERE id = $4",
+                    season.as_ref(),
+                    i32::from(day),
+                    year,
+                    save.id
+                )
+                .execute(pool)
+                .await?;
+                print_aesthetic_message(vec![tr!(
+                    "save-date-changed",
+                    date = date_name(&StardewDate { season, day }),
+                    year = year,
+                    name = save.name.as_str()
+                )]);
+            }
+            _ => {
+                println!("{}", tr!("save-usage"));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn activate_save(pool: &Pool, name: &str) -> Result<bool, Box<dyn Error>> {
+        let exists = checked_query!("SELECT id FROM saves WHERE name = $1", name)
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+        if exists {
+            checked_query!("UPDATE saves SET is_active = (name = $1)", name)
+                .execute(pool)
+                .await?;
+        }
+        Ok(exists)
+    }
+
+    pub async fn handle_gift_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 2 {
+            println!("{}", tr!("gift-usage"));
+            return Ok(());
+        }
+
+        let save = match require_active_save(pool).await? {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        let character = match read_character(pool, arguments[0]).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let item = canonical_name(&TranslationKind::Item, &arguments[1..].join(" "));
+
+        let is_birthday = character.birthday_season == save.date.season
+            && character.birthday_day == save.date.day;
+        let history = read_gift_history(pool, &save).await?;
+        if let Some(history) = history.get(&character.name.to_lowercase()) {
+            if history.today {
+                print_aesthetic_message(vec![tr!(
+                    "gift-already-today",
+                    name = character.name.as_str()
+                )]);
+                return Ok(());
+            }
+            if !is_birthday && history.this_week >= GIFTS_PER_WEEK {
+                print_aesthetic_message(vec![tr!(
+                    "gift-week-limit",
+                    name = character.name.as_str(),
+                    count = GIFTS_PER_WEEK
+                )]);
+                return Ok(());
+            }
+        }
+
+        let tastes = read_gift_tastes(pool).await?;
+        let taste = taste_of(&character, &tastes, &item);
+        let points = if is_birthday {
+            taste.points() * BIRTHDAY_MULTIPLIER
+        } else {
+            taste.points()
+        };
+
+        checked_query!("INSERT INTO gift_log (save_id, character_name, item, taste, points, is_birthday, season, day, year, day_number) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)", save.id, &character.name, &item, taste.as_ref(), points, is_birthday, save.date.season.as_ref(), i32::from(save.date.day), save.year, save.day_number())
+            .execute(pool)
+            .await?;
+        let new_points = add_friendship_points(pool, &save, &character, points, false).await?;
+
+        checked_query!(
+            "UPDATE inventory SET quantity = quantity - 1 WHERE item = $1",
+            &item
+        )
+        .execute(pool)
+        .await?;
+        checked_query!("DELETE FROM inventory WHERE quantity <= 0")
+            .execute(pool)
+            .await?;
+
+        let mut messages = vec![tr!(
+            if is_birthday {
+                "gift-given-birthday"
+            } else {
+                "gift-given"
+            },
+            item = item.as_str(),
+            name = character.name.as_str(),
+            reaction = tr!(
+                &format!("reaction-{}", taste.as_ref().to_lowercase()),
+                name = character.name.as_str()
+            ),
+            points = format!("{:+}", points)
+        )];
+        messages.push(friendship_line(&character.name, new_points));
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    pub async fn handle_talk_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("talk-usage"));
+            return Ok(());
+        }
+
+        let save = match require_active_save(pool).await? {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        let character = match read_character(pool, &arguments.join(" ")).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let (_, _, last_talked) = read_friendship(pool, &save, &character.name).await?;
+        if last_talked == Some(save.day_number()) {
+            print_aesthetic_message(vec![tr!(
+                "talk-already-today",
+                name = character.name.as_str()
+            )]);
+            return Ok(());
+        }
+
+        let new_points =
+            add_friendship_points(pool, &save, &character, TALKING_POINTS, true).await?;
+        print_aesthetic_message(vec![
+            tr!(
+                "talk-done",
+                name = character.name.as_str(),
+                points = TALKING_POINTS
+            ),
+            friendship_line(&character.name, new_points),
+        ]);
+
+        Ok(())
+    }
+
+    pub async fn handle_status_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let save = match require_active_save(pool).await? {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+
+        if !arguments.is_empty() {
+            return change_friendship_status(pool, &save, arguments).await;
+        }
+
+        let records = checked_query_as!(
+            FriendshipStatusRecord,
+            "SELECT c.name, COALESCE(f.points, 0) AS points, COALESCE(f.status, 'Friend') AS status, f.last_talked FROM characters c LEFT JOIN friendships f ON f.character_name = c.name AND f.save_id = $1 ORDER BY points DESC, c.name",
+            save.id
+        )
+        .fetch_all(pool)
+        .await?;
+        let history = read_gift_history(pool, &save).await?;
+
+        let mut messages = vec![format!(
+            "🌱 {}",
+            tr!(
+                "save-line",
+                name = save.name.as_str(),
+                date = date_name(&save.date),
+                year = save.year
+            )
+        )];
+        for record in records {
+            let name = record.name;
+            let points = record.points.unwrap_or(0);
+            let status = record.status.unwrap_or_default();
+            let talked_today = record.last_talked == Some(save.day_number());
+            let gifts_this_week = history
+                .get(&name.to_lowercase())
+                .map(|h| h.this_week)
+                .unwrap_or(0);
+            let line = tr!(
+                "status-line",
+                name = name.as_str(),
+                hearts = hearts(points),
+                points = points,
+                status = value_name("status", &status),
+                gifts = gifts_this_week,
+                max = GIFTS_PER_WEEK
+            );
+            if talked_today {
+                messages.push(format!("{}, {}", line, tr!("status-talked-today")));
+            } else {
+                messages.push(line);
+            }
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    async fn change_friendship_status(
+        pool: &Pool,
+        save: &Save,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 2 {
+            println!("{}", tr!("status-usage"));
+            return Ok(());
+        }
+
+        let character = match read_character(pool, arguments[0]).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let status = match FriendshipStatus::from_str(arguments[1]) {
+            Ok(s) => s,
+            Err(_) => {
+                println!("{}", tr!("status-invalid", input = arguments[1]));
+                return Ok(());
+            }
+        };
+        if status != FriendshipStatus::Friend && !character.is_bachelor {
+            print_aesthetic_message(vec![tr!(
+                "character-cannot-marry",
+                name = character.name.as_str()
+            )]);
+            return Ok(());
+        }
+
+        let upsert = match pool.backend() {
+            Backend::MySql => "INSERT INTO friendships (save_id, character_name, points, status) VALUES (?, ?, 0, ?) ON DUPLICATE KEY UPDATE status = VALUES(status)",
+            Backend::Postgres => "INSERT INTO friendships (save_id, character_name, points, status) VALUES (?, ?, 0, ?) ON CONFLICT (save_id, character_name) DO UPDATE SET status = EXCLUDED.status",
+        };
+        query(upsert)
+            .bind(save.id)
+            .bind(&character.name)
+            .bind(status.as_ref())
+            .execute(pool)
+            .await?;
+        print_aesthetic_message(vec![tr!(
+            "status-changed",
+            name = character.name.as_str(),
+            role = value_name("status-role", &status)
+        )]);
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::Weekday;
+
+        fn save_on(season: Season, day: u8, year: i32) -> Save {
+            Save {
+                id: 1,
+                name: "Sunny".to_string(),
+                date: StardewDate { season, day },
+                year,
+            }
+        }
+
+        #[test]
+        fn weeks_start_on_sunday() {
+            for day in 1..=28 {
+                let save = save_on(Season::Summer, day, 2);
+                let start = StardewDate::from_day_of_year(
+                    save.week_start()
+                        .rem_euclid(StardewDate::DAYS_PER_YEAR as i32) as u16,
+                );
+                assert_eq!(start.weekday(), Weekday::Sunday, "summer {day}");
+                assert!(save.day_number() - save.week_start() < 7, "summer {day}");
+            }
+        }
+
+        #[test]
+        fn sunday_starts_a_new_week() {
+            let saturday = save_on(Season::Spring, 13, 1);
+            let sunday = save_on(Season::Spring, 14, 1);
+            assert_eq!(sunday.week_start(), sunday.day_number());
+            assert_eq!(saturday.week_start(), sunday.day_number() - 7);
+        }
+
+        #[test]
+        fn the_first_days_of_a_season_belong_to_the_week_before() {
+            let last_sunday_of_spring = save_on(Season::Spring, 28, 1);
+            for day in 1..=6 {
+                assert_eq!(
+                    save_on(Season::Summer, day, 1).week_start(),
+                    last_sunday_of_spring.day_number()
+                );
+            }
+            let last_sunday_of_winter = save_on(Season::Winter, 28, 1);
+            assert_eq!(
+                save_on(Season::Spring, 3, 2).week_start(),
+                last_sunday_of_winter.day_number()
+            );
+        }
+    }
+}
@@ -0,0 +1,626 @@
+pub mod gift_commands {
+    use crate::commands::terminal_commands::{string_to_day, string_to_season};
+    use crate::friendships::friendship_commands::{
+        read_active_save, read_gift_history, GiftHistory,
+    };
+    use crate::health::health_commands::is_connection_error;
+    use crate::localization::localization_commands::{
+        canonical_name, date_name, tr, value_name, TranslationKind,
+    };
+    use crate::lookup::lookup_commands::resolve_character;
+    use crate::storage::backend::{checked_query, checked_query_as, query, Backend, Pool};
+    use crate::{print_aesthetic_message, Character, CharacterRecord, GiftTaste, StardewDate};
+    use std::{collections::HashMap, error::Error, str::FromStr};
+
+    pub(crate) const BIRTHDAY_MULTIPLIER: i32 = 8;
+    pub(crate) const GIFTS_PER_WEEK: u8 = 2;
+    const DAYS_TO_LOOK_AHEAD: u16 = 7;
+
+    struct GiftOption {
+        character_name: String,
+        item: String,
+        taste: GiftTaste,
+        points: i32,
+        date: StardewDate,
+        is_birthday: bool,
+    }
+
+    pub async fn handle_recommend_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let save = read_active_save(pool).await?;
+        let date = if arguments.len() >= 2 {
+            let season = match string_to_season(arguments[0]) {
+                Some(s) => s,
+                None => return Ok(()),
+            };
+            let day = match string_to_day(arguments[1]) {
+                Some(d) => d,
+                None => return Ok(()),
+            };
+            StardewDate { season, day }
+        } else {
+            match &save {
+                Some(s) => s.date,
+                None => {
+                    println!("{}", tr!("recommend-no-date"));
+                    println!("{}", tr!("save-date-hint"));
+                    return Ok(());
+                }
+            }
+        };
+
+        let characters: Vec<Character> = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters"
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let tastes = read_gift_tastes(pool).await?;
+        let inventory = read_inventory(pool).await?;
+        // the gift log only tells us about the week the active save is currently in
+        let gift_history = match &save {
+            Some(s) if s.date == date => read_gift_history(pool, s).await?,
+            _ => HashMap::new(),
+        };
+
+        let mut messages = vec![tr!("recommend-title", date = date_name(&date))];
+
+        for character in &characters {
+            let birthday = StardewDate {
+                season: character.birthday_season,
+                day: character.birthday_day,
+            };
+            let days_until_birthday = date.days_until(&birthday);
+            if days_until_birthday == 0 {
+                messages.push(tr!(
+                    "recommend-birthday-today",
+                    name = character.name.as_str()
+                ));
+            } else if days_until_birthday < DAYS_TO_LOOK_AHEAD {
+                messages.push(tr!(
+                    "recommend-birthday-soon",
+                    name = character.name.as_str(),
+                    date = date_name(&birthday),
+                    days = days_until_birthday
+                ));
+            }
+        }
+
+        if inventory.is_empty() {
+            messages.push(tr!("recommend-inventory-empty"));
+            print_aesthetic_message(messages);
+            return Ok(());
+        }
+
+        let options = collect_gift_options(&date, &characters, &tastes, &inventory, &gift_history);
+        let plan = plan_gifts(&date, options, &inventory);
+
+        if plan.is_empty() {
+            messages.push(tr!("recommend-nothing"));
+        }
+
+        let mut total_points = 0;
+        for option in &plan {
+            total_points += option.points;
+            if option.date == date {
+                messages.push(tr!(
+                    if option.is_birthday {
+                        "recommend-give-birthday"
+                    } else {
+                        "recommend-give"
+                    },
+                    item = option.item.as_str(),
+                    name = option.character_name.as_str(),
+                    taste = value_name("taste", &option.taste),
+                    points = option.points
+                ));
+            } else {
+                messages.push(tr!(
+                    "recommend-keep",
+                    item = option.item.as_str(),
+                    name = option.character_name.as_str(),
+                    date = date_name(&option.date),
+                    taste = value_name("taste", &option.taste),
+                    points = option.points
+                ));
+            }
+        }
+        if !plan.is_empty() {
+            messages.push(tr!("recommend-total", points = total_points));
+        }
+
+        print_aesthetic_message(messages);
+        Ok(())
+    }
+
+    fn collect_gift_options(
+        date: &StardewDate,
+        characters: &[Character],
+        tastes: &HashMap<String, HashMap<String, GiftTaste>>,
+        inventory: &[(String, i32)],
+        gift_history: &HashMap<String, GiftHistory>,
+    ) -> Vec<GiftOption> {
+        let mut options = vec![];
+
+        for character in characters {
+            let birthday = StardewDate {
+                season: character.birthday_season,
+                day: character.birthday_day,
+            };
+            let days_until_birthday = date.days_until(&birthday);
+            let (given_this_week, given_today) = gift_history
+                .get(&character.name.to_lowercase())
+                .map(|history| (history.this_week, history.today))
+                .unwrap_or((0, false));
+
+            // a birthday gift is always accepted, otherwise the weekly limit applies
+            let mut gift_dates = vec![];
+            if days_until_birthday == 0 {
+                if !given_today {
+                    gift_dates.push((*date, true));
+                }
+            } else {
+                if !given_today && given_this_week < GIFTS_PER_WEEK {
+                    gift_dates.push((*date, false));
+                }
+                if days_until_birthday < DAYS_TO_LOOK_AHEAD {
+                    gift_dates.push((birthday, true));
+                }
+            }
+
+            for (item, _) in inventory {
+                let taste = taste_of(character, tastes, item);
+                for (gift_date, is_birthday) in &gift_dates {
+                    let multiplier = if *is_birthday { BIRTHDAY_MULTIPLIER } else { 1 };
+                    let points = taste.points() * multiplier;
+                    if points > 0 {
+                        options.push(GiftOption {
+                            character_name: character.name.clone(),
+                            item: item.clone(),
+                            taste,
+                            points,
+                            date: *gift_date,
+                            is_birthday: *is_birthday,
+                        });
+                    }
+                }
+            }
+        }
+
+        options
+    }
+
+    // greedily hands out the most valuable gifts first: every villager gets at most one gift per day
+    fn plan_gifts(
+        date: &StardewDate,
+        mut options: Vec<GiftOption>,
+        inventory: &[(String, i32)],
+    ) -> Vec<GiftOption> {
+        options.sort_by_key(|option| std::cmp::Reverse(option.points));
+
+        let mut remaining: HashMap<String, i32> = inventory
+            .iter()
+            .map(|(item, quantity)| (item.to_lowercase(), *quantity))
+            .collect();
+        let mut gifted_days: Vec<(String, StardewDate)> = vec![];
+        let mut plan = vec![];
+
+        for option in options {
+            let item_key = option.item.to_lowercase();
+            let quantity = remaining.get(&item_key).copied().unwrap_or(0);
+            let already_gifted = gifted_days
+                .iter()
+                .any(|(name, date)| name == &option.character_name && date == &option.date);
+
+            if quantity <= 0 || already_gifted {
+                continue;
+            }
+
+            remaining.insert(item_key, quantity - 1);
+            gifted_days.push((option.character_name.clone(), option.date));
+            plan.push(option);
+        }
+
+        plan.sort_by(|a, b| {
+            date.days_until(&a.date)
+                .cmp(&date.days_until(&b.date))
+                .then(b.points.cmp(&a.points))
+        });
+        plan
+    }
+
+    pub(crate) fn taste_of(
+        character: &Character,
+        tastes: &HashMap<String, HashMap<String, GiftTaste>>,
+        item: &str,
+    ) -> GiftTaste {
+        let item_key = item.to_lowercase();
+        match tastes
+            .get(&character.name.to_lowercase())
+            .and_then(|character_tastes| character_tastes.get(&item_key))
+        {
+            Some(taste) => *taste,
+            None if character.best_gift.to_lowercase() == item_key => GiftTaste::Love,
+            None => GiftTaste::Neutral,
+        }
+    }
+
+    pub(crate) async fn read_gift_tastes(
+        pool: &Pool,
+    ) -> Result<HashMap<String, HashMap<String, GiftTaste>>, Box<dyn Error>> {
+        let rows = checked_query!("SELECT character_name, item, taste FROM gift_tastes")
+            .fetch_all(pool)
+            .await?;
+
+        let mut tastes: HashMap<String, HashMap<String, GiftTaste>> = HashMap::new();
+        for row in rows {
+            let character_name = row.get::<String, _>("character_name").to_lowercase();
+            let item = row.get::<String, _>("item").to_lowercase();
+            if let Ok(taste) = GiftTaste::from_str(&row.get::<String, _>("taste")) {
+                tastes
+                    .entry(character_name)
+                    .or_default()
+                    .insert(item, taste);
+            }
+        }
+
+        Ok(tastes)
+    }
+
+    // every character's loved gifts, starting with their best gift
+    pub(crate) async fn read_loved_gifts(
+        pool: &Pool,
+    ) -> Result<HashMap<String, Vec<String>>, Box<dyn Error>> {
+        let rows = checked_query!("SELECT name, best_gift FROM characters")
+            .fetch_all(pool)
+            .await?;
+        let mut loved_gifts: HashMap<String, Vec<String>> = HashMap::new();
+        for row in rows {
+            loved_gifts.insert(
+                row.get::<String, _>("name").to_lowercase(),
+                vec![row.get::<String, _>("best_gift")],
+            );
+        }
+
+        let rows = checked_query!(
+            "SELECT character_name, item FROM gift_tastes WHERE taste = $1 ORDER BY item",
+            GiftTaste::Love.as_ref()
+        )
+        .fetch_all(pool)
+        .await?;
+        for row in rows {
+            let item = row.get::<String, _>("item");
+            let gifts = loved_gifts
+                .entry(row.get::<String, _>("character_name").to_lowercase())
+                .or_default();
+            if !gifts.iter().any(|gift| gift.eq_ignore_ascii_case(&item)) {
+                gifts.push(item);
+            }
+        }
+
+        Ok(loved_gifts)
+    }
+
+    async fn read_inventory(pool: &Pool) -> Result<Vec<(String, i32)>, Box<dyn Error>> {
+        let rows =
+            checked_query!("SELECT item, quantity FROM inventory WHERE quantity > 0 ORDER BY item")
+                .fetch_all(pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.get::<String, _>("item"), row.get::<i32, _>("quantity")))
+            .collect())
+    }
+
+    pub async fn handle_inventory_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() || arguments[0] == "list" {
+            let inventory = read_inventory(pool).await?;
+            if inventory.is_empty() {
+                print_aesthetic_message(vec![tr!("inventory-empty")]);
+            } else {
+                let mut messages = vec![tr!("inventory-title")];
+                for (item, quantity) in inventory {
+                    messages.push(format!("{} x{}", item, quantity));
+                }
+                print_aesthetic_message(messages);
+            }
+            return Ok(());
+        }
+
+        let action = arguments[0];
+        if (action != "add" && action != "remove") || arguments.len() < 2 {
+            println!("{}", tr!("inventory-usage"));
+            println!("{}", tr!("inventory-example"));
+            return Ok(());
+        }
+
+        let (item, quantity) = split_item_and_quantity(&arguments[1..]);
+        let quantity = match quantity {
+            Some(q) => q,
+            None => return Ok(()),
+        };
+
+        if action == "add" {
+            let upsert = match pool.backend() {
+                Backend::MySql => "INSERT INTO inventory (item, quantity) VALUES (?, ?) ON DUPLICATE KEY UPDATE quantity = quantity + VALUES(quantity)",
+                Backend::Postgres => "INSERT INTO inventory (item, quantity) VALUES (?, ?) ON CONFLICT (item) DO UPDATE SET quantity = inventory.quantity + EXCLUDED.quantity",
+            };
+            query(upsert)
+                .bind(&item)
+                .bind(quantity)
+                .execute(pool)
+                .await?;
+            print_aesthetic_message(vec![tr!(
+                "inventory-added",
+                item = item.as_str(),
+                quantity = quantity
+            )]);
+        } else {
+            let result = checked_query!(
+                "UPDATE inventory SET quantity = GREATEST(quantity - $1, 0) WHERE item = $2",
+                quantity,
+                &item
+            )
+            .execute(pool)
+            .await?;
+            checked_query!("DELETE FROM inventory WHERE quantity <= 0")
+                .execute(pool)
+                .await?;
+            if result.rows_affected() == 0 {
+                print_aesthetic_message(vec![tr!("inventory-missing", item = item.as_str())]);
+            } else {
+                print_aesthetic_message(vec![tr!(
+                    "inventory-removed",
+                    item = item.as_str(),
+                    quantity = quantity
+                )]);
+            }
+        }
+
+        Ok(())
+    }
+
+    // "fish taco 3" -> ("fish taco", 3), "pizza" -> ("pizza", 1)
+    fn split_item_and_quantity(arguments: &[&str]) -> (String, Option<i32>) {
+        let last = arguments[arguments.len() - 1];
+        if arguments.len() > 1 && last.chars().all(|c| c.is_ascii_digit()) {
+            let item = canonical_name(
+                &TranslationKind::Item,
+                &arguments[..arguments.len() - 1].join(" "),
+            );
+            match last.parse::<i32>() {
+                Ok(quantity) if quantity > 0 => (item, Some(quantity)),
+                _ => {
+                    println!("{}", tr!("inventory-invalid-quantity", input = last));
+                    (item, None)
+                }
+            }
+        } else {
+            (
+                canonical_name(&TranslationKind::Item, &arguments.join(" ")),
+                Some(1),
+            )
+        }
+    }
+
+    pub async fn handle_taste_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 3 {
+            println!("{}", tr!("taste-usage"));
+            println!("{}", tr!("taste-example"));
+            return Ok(());
+        }
+
+        let character_name = match resolve_character(pool, arguments[0]).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let taste = match GiftTaste::from_str(arguments[1]) {
+            Ok(t) => t,
+            Err(_) => {
+                println!("{}", tr!("taste-invalid", input = arguments[1]));
+                return Ok(());
+            }
+        };
+        let item = canonical_name(&TranslationKind::Item, &arguments[2..].join(" "));
+
+        let upsert = match pool.backend() {
+            Backend::MySql => "INSERT INTO gift_tastes (character_name, item, taste) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE taste = VALUES(taste)",
+            Backend::Postgres => "INSERT INTO gift_tastes (character_name, item, taste) VALUES (?, ?, ?) ON CONFLICT (character_name, item) DO UPDATE SET taste = EXCLUDED.taste",
+        };
+        let result = query(upsert)
+            .bind(&character_name)
+            .bind(&item)
+            .bind(taste.as_ref())
+            .execute(pool)
+            .await;
+
+        match result {
+            Ok(_) => print_aesthetic_message(vec![tr!(
+                "taste-saved",
+                name = character_name.as_str(),
+                item = item.as_str(),
+                taste = value_name("taste", &taste)
+            )]),
+            Err(e) if is_connection_error(&e) => return Err(e.into()),
+            Err(e) => print_aesthetic_message(vec![
+                tr!("taste-error", name = character_name.as_str()),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{Season, VANILLA_SOURCE};
+
+        // spring 10
+        const TODAY: StardewDate = StardewDate {
+            season: Season::Spring,
+            day: 10,
+        };
+
+        fn villager(name: &str, birthday_season: Season, birthday_day: u8) -> Character {
+            Character {
+                name: name.to_string(),
+                birthday_season,
+                birthday_day,
+                is_bachelor: true,
+                best_gift: "Amethyst".to_string(),
+                source: VANILLA_SOURCE.to_string(),
+                notes: vec![],
+                tags: vec![],
+            }
+        }
+
+        fn villagers() -> Vec<Character> {
+            vec![
+                villager("Abigail", Season::Fall, 13),
+                villager("Haley", Season::Spring, 14),
+            ]
+        }
+
+        // Haley likes daffodils and doesn't care for amethysts
+        fn tastes() -> HashMap<String, HashMap<String, GiftTaste>> {
+            HashMap::from([(
+                "haley".to_string(),
+                HashMap::from([
+                    ("daffodil".to_string(), GiftTaste::Like),
+                    ("amethyst".to_string(), GiftTaste::Neutral),
+                ]),
+            )])
+        }
+
+        fn inventory() -> Vec<(String, i32)> {
+            vec![("Amethyst".to_string(), 1), ("Daffodil".to_string(), 1)]
+        }
+
+        fn summary(options: &[GiftOption]) -> Vec<(&str, &str, i32, u8)> {
+            options
+                .iter()
+                .map(|o| {
+                    (
+                        o.character_name.as_str(),
+                        o.item.as_str(),
+                        o.points,
+                        o.date.day,
+                    )
+                })
+                .collect()
+        }
+
+        #[test]
+        fn birthdays_in_the_coming_week_are_worth_eight_times_as_much() {
+            let options = collect_gift_options(
+                &TODAY,
+                &villagers(),
+                &tastes(),
+                &inventory(),
+                &HashMap::new(),
+            );
+            let haley: Vec<_> = summary(&options)
+                .into_iter()
+                .filter(|(name, ..)| *name == "Haley")
+                .collect();
+            assert_eq!(
+                haley,
+                [
+                    ("Haley", "Amethyst", 20, 10),
+                    ("Haley", "Amethyst", 20 * BIRTHDAY_MULTIPLIER, 14),
+                    ("Haley", "Daffodil", 45, 10),
+                    ("Haley", "Daffodil", 45 * BIRTHDAY_MULTIPLIER, 14),
+                ]
+            );
+            assert!(options
+                .iter()
+                .filter(|o| o.character_name == "Abigail")
+                .all(|o| !o.is_birthday && o.date == TODAY));
+        }
+
+        #[test]
+        fn the_weekly_limit_applies_except_on_birthdays() {
+            let history = HashMap::from([
+                (
+                    "abigail".to_string(),
+                    GiftHistory {
+                        this_week: GIFTS_PER_WEEK,
+                        today: false,
+                    },
+                ),
+                (
+                    "haley".to_string(),
+                    GiftHistory {
+                        this_week: GIFTS_PER_WEEK,
+                        today: false,
+                    },
+                ),
+            ]);
+            let options =
+                collect_gift_options(&TODAY, &villagers(), &tastes(), &inventory(), &history);
+            assert!(options
+                .iter()
+                .all(|o| o.character_name == "Haley" && o.is_birthday));
+            assert_eq!(options.len(), 2);
+
+            let birthday = StardewDate {
+                season: Season::Spring,
+                day: 14,
+            };
+            let given_today = HashMap::from([(
+                "haley".to_string(),
+                GiftHistory {
+                    this_week: 1,
+                    today: true,
+                },
+            )]);
+            let options = collect_gift_options(
+                &birthday,
+                &villagers(),
+                &tastes(),
+                &inventory(),
+                &given_today,
+            );
+            assert!(options.iter().all(|o| o.character_name == "Abigail"));
+        }
+
+        #[test]
+        fn the_plan_gives_each_item_once_and_each_villager_one_gift_a_day() {
+            let options = collect_gift_options(
+                &TODAY,
+                &villagers(),
+                &tastes(),
+                &inventory(),
+                &HashMap::new(),
+            );
+            let plan = plan_gifts(&TODAY, options, &inventory());
+            // the daffodil is worth most on Haley's birthday, which leaves the amethyst for Abigail today
+            assert_eq!(
+                summary(&plan),
+                [
+                    ("Abigail", "Amethyst", 80, 10),
+                    ("Haley", "Daffodil", 45 * BIRTHDAY_MULTIPLIER, 14),
+                ]
+            );
+        }
+
+        #[test]
+        fn nothing_is_planned_without_an_inventory() {
+            let options =
+                collect_gift_options(&TODAY, &villagers(), &tastes(), &[], &HashMap::new());
+            assert!(options.is_empty());
+            assert!(plan_gifts(&TODAY, options, &[]).is_empty());
+        }
+    }
+}
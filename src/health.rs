@@ -0,0 +1,188 @@
+pub mod health_commands {
+    use crate::config::settings::{Config, ConfigError};
+    use crate::localization::localization_commands::tr;
+    use crate::print_aesthetic_message;
+    use crate::profiles::profile_commands::Session;
+    use crate::storage::backend::{query, Backend, ConnectOptions, Pool};
+    use sqlx::mysql::MySqlDatabaseError;
+    use std::{
+        error::Error,
+        time::{Duration, Instant},
+    };
+
+    const FIRST_RETRY_DELAY: Duration = Duration::from_millis(500);
+    const MAX_RETRY_DELAY: Duration = Duration::from_secs(8);
+
+    // 0.5s, 1s, 2s, 4s, 8s, 8s, ...
+    fn retry_delay(attempt: u32) -> Duration {
+        FIRST_RETRY_DELAY
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_RETRY_DELAY)
+    }
+
+    pub(crate) fn connect_retries(config: &Config) -> Result<u32, ConfigError> {
+        Ok(config
+            .parse::<u32>("database.connect_retries")?
+            .unwrap_or(5))
+    }
+
+    // true when the server can't be reached or went away, not when a query or the login was wrong
+    pub(crate) fn is_connection_error(error: &sqlx::Error) -> bool {
+        match error {
+            sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::WorkerCrashed => true,
+            // SQLSTATE class 08 is "connection exception", e.g. when the server shuts down,
+            // and Postgres uses 57P01-57P03 while it shuts down or is still starting up
+            sqlx::Error::Database(e) => e
+                .code()
+                .is_some_and(|code| code.starts_with("08") || code.starts_with("57P")),
+            _ => false,
+        }
+    }
+
+    // the user may not do this, e.g. an app user without the rights to create tables
+    pub(crate) fn is_permission_error(error: &sqlx::Error) -> bool {
+        match error {
+            // MySQL's 1044, 1142 and 1227 share SQLSTATE 42000 with syntax errors, so the number decides
+            sqlx::Error::Database(e) => match e.try_downcast_ref::<MySqlDatabaseError>() {
+                Some(e) => [1044, 1142, 1227].contains(&e.number()),
+                None => e.code().is_some_and(|code| code == "42501"),
+            },
+            _ => false,
+        }
+    }
+
+    // tries a single connection instead of the pool, which would silently wait for the whole acquire timeout
+    pub(crate) async fn wait_for_server(
+        options: &ConnectOptions,
+        retries: u32,
+    ) -> Result<(), sqlx::Error> {
+        let mut attempt = 0;
+        loop {
+            match options.try_connect().await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt < retries && is_connection_error(&e) => {
+                    let delay = retry_delay(attempt);
+                    attempt += 1;
+                    tracing::warn!(attempt, retries, error = %e, "the database isn't reachable, trying again");
+                    println!(
+                        "{}",
+                        tr!(
+                            "connection-retry",
+                            attempt = attempt,
+                            retries = retries,
+                            seconds = delay.as_secs_f64()
+                        )
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    // the pools throw away dropped connections by themselves once the server is back,
+    // so it's enough to wait for that before trying a command again
+    pub(crate) async fn reconnect(session: &Session, retry: bool) -> bool {
+        println!("{}", tr!("connection-lost"));
+        tracing::warn!("lost the connection to the database");
+        let retries = match connect_retries(&session.config) {
+            Ok(retries) => retries,
+            Err(e) => {
+                print_aesthetic_message(vec![tr!("connection-unavailable"), format!("{e}")]);
+                return false;
+            }
+        };
+        match wait_for_server(&session.pool.connect_options(), retries).await {
+            Ok(()) => {
+                if retry {
+                    println!("{}", tr!("connection-restored"));
+                } else {
+                    println!("{}", tr!("connection-restored-not-retried"));
+                }
+                tracing::info!("reconnected to the database");
+                true
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "couldn't reconnect to the database");
+                print_aesthetic_message(vec![tr!("connection-unavailable"), format!("{e}")]);
+                false
+            }
+        }
+    }
+
+    async fn ping(pool: &Pool) -> Result<Duration, sqlx::Error> {
+        let started = Instant::now();
+        pool.ping().await?;
+        Ok(started.elapsed())
+    }
+
+    pub async fn handle_health_command(
+        session: &Session,
+        _arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let pool = &session.pool;
+        let latency = match ping(pool).await {
+            Ok(l) => l,
+            Err(e) => {
+                print_aesthetic_message(vec![tr!("health-failed"), format!("{e}")]);
+                return Ok(());
+            }
+        };
+
+        let row = match pool.backend() {
+            Backend::MySql => query("SELECT VERSION() AS version, DATABASE() AS name"),
+            Backend::Postgres => query("SELECT version() AS version, current_database() AS name"),
+        }
+        .fetch_one(pool)
+        .await?;
+        print_aesthetic_message(vec![
+            tr!("health-ok", milliseconds = latency.as_millis() as u64),
+            tr!("health-server", version = row.get::<String, _>("version")),
+            tr!(
+                "health-database",
+                name = row.get::<Option<String>, _>("name").unwrap_or_default(),
+                profile = session.profile.as_str()
+            ),
+            tr!(
+                "health-connections",
+                open = pool.size(),
+                idle = pool.num_idle(),
+                max = pool.max_connections()
+            ),
+        ]);
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::io;
+
+        #[test]
+        fn the_retry_delay_doubles_up_to_the_maximum() {
+            let delays: Vec<Duration> = (0..7).map(retry_delay).collect();
+            assert_eq!(
+                delays,
+                [500, 1000, 2000, 4000, 8000, 8000, 8000].map(Duration::from_millis)
+            );
+            assert_eq!(retry_delay(u32::MAX), MAX_RETRY_DELAY);
+        }
+
+        #[test]
+        fn only_an_unreachable_server_is_a_connection_error() {
+            let refused = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+            assert!(is_connection_error(&sqlx::Error::Io(refused)));
+            assert!(is_connection_error(&sqlx::Error::PoolTimedOut));
+            assert!(is_connection_error(&sqlx::Error::WorkerCrashed));
+            assert!(!is_connection_error(&sqlx::Error::RowNotFound));
+            assert!(!is_connection_error(&sqlx::Error::PoolClosed));
+            assert!(!is_connection_error(&sqlx::Error::ColumnNotFound(
+                "name".to_string()
+            )));
+        }
+    }
+}
@@ -0,0 +1,342 @@
+pub mod import_commands {
+    use crate::friendships::friendship_commands::{
+        read_active_save, FriendshipStatus, Save, POINTS_PER_HEART,
+    };
+    use crate::localization::localization_commands::{date_name, tr};
+    use crate::storage::backend::{checked_query, query, Backend, Pool};
+    use crate::{print_aesthetic_message, Season, StardewDate};
+    use roxmltree::{Document, Node};
+    use std::{error::Error, fs, path::Path, str::FromStr};
+
+    const IMPORTED_GIFT: &str = "(imported from save file)";
+
+    struct ImportedFriendship {
+        character_name: String,
+        points: i32,
+        status: FriendshipStatus,
+        gifts_this_week: i32,
+        gifts_today: i32,
+        talked_today: bool,
+    }
+
+    struct ImportedSave {
+        farm_name: String,
+        date: StardewDate,
+        year: i32,
+        friendships: Vec<ImportedFriendship>,
+    }
+
+    fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+        node.children().find(|n| n.has_tag_name(name))
+    }
+
+    fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+        child(node, name).and_then(|n| n.text()).map(|t| t.trim())
+    }
+
+    // the game knows more relationship states than we track, so they are mapped onto ours
+    fn status_from_game(status: &str) -> FriendshipStatus {
+        match status {
+            "Dating" | "Engaged" => FriendshipStatus::Dating,
+            "Married" => FriendshipStatus::Married,
+            _ => FriendshipStatus::Friend,
+        }
+    }
+
+    fn parse_save_game(xml: &str) -> Result<ImportedSave, String> {
+        let document =
+            Document::parse(xml).map_err(|e| tr!("import-invalid-xml", error = e.to_string()))?;
+        let root = document.root_element();
+        if !root.has_tag_name("SaveGame") {
+            return Err(tr!("import-not-a-save"));
+        }
+        let missing = |field: &str| tr!("import-missing-field", field = format!("<{}>", field));
+
+        let season = child_text(root, "currentSeason")
+            .and_then(|s| Season::from_str(s).ok())
+            .ok_or_else(|| missing("currentSeason"))?;
+        let day = child_text(root, "dayOfMonth")
+            .and_then(|d| d.parse::<u8>().ok())
+            .filter(|d| (1..=28).contains(d))
+            .ok_or_else(|| missing("dayOfMonth"))?;
+        let year = child_text(root, "year")
+            .and_then(|y| y.parse::<i32>().ok())
+            .ok_or_else(|| missing("year"))?;
+
+        let player = child(root, "player").ok_or_else(|| missing("player"))?;
+        let farm_name = child_text(player, "farmName")
+            .filter(|f| !f.is_empty())
+            .unwrap_or("Imported Farm")
+            .to_string();
+
+        let mut friendships = vec![];
+        if let Some(friendship_data) = child(player, "friendshipData") {
+            for item in friendship_data
+                .children()
+                .filter(|n| n.has_tag_name("item"))
+            {
+                let character_name = match child(item, "key").and_then(|k| child_text(k, "string"))
+                {
+                    Some(name) => name.to_string(),
+                    None => continue,
+                };
+                let friendship = match child(item, "value").and_then(|v| child(v, "Friendship")) {
+                    Some(f) => f,
+                    None => continue,
+                };
+                let number = |name: &str| {
+                    child_text(friendship, name)
+                        .and_then(|n| n.parse::<i32>().ok())
+                        .unwrap_or(0)
+                };
+
+                friendships.push(ImportedFriendship {
+                    character_name,
+                    points: number("Points"),
+                    status: status_from_game(child_text(friendship, "Status").unwrap_or("")),
+                    gifts_this_week: number("GiftsThisWeek"),
+                    gifts_today: number("GiftsToday"),
+                    talked_today: child_text(friendship, "TalkedToToday") == Some("true"),
+                });
+            }
+        }
+
+        Ok(ImportedSave {
+            farm_name,
+            date: StardewDate { season, day },
+            year,
+            friendships,
+        })
+    }
+
+    // accepts the save file itself or its folder, which contains a file with the same name
+    fn read_save_file(path: &str) -> Result<String, Box<dyn Error>> {
+        let path = Path::new(path);
+        if path.is_dir() {
+            let file_name = path
+                .file_name()
+                .ok_or_else(|| tr!("import-folder-without-name"))?;
+            Ok(fs::read_to_string(path.join(file_name))?)
+        } else {
+            Ok(fs::read_to_string(path)?)
+        }
+    }
+
+    pub async fn handle_import_save_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("import-usage"));
+            return Ok(());
+        }
+
+        let path = arguments.join(" ");
+        let xml = match read_save_file(&path) {
+            Ok(x) => x,
+            Err(e) => {
+                print_aesthetic_message(vec![
+                    tr!("import-read-error", path = path.as_str()),
+                    format!("{e}"),
+                ]);
+                return Ok(());
+            }
+        };
+        let imported = match parse_save_game(&xml) {
+            Ok(i) => i,
+            Err(e) => {
+                print_aesthetic_message(vec![tr!("import-error", path = path.as_str()), e]);
+                return Ok(());
+            }
+        };
+
+        let upsert = match pool.backend() {
+            Backend::MySql => "INSERT INTO saves (name, season, day, year, is_active) VALUES (?, ?, ?, ?, FALSE) ON DUPLICATE KEY UPDATE season = VALUES(season), day = VALUES(day), year = VALUES(year)",
+            Backend::Postgres => "INSERT INTO saves (name, season, day, year, is_active) VALUES (?, ?, ?, ?, FALSE) ON CONFLICT (name) DO UPDATE SET season = EXCLUDED.season, day = EXCLUDED.day, year = EXCLUDED.year",
+        };
+        query(upsert)
+            .bind(&imported.farm_name)
+            .bind(imported.date.season.as_ref())
+            .bind(imported.date.day)
+            .bind(imported.year)
+            .execute(pool)
+            .await?;
+        checked_query!(
+            "UPDATE saves SET is_active = (name = $1)",
+            &imported.farm_name
+        )
+        .execute(pool)
+        .await?;
+        let save = read_active_save(pool)
+            .await?
+            .ok_or_else(|| tr!("import-not-activated"))?;
+
+        let known_characters: Vec<String> = checked_query!("SELECT name FROM characters")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|row| row.get::<String, _>("name"))
+            .collect();
+
+        let mut imported_count = 0;
+        let mut unknown_characters = vec![];
+        for friendship in &imported.friendships {
+            let character_name = match known_characters
+                .iter()
+                .find(|name| name.eq_ignore_ascii_case(&friendship.character_name))
+            {
+                Some(name) => name,
+                None => {
+                    unknown_characters.push(friendship.character_name.clone());
+                    continue;
+                }
+            };
+            import_friendship(pool, &save, character_name, friendship).await?;
+            imported_count += 1;
+        }
+
+        let mut messages = vec![
+            tr!(
+                "import-done",
+                save = tr!(
+                    "save-line",
+                    name = save.name.as_str(),
+                    date = date_name(&save.date),
+                    year = save.year
+                )
+            ),
+            tr!("import-friendships", count = imported_count),
+        ];
+        if !unknown_characters.is_empty() {
+            messages.push(tr!(
+                "import-unknown-characters",
+                names = unknown_characters.join(", ")
+            ));
+            messages.push(tr!("import-unknown-hint"));
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    async fn import_friendship(
+        pool: &Pool,
+        save: &Save,
+        character_name: &str,
+        friendship: &ImportedFriendship,
+    ) -> Result<(), Box<dyn Error>> {
+        let last_talked = if friendship.talked_today {
+            Some(save.day_number())
+        } else {
+            None
+        };
+        let upsert = match pool.backend() {
+            Backend::MySql => "INSERT INTO friendships (save_id, character_name, points, status, last_talked) VALUES (?, ?, ?, ?, ?) ON DUPLICATE KEY UPDATE points = VALUES(points), status = VALUES(status), last_talked = VALUES(last_talked)",
+            Backend::Postgres => "INSERT INTO friendships (save_id, character_name, points, status, last_talked) VALUES (?, ?, ?, ?, ?) ON CONFLICT (save_id, character_name) DO UPDATE SET points = EXCLUDED.points, status = EXCLUDED.status, last_talked = EXCLUDED.last_talked",
+        };
+        query(upsert)
+            .bind(save.id)
+            .bind(character_name)
+            .bind(friendship.points.clamp(0, 14 * POINTS_PER_HEART))
+            .bind(friendship.status.as_ref())
+            .bind(last_talked)
+            .execute(pool)
+            .await?;
+
+        // the save file only counts this week's gifts, so the log for this week is replaced with placeholders
+        checked_query!(
+            "DELETE FROM gift_log WHERE save_id = $1 AND character_name = $2 AND day_number >= $3",
+            save.id,
+            character_name,
+            save.week_start()
+        )
+        .execute(pool)
+        .await?;
+
+        let gifts_today = friendship
+            .gifts_today
+            .clamp(0, friendship.gifts_this_week.max(0));
+        let earlier_gifts = friendship.gifts_this_week.max(0) - gifts_today;
+        let gift_days = std::iter::repeat_n(save.week_start(), earlier_gifts as usize)
+            .chain(std::iter::repeat_n(save.day_number(), gifts_today as usize));
+
+        for day_number in gift_days {
+            checked_query!("INSERT INTO gift_log (save_id, character_name, item, taste, points, is_birthday, season, day, year, day_number) VALUES ($1, $2, $3, 'Neutral', 0, FALSE, $4, $5, $6, $7)", save.id, character_name, IMPORTED_GIFT, save.date.season.as_ref(), i32::from(save.date.day), save.year, day_number)
+                .execute(pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const SAVE_GAME: &str = include_str!("../fixtures/SaveGameFile.xml");
+
+        #[test]
+        fn reads_the_date_and_farm_of_a_save() {
+            let save = parse_save_game(SAVE_GAME).unwrap();
+            assert_eq!(save.farm_name, "Sunny");
+            assert_eq!(
+                save.date,
+                StardewDate {
+                    season: Season::Fall,
+                    day: 10
+                }
+            );
+            assert_eq!(save.year, 2);
+        }
+
+        #[test]
+        fn reads_every_friendship_of_a_save() {
+            let save = parse_save_game(SAVE_GAME).unwrap();
+            let names: Vec<&str> = save
+                .friendships
+                .iter()
+                .map(|f| f.character_name.as_str())
+                .collect();
+            assert_eq!(names, ["Abigail", "Haley", "Lewis", "Linus"]);
+
+            let abigail = &save.friendships[0];
+            assert_eq!(abigail.points, 1830);
+            assert_eq!(abigail.status, FriendshipStatus::Dating);
+            assert_eq!(abigail.gifts_this_week, 2);
+            assert_eq!(abigail.gifts_today, 1);
+            assert!(abigail.talked_today);
+
+            let haley = &save.friendships[1];
+            assert_eq!(haley.status, FriendshipStatus::Friend);
+            assert!(!haley.talked_today);
+        }
+
+        #[test]
+        fn rejects_files_that_are_not_saves() {
+            assert_eq!(
+                parse_save_game("<Farmer><name>Farmer</name></Farmer>").err(),
+                Some(tr!("import-not-a-save"))
+            );
+            assert!(parse_save_game("<SaveGame>").is_err());
+        }
+
+        #[test]
+        fn names_the_field_that_is_missing() {
+            let without_day = SAVE_GAME.replace("<dayOfMonth>10</dayOfMonth>", "");
+            assert_eq!(
+                parse_save_game(&without_day).err(),
+                Some(tr!("import-missing-field", field = "<dayOfMonth>"))
+            );
+            let day_out_of_range = SAVE_GAME.replace(">10</dayOfMonth>", ">29</dayOfMonth>");
+            assert!(parse_save_game(&day_out_of_range).is_err());
+        }
+
+        #[test]
+        fn a_save_without_a_farm_name_still_imports() {
+            let without_farm_name = SAVE_GAME.replace("<farmName>Sunny</farmName>", "");
+            let save = parse_save_game(&without_farm_name).unwrap();
+            assert_eq!(save.farm_name, "Imported Farm");
+            assert_eq!(save.friendships.len(), 4);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+pub mod localization_commands {
+    pub(crate) use crate::messages::catalog::{
+        current_locale, init_locale, locales, matches_text, set_locale, text, text_with, tr,
+    };
+    use crate::storage::backend::{checked_query, query, Backend, Pool};
+    use crate::{print_aesthetic_message, Season, StardewDate};
+    use fluent_bundle::FluentValue;
+    use std::{error::Error, str::FromStr, sync::RwLock};
+    use strum_macros::{AsRefStr, EnumString};
+
+    // every command can be typed in any language, the catalogs call them "command-<name>"
+    const COMMANDS: [&str; 40] = [
+        "add",
+        "read",
+        "change",
+        "delete",
+        "alias",
+        "search",
+        "note",
+        "tag",
+        "untag",
+        "recommend",
+        "inventory",
+        "taste",
+        "save",
+        "gift",
+        "talk",
+        "status",
+        "export-calendar",
+        "report",
+        "relate",
+        "family",
+        "graph",
+        "schedule",
+        "where",
+        "who-is-at",
+        "festivals",
+        "upcoming",
+        "romance",
+        "next-event",
+        "profile",
+        "config",
+        "health",
+        "ping",
+        "migrate",
+        "stats",
+        "import-save",
+        "import-mod",
+        "uninstall-mod",
+        "language",
+        "translate",
+        "quit",
+    ];
+
+    static TRANSLATIONS: RwLock<Vec<Translation>> = RwLock::new(Vec::new());
+
+    // a row of the translations table, kept in memory so that printing doesn't need the database
+    struct Translation {
+        kind: String,
+        name: String,
+        locale: String,
+        display_name: String,
+    }
+
+    #[derive(AsRefStr, EnumString)]
+    #[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+    pub(crate) enum TranslationKind {
+        Character,
+        Item,
+    }
+
+    pub(crate) fn canonical_command(input: &str) -> String {
+        COMMANDS
+            .iter()
+            .find(|command| {
+                command.eq_ignore_ascii_case(input)
+                    || matches_text(input, &format!("command-{}", command))
+            })
+            .map(|command| command.to_string())
+            .unwrap_or_else(|| input.to_lowercase())
+    }
+
+    // the name of a season, weekday, gift taste, ... in the active language, e.g. "taste-love"
+    pub(crate) fn value_name(prefix: &str, value: &impl AsRef<str>) -> String {
+        text(&format!("{}-{}", prefix, value.as_ref().to_lowercase()))
+    }
+
+    pub(crate) fn season_name(season: &Season) -> String {
+        value_name("season", season)
+    }
+
+    // "Spring 3" in English, "3. Frühling" in German
+    pub(crate) fn date_name(date: &StardewDate) -> String {
+        text_with(
+            "date",
+            &[
+                ("season", FluentValue::from(season_name(&date.season))),
+                ("day", FluentValue::from(date.day)),
+            ],
+        )
+    }
+
+    pub(crate) fn parse_season(input: &str) -> Option<Season> {
+        Season::from_str(input).ok().or_else(|| {
+            [Season::Spring, Season::Summer, Season::Fall, Season::Winter]
+                .into_iter()
+                .find(|season| {
+                    matches_text(input, &format!("season-{}", season.as_ref().to_lowercase()))
+                })
+        })
+    }
+
+    pub(crate) async fn load_translations(pool: &Pool) -> Result<(), Box<dyn Error>> {
+        let rows = checked_query!("SELECT kind, name, locale, display_name FROM translations")
+            .fetch_all(pool)
+            .await?;
+        *TRANSLATIONS.write().unwrap() = rows
+            .into_iter()
+            .map(|row| Translation {
+                kind: row.get::<String, _>("kind"),
+                name: row.get::<String, _>("name"),
+                locale: row.get::<String, _>("locale"),
+                display_name: row.get::<String, _>("display_name"),
+            })
+            .collect();
+        Ok(())
+    }
+
+    pub(crate) fn display_name(kind: &TranslationKind, name: &str) -> String {
+        TRANSLATIONS
+            .read()
+            .unwrap()
+            .iter()
+            .find(|t| {
+                t.kind == kind.as_ref()
+                    && t.locale == current_locale()
+                    && t.name.eq_ignore_ascii_case(name)
+            })
+            .map(|t| t.display_name.clone())
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    // turns a translated name like "Kürbis" back into the name stored in the database
+    pub(crate) fn canonical_name(kind: &TranslationKind, input: &str) -> String {
+        TRANSLATIONS
+            .read()
+            .unwrap()
+            .iter()
+            .find(|t| {
+                t.kind == kind.as_ref() && t.display_name.to_lowercase() == input.to_lowercase()
+            })
+            .map(|t| t.name.clone())
+            .unwrap_or_else(|| input.to_string())
+    }
+
+    pub async fn handle_language_command(arguments: Vec<&str>) -> Result<(), Box<dyn Error>> {
+        match arguments.first() {
+            None => print_aesthetic_message(vec![
+                tr!("language-current", locale = current_locale()),
+                tr!("language-available", locales = locales().join(", ")),
+            ]),
+            Some(locale) if set_locale(locale) => {
+                print_aesthetic_message(vec![tr!("language-switched", locale = current_locale())])
+            }
+            Some(locale) => println!(
+                "{}",
+                tr!(
+                    "language-unknown",
+                    locale = *locale,
+                    locales = locales().join(", ")
+                )
+            ),
+        }
+        Ok(())
+    }
+
+    pub async fn handle_translate_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let kind = arguments
+            .first()
+            .and_then(|k| TranslationKind::from_str(k).ok());
+        let (kind, name) = match (kind, arguments.get(1)) {
+            (Some(kind), Some(name)) => (kind, name.replace('_', " ")),
+            _ => {
+                println!("{}", tr!("translate-usage"));
+                println!("{}", tr!("translate-example"));
+                return Ok(());
+            }
+        };
+        let name = canonical_name(&kind, &name);
+
+        if arguments.len() < 4 {
+            let rows = checked_query!("SELECT locale, display_name FROM translations WHERE kind = $1 AND name = $2 ORDER BY locale", kind.as_ref(), &name)
+                .fetch_all(pool)
+                .await?;
+            let mut messages = vec![tr!("translate-list", name = name.as_str())];
+            if rows.is_empty() {
+                messages.push(tr!("translate-none"));
+            }
+            for row in rows {
+                messages.push(format!(
+                    "{}: {}",
+                    row.get::<String, _>("locale"),
+                    row.get::<String, _>("display_name")
+                ));
+            }
+            print_aesthetic_message(messages);
+            return Ok(());
+        }
+
+        let locale = arguments[2].to_lowercase();
+        if !locales().contains(&locale.as_str()) {
+            println!(
+                "{}",
+                tr!(
+                    "language-unknown",
+                    locale = locale.as_str(),
+                    locales = locales().join(", ")
+                )
+            );
+            return Ok(());
+        }
+        let display = arguments[3..].join(" ");
+
+        let upsert = match pool.backend() {
+            Backend::MySql => "INSERT INTO translations (kind, name, locale, display_name) VALUES (?, ?, ?, ?) ON DUPLICATE KEY UPDATE display_name = VALUES(display_name)",
+            Backend::Postgres => "INSERT INTO translations (kind, name, locale, display_name) VALUES (?, ?, ?, ?) ON CONFLICT (kind, name, locale) DO UPDATE SET display_name = EXCLUDED.display_name",
+        };
+        query(upsert)
+            .bind(kind.as_ref())
+            .bind(&name)
+            .bind(&locale)
+            .bind(&display)
+            .execute(pool)
+            .await?;
+        load_translations(pool).await?;
+
+        print_aesthetic_message(vec![tr!(
+            "translate-saved",
+            name = name.as_str(),
+            locale = locale.as_str(),
+            display = display.as_str()
+        )]);
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::messages::catalog::CATALOGS;
+        use std::collections::BTreeSet;
+
+        // every message starts at the beginning of a line with "id ="
+        fn message_ids(source: &str) -> BTreeSet<String> {
+            source
+                .lines()
+                .filter(|line| line.starts_with(|c: char| c.is_ascii_alphabetic()))
+                .filter_map(|line| line.split_once(" = ").map(|(id, _)| id.to_string()))
+                .collect()
+        }
+
+        #[test]
+        fn catalogs_have_the_same_messages() {
+            let english = message_ids(CATALOGS[0].1);
+            for (locale, source) in &CATALOGS[1..] {
+                let ids = message_ids(source);
+                let missing: Vec<&String> = english.difference(&ids).collect();
+                let extra: Vec<&String> = ids.difference(&english).collect();
+                assert!(
+                    missing.is_empty() && extra.is_empty(),
+                    "{} is missing {:?} and has extra {:?}",
+                    locale,
+                    missing,
+                    extra
+                );
+            }
+        }
+
+        #[test]
+        fn every_command_has_a_name_in_every_catalog() {
+            for (locale, source) in &CATALOGS {
+                let ids = message_ids(source);
+                for command in COMMANDS {
+                    assert!(
+                        ids.contains(&format!("command-{}", command)),
+                        "{} has no name for '{}'",
+                        locale,
+                        command
+                    );
+                }
+            }
+        }
+    }
+}
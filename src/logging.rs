@@ -0,0 +1,118 @@
+pub mod subscriber {
+    use std::io::{self, IsTerminal};
+    use std::path::Path;
+    use std::str::FromStr;
+    use tracing::level_filters::LevelFilter;
+    use tracing_appender::rolling::{RollingFileAppender, Rotation};
+    use tracing_subscriber::fmt::writer::BoxMakeWriter;
+    use tracing_subscriber::EnvFilter;
+
+    // appends to the file as it is, instead of starting a new one every day like tracing-appender would
+    fn file_writer(path: &str) -> Result<BoxMakeWriter, String> {
+        let cant_open =
+            |reason: String| format!("The log file {} can't be opened: {}", path, reason);
+        let path = Path::new(path);
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| cant_open("it isn't the name of a file".to_string()))?;
+        let folder = path
+            .parent()
+            .filter(|folder| !folder.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let appender = RollingFileAppender::builder()
+            .rotation(Rotation::NEVER)
+            .filename_prefix(file_name)
+            .build(folder)
+            .map_err(|e| cant_open(e.to_string()))?;
+        Ok(BoxMakeWriter::new(appender))
+    }
+
+    fn parse_level(level: &str) -> Result<EnvFilter, String> {
+        let invalid_level = || {
+            format!(
+                "The log level can't be '{}': use off, error, warn, info, debug or trace.",
+                level
+            )
+        };
+        // a single word would otherwise be taken as a module to log everything of
+        if !level.contains(['=', ',']) && LevelFilter::from_str(level).is_err() {
+            return Err(invalid_level());
+        }
+        EnvFilter::builder()
+            .parse(level)
+            .map_err(|_| invalid_level())
+    }
+
+    // level is off, error, warn, info, debug or trace, or a filter such as "warn,sqlx=debug"; format is
+    // text or json. Without a file the log goes to stderr, so it doesn't mix with the answers on stdout.
+    // The events of sqlx (the statements it runs, with their timings) are logged like our own.
+    pub fn init(level: &str, format: &str, file: Option<&str>) -> Result<(), String> {
+        let filter = parse_level(level)?;
+        // checked before the file is opened, so a wrong format doesn't leave an empty log behind
+        let json = match format.to_lowercase().as_str() {
+            "text" => false,
+            "json" => true,
+            _ => {
+                return Err(format!(
+                    "The log format can't be '{}': use text or json.",
+                    format
+                ))
+            }
+        };
+
+        let (writer, ansi) = match file {
+            Some(path) => (file_writer(path)?, false),
+            None => (BoxMakeWriter::new(io::stderr), io::stderr().is_terminal()),
+        };
+        let builder = tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(writer)
+            .with_ansi(ansi);
+
+        if json {
+            builder
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .try_init()
+        } else {
+            builder.try_init()
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    // only wrong settings are tried here: a valid one would install the subscriber for every test
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn levels_and_filters_are_accepted() {
+            for level in ["off", "warn", "TRACE", "warn,sqlx=debug", "sqlx=info"] {
+                assert!(parse_level(level).is_ok(), "{}", level);
+            }
+        }
+
+        #[test]
+        fn a_word_that_isnt_a_level_is_refused() {
+            assert_eq!(
+                parse_level("loud").unwrap_err(),
+                "The log level can't be 'loud': use off, error, warn, info, debug or trace."
+            );
+            assert!(parse_level("warn,sqlx=loud").is_err());
+            assert!(init("loud", "text", None).is_err());
+        }
+
+        #[test]
+        fn a_wrong_format_is_refused_before_the_file_is_opened() {
+            let path =
+                std::env::temp_dir().join(format!("logging_test_{}.log", std::process::id()));
+            let error = init("warn", "xml", path.to_str()).unwrap_err();
+            assert_eq!(error, "The log format can't be 'xml': use text or json.");
+            assert!(!path.exists());
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
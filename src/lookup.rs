@@ -0,0 +1,376 @@
+pub mod lookup_commands {
+    use crate::health::health_commands::is_connection_error;
+    use crate::localization::localization_commands::{canonical_name, tr, TranslationKind};
+    use crate::print_aesthetic_message;
+    use crate::storage::backend::{checked_query, Pool};
+    use std::{cmp::Ordering, error::Error, io};
+
+    const MAX_PICKS: usize = 5;
+
+    struct Candidate {
+        name: String,
+        // the alias or nickname that matched, if it wasn't the name itself
+        via: Option<String>,
+        // lower is better: 0 is an exact match, then prefixes, then typos
+        score: usize,
+    }
+
+    // "Abigail ", "abigail" and "A-bi-gail" are all the same name
+    pub(crate) fn normalize(name: &str) -> String {
+        name.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous: Vec<usize> = (0..=b.len()).collect();
+        for (i, a_char) in a.chars().enumerate() {
+            let mut current = vec![i + 1];
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution = previous[j] + usize::from(a_char != *b_char);
+                current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+            }
+            previous = current;
+        }
+        previous[b.len()]
+    }
+
+    // short names only forgive one typo, longer ones a few more
+    fn allowed_typos(input: &str) -> usize {
+        match input.chars().count() {
+            0..=2 => 0,
+            3..=5 => 1,
+            6..=9 => 2,
+            _ => 3,
+        }
+    }
+
+    fn score(input: &str, candidate: &str) -> Option<usize> {
+        let candidate = normalize(candidate);
+        if candidate == input {
+            Some(0)
+        } else if candidate.starts_with(input) {
+            Some(1 + candidate.len() - input.len())
+        } else {
+            let distance = edit_distance(input, &candidate);
+            // the prefix of the same length counts too, so "sebasi" still finds Sebastian
+            let prefix: String = candidate.chars().take(input.chars().count()).collect();
+            let distance = distance.min(edit_distance(input, &prefix) + 1);
+            (distance <= allowed_typos(input)).then_some(100 + distance)
+        }
+    }
+
+    // every name and nickname, each with the character it belongs to
+    async fn read_names(pool: &Pool) -> Result<Vec<(String, Option<String>)>, Box<dyn Error>> {
+        let mut names: Vec<(String, Option<String>)> =
+            checked_query!("SELECT name FROM characters")
+                .fetch_all(pool)
+                .await?
+                .into_iter()
+                .map(|row| (row.get::<String, _>("name"), None))
+                .collect();
+        names.extend(
+            checked_query!("SELECT character_name, alias FROM aliases")
+                .fetch_all(pool)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    (
+                        row.get::<String, _>("character_name"),
+                        Some(row.get::<String, _>("alias")),
+                    )
+                }),
+        );
+        Ok(names)
+    }
+
+    // the characters whose name or nickname is exactly the input, without reading all of them
+    async fn find_exact_candidates(
+        pool: &Pool,
+        input: &str,
+    ) -> Result<Vec<Candidate>, Box<dyn Error>> {
+        let mut candidates: Vec<Candidate> =
+            checked_query!("SELECT name FROM characters WHERE name = $1", input)
+                .fetch_all(pool)
+                .await?
+                .into_iter()
+                .map(|row| Candidate {
+                    name: row.get::<String, _>("name"),
+                    via: None,
+                    score: 0,
+                })
+                .collect();
+        for row in checked_query!(
+            "SELECT character_name, alias FROM aliases WHERE alias = $1",
+            input
+        )
+        .fetch_all(pool)
+        .await?
+        {
+            let name = row.get::<String, _>("character_name");
+            if !candidates.iter().any(|c| c.name == name) {
+                candidates.push(Candidate {
+                    name,
+                    via: Some(row.get::<String, _>("alias")),
+                    score: 0,
+                });
+            }
+        }
+        Ok(candidates)
+    }
+
+    // the best match for each character, best first
+    fn rank_candidates(input: &str, names: Vec<(String, Option<String>)>) -> Vec<Candidate> {
+        let mut candidates: Vec<Candidate> = vec![];
+        for (name, via) in names {
+            let score = match score(input, via.as_deref().unwrap_or(&name)) {
+                Some(s) => s,
+                None => continue,
+            };
+            match candidates.iter_mut().find(|c| c.name == name) {
+                Some(existing) if existing.score <= score => {}
+                Some(existing) => {
+                    existing.score = score;
+                    existing.via = via;
+                }
+                None => candidates.push(Candidate { name, via, score }),
+            }
+        }
+        candidates.sort_by(|a, b| match a.score.cmp(&b.score) {
+            Ordering::Equal => a.name.cmp(&b.name),
+            ordering => ordering,
+        });
+        candidates
+    }
+
+    async fn find_candidates(pool: &Pool, input: &str) -> Result<Vec<Candidate>, Box<dyn Error>> {
+        let exact = find_exact_candidates(pool, input).await?;
+        if !exact.is_empty() {
+            return Ok(exact);
+        }
+        let input = normalize(input);
+        if input.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(rank_candidates(&input, read_names(pool).await?))
+    }
+
+    // how many of the words at the start are the character's name: the most words that are
+    // a whole name or nickname, so 'Mr Qi' is one name, and otherwise just the first word
+    fn name_length(arguments: &[&str], names: &[(String, Option<String>)]) -> usize {
+        (2..=arguments.len())
+            .rev()
+            .find(|&length| {
+                let input = normalize(&canonical_name(
+                    &TranslationKind::Character,
+                    &arguments[..length].join(" "),
+                ));
+                names
+                    .iter()
+                    .any(|(name, via)| normalize(via.as_deref().unwrap_or(name)) == input)
+            })
+            .unwrap_or(1)
+    }
+
+    // splits the arguments into the character's name and the rest, for commands
+    // like 'alias <character_name> <nickname>' where both may have several words
+    pub(crate) async fn split_character_name<'a>(
+        pool: &Pool,
+        arguments: &'a [&'a str],
+    ) -> Result<(String, &'a [&'a str]), Box<dyn Error>> {
+        let length = if arguments.len() > 1 {
+            name_length(arguments, &read_names(pool).await?)
+        } else {
+            1
+        };
+        Ok((arguments[..length].join(" "), &arguments[length..]))
+    }
+
+    // asks which character was meant and returns None if the answer isn't one of the numbers
+    fn pick(input: &str, candidates: &[Candidate]) -> Option<String> {
+        let mut messages = vec![tr!("lookup-ambiguous", input = input)];
+        for (number, candidate) in candidates.iter().take(MAX_PICKS).enumerate() {
+            messages.push(match &candidate.via {
+                Some(alias) => format!("{}. {} ({})", number + 1, &candidate.name, alias),
+                None => format!("{}. {}", number + 1, &candidate.name),
+            });
+        }
+        print_aesthetic_message(messages);
+        println!("{}", tr!("lookup-pick"));
+
+        let mut answer = String::new();
+        io::stdin()
+            .read_line(&mut answer)
+            .expect("Failed to read input!");
+        match answer.trim().parse::<usize>() {
+            Ok(number) if (1..=candidates.len().min(MAX_PICKS)).contains(&number) => {
+                Some(candidates[number - 1].name.clone())
+            }
+            _ => {
+                println!("{}", tr!("lookup-cancelled"));
+                None
+            }
+        }
+    }
+
+    // finds the character the user meant: by name or alias, ignoring case, spaces and punctuation,
+    // by the beginning of the name, or despite a typo. Asks when there is more than one option.
+    pub(crate) async fn resolve_character(
+        pool: &Pool,
+        input: &str,
+    ) -> Result<Option<String>, Box<dyn Error>> {
+        let input = canonical_name(&TranslationKind::Character, input.trim());
+        let candidates = find_candidates(pool, &input).await?;
+
+        match candidates.as_slice() {
+            [] => {
+                print_aesthetic_message(vec![tr!("character-not-found", name = input.as_str())]);
+                Ok(None)
+            }
+            [only] => Ok(Some(only.name.clone())),
+            [best, second, ..] if best.score == 0 && second.score > 0 => {
+                Ok(Some(best.name.clone()))
+            }
+            _ => Ok(pick(&input, &candidates)),
+        }
+    }
+
+    pub async fn handle_alias_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("alias-usage"));
+            println!("{}", tr!("alias-example"));
+            return Ok(());
+        }
+
+        let (name, alias) = split_character_name(pool, &arguments).await?;
+        let character_name = match resolve_character(pool, &name).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        if alias.is_empty() {
+            let aliases: Vec<String> = checked_query!(
+                "SELECT alias FROM aliases WHERE character_name = $1 ORDER BY alias",
+                &character_name
+            )
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|row| row.get::<String, _>("alias"))
+            .collect();
+            print_aesthetic_message(vec![if aliases.is_empty() {
+                tr!("alias-none", name = character_name.as_str())
+            } else {
+                tr!(
+                    "alias-list",
+                    name = character_name.as_str(),
+                    aliases = aliases.join(", ")
+                )
+            }]);
+            return Ok(());
+        }
+
+        let alias = alias.join(" ");
+        let result = checked_query!(
+            "INSERT INTO aliases (alias, character_name) VALUES ($1, $2)",
+            &alias,
+            &character_name
+        )
+        .execute(pool)
+        .await;
+
+        match result {
+            Ok(_) => print_aesthetic_message(vec![tr!(
+                "alias-added",
+                name = character_name.as_str(),
+                alias = alias.as_str()
+            )]),
+            Err(e) if is_connection_error(&e) => return Err(e.into()),
+            Err(e) => print_aesthetic_message(vec![
+                tr!("alias-error", alias = alias.as_str()),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn names() -> Vec<(String, Option<String>)> {
+            vec![
+                ("Abigail".to_string(), None),
+                ("Abigail".to_string(), Some("Abby".to_string())),
+                ("Alex".to_string(), None),
+                ("Mr. Qi".to_string(), None),
+                ("Sam".to_string(), None),
+            ]
+        }
+
+        #[test]
+        fn normalized_names_ignore_case_spaces_and_punctuation() {
+            assert_eq!(normalize(" A-bi Gail "), "abigail");
+            assert_eq!(normalize("Mr. Qi"), "mrqi");
+            assert_eq!(normalize("Élliott"), "élliott");
+            assert_eq!(normalize("?!"), "");
+        }
+
+        #[test]
+        fn edit_distance_counts_single_character_edits() {
+            assert_eq!(edit_distance("abigail", "abigail"), 0);
+            assert_eq!(edit_distance("abigial", "abigail"), 2);
+            assert_eq!(edit_distance("sam", "sams"), 1);
+            assert_eq!(edit_distance("", "sam"), 3);
+            assert_eq!(edit_distance("émily", "emily"), 1);
+        }
+
+        #[test]
+        fn longer_names_allow_more_typos() {
+            assert_eq!(allowed_typos("ab"), 0);
+            assert_eq!(allowed_typos("sam"), 1);
+            assert_eq!(allowed_typos("abigail"), 2);
+            assert_eq!(allowed_typos("maximilian"), 3);
+            // characters, not bytes
+            assert_eq!(allowed_typos("émé"), 1);
+        }
+
+        #[test]
+        fn exact_matches_beat_prefixes_which_beat_typos() {
+            assert_eq!(score("abigail", "Abigail"), Some(0));
+            assert_eq!(score("abi", "Abigail"), Some(5));
+            assert_eq!(score("abigial", "Abigail"), Some(102));
+            assert_eq!(score("sebasi", "Sebastian"), Some(102));
+            assert_eq!(score("abgi", "Abigail"), None);
+            assert_eq!(score("sebastian", "Abigail"), None);
+            assert_eq!(score("ab", "Alex"), None);
+        }
+
+        #[test]
+        fn candidates_are_ranked_once_per_character() {
+            let candidates = rank_candidates("ab", names());
+            assert_eq!(candidates.len(), 1);
+            assert_eq!(candidates[0].name, "Abigail");
+            // 'abby' is a closer prefix than 'abigail'
+            assert_eq!(candidates[0].via.as_deref(), Some("Abby"));
+
+            let candidates = rank_candidates("a", names());
+            let found: Vec<&str> = candidates.iter().map(|c| c.name.as_str()).collect();
+            assert_eq!(found, ["Abigail", "Alex"]);
+        }
+
+        #[test]
+        fn the_name_is_the_most_words_that_name_someone() {
+            assert_eq!(name_length(&["mr", "qi", "qi"], &names()), 2);
+            assert_eq!(name_length(&["Mr.", "Qi"], &names()), 2);
+            assert_eq!(name_length(&["abigail", "purple", "hair"], &names()), 1);
+            assert_eq!(name_length(&["abi", "purple"], &names()), 1);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+pub mod catalog {
+    use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource, FluentValue};
+    use std::{
+        env,
+        sync::{OnceLock, RwLock},
+    };
+    use unic_langid::LanguageIdentifier;
+
+    pub(crate) const DEFAULT_LOCALE: &str = "en";
+
+    // the message catalogs are compiled into the program, one Fluent file per language
+    pub(crate) const CATALOGS: [(&str, &str); 3] = [
+        ("en", include_str!("../locales/en.ftl")),
+        ("de", include_str!("../locales/de.ftl")),
+        ("fr", include_str!("../locales/fr.ftl")),
+    ];
+
+    static BUNDLES: OnceLock<Vec<(&'static str, FluentBundle<FluentResource>)>> = OnceLock::new();
+    static LOCALE: RwLock<&'static str> = RwLock::new(DEFAULT_LOCALE);
+
+    fn bundles() -> &'static Vec<(&'static str, FluentBundle<FluentResource>)> {
+        BUNDLES.get_or_init(|| {
+            CATALOGS
+                .iter()
+                .map(|(locale, source)| {
+                    let resource = FluentResource::try_new(source.to_string())
+                        .unwrap_or_else(|_| panic!("The {} message catalog is broken!", locale));
+                    let language: LanguageIdentifier =
+                        locale.parse().expect("Invalid locale in the catalogs!");
+                    let mut bundle = FluentBundle::new_concurrent(vec![language]);
+                    // the invisible isolation marks around arguments only confuse terminals
+                    bundle.set_use_isolating(false);
+                    bundle
+                        .add_resource(resource)
+                        .unwrap_or_else(|_| panic!("The {} message catalog is broken!", locale));
+                    (*locale, bundle)
+                })
+                .collect()
+        })
+    }
+
+    fn bundle(locale: &str) -> Option<&'static FluentBundle<FluentResource>> {
+        bundles()
+            .iter()
+            .find(|(l, _)| *l == locale)
+            .map(|(_, bundle)| bundle)
+    }
+
+    fn format(locale: &str, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let bundle = bundle(locale)?;
+        let pattern = bundle.get_message(id)?.value()?;
+        let mut errors = vec![];
+        Some(
+            bundle
+                .format_pattern(pattern, args, &mut errors)
+                .into_owned(),
+        )
+    }
+
+    pub(crate) fn current_locale() -> &'static str {
+        *LOCALE.read().unwrap()
+    }
+
+    pub(crate) fn locales() -> Vec<&'static str> {
+        CATALOGS.iter().map(|(locale, _)| *locale).collect()
+    }
+
+    // accepts "de", "de_DE.UTF-8" or "de-AT" and keeps only the language
+    pub(crate) fn set_locale(locale: &str) -> bool {
+        let language = locale
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        match CATALOGS.iter().find(|(l, _)| *l == language) {
+            Some((l, _)) => {
+                *LOCALE.write().unwrap() = l;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // the configured locale wins over the system's LANG
+    pub(crate) fn init_locale(configured: Option<&str>) {
+        if configured.is_some_and(set_locale) {
+            return;
+        }
+        if let Ok(locale) = env::var("LANG") {
+            set_locale(&locale);
+        }
+    }
+
+    // looks up a message in the active language, falls back to English, and to the id itself
+    pub(crate) fn text_with(id: &str, args: &[(&str, FluentValue)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        format(current_locale(), id, Some(&fluent_args))
+            .or_else(|| format(DEFAULT_LOCALE, id, Some(&fluent_args)))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    pub(crate) fn text(id: &str) -> String {
+        text_with(id, &[])
+    }
+
+    macro_rules! tr {
+        ($id:expr) => {
+            $crate::messages::catalog::text($id)
+        };
+        ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {
+            $crate::messages::catalog::text_with(
+                $id,
+                &[$((stringify!($name), fluent_bundle::FluentValue::from($value))),+],
+            )
+        };
+    }
+    pub(crate) use tr;
+
+    // true when the input is the message in any of the languages, e.g. "all", "alle" or "tout"
+    pub(crate) fn matches_text(input: &str, id: &str) -> bool {
+        locales().iter().any(|locale| {
+            format(locale, id, None).is_some_and(|t| t.to_lowercase() == input.to_lowercase())
+        })
+    }
+}
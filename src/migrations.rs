@@ -0,0 +1,486 @@
+pub mod migration_commands {
+    use crate::config::settings::Config;
+    use crate::localization::localization_commands::{load_translations, tr};
+    use crate::profiles::profile_commands::Session;
+    use crate::storage::backend::{query, Backend, Pool};
+    use crate::{
+        connect_as_migrator, print_aesthetic_message, schema_change_error, setup_initial_values,
+    };
+    use sqlx::migrate;
+    use sqlx::migrate::{Migration, Migrator};
+    use std::{error::Error, io};
+
+    // every migration is an .up.sql with a matching .down.sql, so each one can be reverted.
+    // Both servers have the same versions, so a database can be moved from one to the other.
+    static MYSQL_MIGRATOR: Migrator = migrate!("./migrations/mysql");
+    static POSTGRES_MIGRATOR: Migrator = migrate!("./migrations/postgres");
+
+    pub(crate) fn migrator(backend: Backend) -> &'static Migrator {
+        match backend {
+            Backend::MySql => &MYSQL_MIGRATOR,
+            Backend::Postgres => &POSTGRES_MIGRATOR,
+        }
+    }
+
+    // the grants setup_db gives on MySQL cover every table of the database, but Postgres grants
+    // table by table, so the app user is given the tables the migrator has just created
+    async fn grant_to_app_user(migrator_pool: &Pool, pool: &Pool) -> Result<(), sqlx::Error> {
+        if migrator_pool.backend() != Backend::Postgres {
+            return Ok(());
+        }
+        let schema = query("SELECT current_schema() AS name")
+            .fetch_one(migrator_pool)
+            .await?
+            .get::<String, _>("name");
+        let quote = |name: &str| format!("\"{}\"", name.replace('"', "\"\""));
+        let (schema, user) = (quote(&schema), quote(pool.connect_options().username()));
+        query(&format!(
+            "GRANT SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA {} TO {}",
+            schema, user
+        ))
+        .execute(migrator_pool)
+        .await?;
+        query(&format!(
+            "GRANT USAGE, SELECT ON ALL SEQUENCES IN SCHEMA {} TO {}",
+            schema, user
+        ))
+        .execute(migrator_pool)
+        .await?;
+        Ok(())
+    }
+
+    // undoes everything after the given version, or applies what is pending without one. This runs
+    // as the migrator when there is one, see connect_as_migrator.
+    async fn change_schema(
+        pool: &Pool,
+        config: &Config,
+        target: Option<i64>,
+    ) -> Result<(), Box<dyn Error>> {
+        let migrator_pool = connect_as_migrator(config, pool).await?;
+        let schema_pool = migrator_pool.as_ref().unwrap_or(pool);
+        let mut result: Result<(), Box<dyn Error>> = match (schema_pool, target) {
+            (Pool::MySql(p), None) => MYSQL_MIGRATOR.run(p).await.map_err(Into::into),
+            (Pool::MySql(p), Some(target)) => {
+                MYSQL_MIGRATOR.undo(p, target).await.map_err(Into::into)
+            }
+            (Pool::Postgres(p), None) => POSTGRES_MIGRATOR.run(p).await.map_err(Into::into),
+            (Pool::Postgres(p), Some(target)) => {
+                POSTGRES_MIGRATOR.undo(p, target).await.map_err(Into::into)
+            }
+        };
+        if let (Ok(()), Some(migrator_pool)) = (&result, &migrator_pool) {
+            result = grant_to_app_user(migrator_pool, pool)
+                .await
+                .map_err(Into::into);
+        }
+        if let Some(migrator_pool) = migrator_pool {
+            migrator_pool.close().await;
+        }
+        result.map_err(|e| schema_change_error(config, e))
+    }
+
+    // a row of the table sqlx keeps its bookkeeping in
+    struct AppliedMigration {
+        version: i64,
+        installed_on: String,
+        success: bool,
+        checksum: Vec<u8>,
+    }
+
+    // how the database compares to the migrations built into this binary
+    pub(crate) struct SchemaState {
+        migrator: &'static Migrator,
+        applied: Vec<AppliedMigration>,
+        // known to this binary, but not applied yet
+        pending: Vec<&'static Migration>,
+        // applied by a newer version of the application
+        unknown: Vec<i64>,
+        // applied, but the file was changed afterwards
+        changed: Vec<i64>,
+        // a migration that failed halfway
+        failed: Vec<i64>,
+    }
+
+    fn up_migrations(
+        migrator: &'static Migrator,
+    ) -> impl DoubleEndedIterator<Item = &'static Migration> {
+        migrator
+            .iter()
+            .filter(|m| m.migration_type.is_up_migration())
+    }
+
+    fn down_migration(migrator: &'static Migrator, version: i64) -> Option<&'static Migration> {
+        migrator
+            .iter()
+            .find(|m| m.version == version && m.migration_type.is_down_migration())
+    }
+
+    fn latest_version(migrator: &'static Migrator) -> i64 {
+        up_migrations(migrator)
+            .map(|m| m.version)
+            .max()
+            .unwrap_or(0)
+    }
+
+    // reading it doesn't create the table, so 'migrate status' never changes anything
+    async fn read_applied_migrations(pool: &Pool) -> Result<Vec<AppliedMigration>, sqlx::Error> {
+        let schema = match pool.backend() {
+            Backend::MySql => "DATABASE()",
+            Backend::Postgres => "current_schema()",
+        };
+        let exists = query(&format!("SELECT COUNT(*) AS count FROM information_schema.tables WHERE table_schema = {} AND table_name = '_sqlx_migrations'", schema))
+            .fetch_one(pool)
+            .await?
+            .get::<i64, _>("count")
+            > 0;
+        if !exists {
+            return Ok(vec![]);
+        }
+
+        let installed_on = match pool.backend() {
+            Backend::MySql => "DATE_FORMAT(installed_on, '%Y-%m-%d %H:%i')",
+            Backend::Postgres => "to_char(installed_on, 'YYYY-MM-DD HH24:MI')",
+        };
+        Ok(query(&format!("SELECT version, {} AS installed_on, success, checksum FROM _sqlx_migrations ORDER BY version", installed_on))
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|row| AppliedMigration {
+                version: row.get::<i64, _>("version"),
+                installed_on: row.get::<String, _>("installed_on"),
+                success: row.get::<bool, _>("success"),
+                checksum: row.get::<Vec<u8>, _>("checksum"),
+            })
+            .collect())
+    }
+
+    pub(crate) async fn schema_state(pool: &Pool) -> Result<SchemaState, sqlx::Error> {
+        let migrator = migrator(pool.backend());
+        let applied = read_applied_migrations(pool).await?;
+
+        let pending = up_migrations(migrator)
+            .filter(|m| applied.iter().all(|a| a.version != m.version))
+            .collect();
+        let unknown = applied
+            .iter()
+            .filter(|a| up_migrations(migrator).all(|m| m.version != a.version))
+            .map(|a| a.version)
+            .collect();
+        let changed = applied
+            .iter()
+            .filter(|a| {
+                up_migrations(migrator)
+                    .any(|m| m.version == a.version && *m.checksum != *a.checksum)
+            })
+            .map(|a| a.version)
+            .collect();
+        let failed = applied
+            .iter()
+            .filter(|a| !a.success)
+            .map(|a| a.version)
+            .collect();
+
+        Ok(SchemaState {
+            migrator,
+            applied,
+            pending,
+            unknown,
+            changed,
+            failed,
+        })
+    }
+
+    // a schema that is ahead of the binary, was changed or is half migrated is never touched
+    fn check_schema(state: &SchemaState) -> Result<(), Box<dyn Error>> {
+        if let Some(version) = state.unknown.iter().max() {
+            return Err(tr!(
+                "migrate-ahead",
+                version = *version,
+                latest = latest_version(state.migrator)
+            )
+            .into());
+        }
+        if let Some(version) = state.failed.first() {
+            return Err(tr!("migrate-failed", version = *version).into());
+        }
+        if let Some(version) = state.changed.first() {
+            return Err(tr!("migrate-changed", version = *version).into());
+        }
+        Ok(())
+    }
+
+    // runs at startup and whenever a profile is opened. Without auto_migrate, pending migrations
+    // are only reported, and false is returned so that nothing is written to the old schema.
+    pub(crate) async fn migrate_on_startup(
+        pool: &Pool,
+        config: &Config,
+        auto_migrate: bool,
+    ) -> Result<bool, Box<dyn Error>> {
+        let state = schema_state(pool).await?;
+        check_schema(&state)?;
+
+        if state.pending.is_empty() {
+            return Ok(true);
+        }
+        if !auto_migrate {
+            println!(
+                "{}",
+                tr!("migrate-pending-startup", count = state.pending.len())
+            );
+            return Ok(false);
+        }
+        change_schema(pool, config, None).await?;
+        Ok(true)
+    }
+
+    fn print_sql(messages: &mut Vec<String>, migration: &Migration) {
+        messages.push(format!(
+            "-- {} {}",
+            migration.version, &migration.description
+        ));
+        messages.extend(migration.sql.lines().map(str::to_string));
+    }
+
+    async fn show_status(session: &Session) -> Result<(), Box<dyn Error>> {
+        let state = schema_state(&session.pool).await?;
+
+        let mut messages = vec![tr!("migrate-status", profile = session.profile.as_str())];
+        for migration in up_migrations(state.migrator) {
+            let applied = state
+                .applied
+                .iter()
+                .find(|a| a.version == migration.version);
+            let status = match applied {
+                None => tr!("migrate-state-pending"),
+                Some(a) if !a.success => tr!("migrate-state-failed"),
+                Some(a) if state.changed.contains(&a.version) => tr!("migrate-state-changed"),
+                Some(a) => tr!("migrate-state-applied", date = a.installed_on.as_str()),
+            };
+            let reversible = if down_migration(state.migrator, migration.version).is_some() {
+                "↕"
+            } else {
+                " "
+            };
+            messages.push(format!(
+                "{:04} {} {:<20} {}",
+                migration.version, reversible, &migration.description, status
+            ));
+        }
+        for version in &state.unknown {
+            messages.push(format!("{:04}   {}", version, tr!("migrate-state-unknown")));
+        }
+        messages.push(if state.pending.is_empty() {
+            tr!("migrate-up-to-date")
+        } else {
+            tr!("migrate-pending", count = state.pending.len())
+        });
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    async fn migrate_up(session: &mut Session, dry_run: bool) -> Result<(), Box<dyn Error>> {
+        let state = schema_state(&session.pool).await?;
+        if let Err(e) = check_schema(&state) {
+            print_aesthetic_message(vec![e.to_string()]);
+            return Ok(());
+        }
+
+        if state.pending.is_empty() {
+            print_aesthetic_message(vec![tr!("migrate-up-to-date")]);
+            return Ok(());
+        }
+        if dry_run {
+            let mut messages = vec![tr!("migrate-dry-run-up", count = state.pending.len())];
+            for migration in &state.pending {
+                print_sql(&mut messages, migration);
+            }
+            print_aesthetic_message(messages);
+            return Ok(());
+        }
+
+        if let Err(e) = change_schema(&session.pool, &session.config, None).await {
+            print_aesthetic_message(vec![tr!("migrate-error"), format!("{e}")]);
+            return Ok(());
+        }
+        session.reconnect_after_migration().await?;
+        setup_initial_values(&session.pool).await?;
+        load_translations(&session.pool).await?;
+        print_aesthetic_message(vec![tr!(
+            "migrate-up-done",
+            count = state.pending.len(),
+            version = latest_version(state.migrator)
+        )]);
+
+        Ok(())
+    }
+
+    async fn migrate_down(
+        session: &mut Session,
+        target: i64,
+        dry_run: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        let state = schema_state(&session.pool).await?;
+        if let Err(e) = check_schema(&state) {
+            print_aesthetic_message(vec![e.to_string()]);
+            return Ok(());
+        }
+
+        let mut reverted: Vec<&Migration> = vec![];
+        for applied in state.applied.iter().rev().filter(|a| a.version > target) {
+            match down_migration(state.migrator, applied.version) {
+                Some(migration) => reverted.push(migration),
+                None => {
+                    println!("{}", tr!("migrate-irreversible", version = applied.version));
+                    return Ok(());
+                }
+            }
+        }
+        if reverted.is_empty() {
+            print_aesthetic_message(vec![tr!("migrate-down-nothing", version = target)]);
+            return Ok(());
+        }
+
+        let mut messages = vec![tr!(
+            "migrate-dry-run-down",
+            count = reverted.len(),
+            version = target
+        )];
+        for migration in &reverted {
+            print_sql(&mut messages, migration);
+        }
+        print_aesthetic_message(messages);
+        if dry_run {
+            return Ok(());
+        }
+
+        // dropping tables loses data, so this has to be confirmed just like deleting a character
+        println!(
+            "{}",
+            tr!(
+                "migrate-down-confirm",
+                version = target,
+                profile = session.profile.as_str()
+            )
+        );
+        let mut confirmation = String::new();
+        io::stdin()
+            .read_line(&mut confirmation)
+            .expect("Failed to read input!");
+        if confirmation.trim() != target.to_string() {
+            println!("{}", tr!("migrate-down-cancelled"));
+            return Ok(());
+        }
+
+        if let Err(e) = change_schema(&session.pool, &session.config, Some(target)).await {
+            print_aesthetic_message(vec![tr!("migrate-error"), format!("{e}")]);
+            return Ok(());
+        }
+        session.reconnect_after_migration().await?;
+        print_aesthetic_message(vec![tr!(
+            "migrate-down-done",
+            count = reverted.len(),
+            version = target
+        )]);
+
+        Ok(())
+    }
+
+    pub async fn handle_migrate_command(
+        session: &mut Session,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let dry_run = arguments.contains(&"--dry-run");
+        let arguments: Vec<&str> = arguments
+            .into_iter()
+            .filter(|a| *a != "--dry-run")
+            .collect();
+
+        match arguments.as_slice() {
+            [] | ["status"] => show_status(session).await,
+            ["up"] => migrate_up(session, dry_run).await,
+            ["down", version] => match version.parse::<i64>() {
+                Ok(target) if target >= 0 => migrate_down(session, target, dry_run).await,
+                _ => {
+                    println!("{}", tr!("migrate-invalid-version", input = *version));
+                    Ok(())
+                }
+            },
+            _ => {
+                println!("{}", tr!("migrate-usage"));
+                println!("{}", tr!("migrate-example"));
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn versions(migrator: &'static Migrator) -> Vec<(i64, String)> {
+            up_migrations(migrator)
+                .map(|m| (m.version, m.description.to_string()))
+                .collect()
+        }
+
+        fn state(unknown: Vec<i64>, failed: Vec<i64>, changed: Vec<i64>) -> SchemaState {
+            SchemaState {
+                migrator: &POSTGRES_MIGRATOR,
+                applied: vec![],
+                pending: vec![],
+                unknown,
+                changed,
+                failed,
+            }
+        }
+
+        #[test]
+        fn both_servers_have_the_same_migrations() {
+            assert_eq!(versions(&MYSQL_MIGRATOR), versions(&POSTGRES_MIGRATOR));
+        }
+
+        #[test]
+        fn every_migration_can_be_reverted() {
+            for migrator in [&MYSQL_MIGRATOR, &POSTGRES_MIGRATOR] {
+                for migration in up_migrations(migrator) {
+                    assert!(
+                        down_migration(migrator, migration.version).is_some(),
+                        "{} has no .down.sql",
+                        migration.version
+                    );
+                }
+                assert_eq!(migrator.iter().count(), 2 * up_migrations(migrator).count());
+            }
+        }
+
+        #[test]
+        fn a_schema_that_matches_the_migrations_can_be_used() {
+            assert!(check_schema(&state(vec![], vec![], vec![])).is_ok());
+        }
+
+        #[test]
+        fn a_schema_ahead_failed_or_changed_is_refused() {
+            let latest = latest_version(&POSTGRES_MIGRATOR);
+            let error = |state: SchemaState| check_schema(&state).unwrap_err().to_string();
+
+            assert_eq!(
+                error(state(vec![latest + 1, latest + 2], vec![], vec![])),
+                tr!("migrate-ahead", version = latest + 2, latest = latest)
+            );
+            assert_eq!(
+                error(state(vec![], vec![3], vec![])),
+                tr!("migrate-failed", version = 3)
+            );
+            assert_eq!(
+                error(state(vec![], vec![], vec![4])),
+                tr!("migrate-changed", version = 4)
+            );
+            // a newer schema is reported first, as this binary can't judge the rest of it
+            assert_eq!(
+                error(state(vec![latest + 1], vec![3], vec![4])),
+                tr!("migrate-ahead", version = latest + 1, latest = latest)
+            );
+        }
+    }
+}
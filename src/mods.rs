@@ -0,0 +1,434 @@
+pub mod mod_commands {
+    use crate::localization::localization_commands::tr;
+    use crate::storage::backend::{checked_query, query, Backend, Pool};
+    use crate::{print_aesthetic_message, GiftTaste, Season, VANILLA_SOURCE};
+    use serde_json::Value;
+    use std::{collections::HashMap, error::Error, fs, path::Path, str::FromStr};
+
+    // Data/NPCGiftTastes: "love text/love items/like text/like items/dislike text/dislike items/hate text/hate items/..."
+    const GIFT_TASTE_FIELDS: [(usize, GiftTaste); 4] = [
+        (1, GiftTaste::Love),
+        (3, GiftTaste::Like),
+        (5, GiftTaste::Dislike),
+        (7, GiftTaste::Hate),
+    ];
+
+    struct Manifest {
+        unique_id: String,
+        name: String,
+    }
+
+    #[derive(Default)]
+    struct ModCharacter {
+        birthday: Option<(Season, u8)>,
+        is_bachelor: bool,
+    }
+
+    #[derive(Default)]
+    struct ContentPack {
+        characters: HashMap<String, ModCharacter>,
+        gift_tastes: HashMap<String, Vec<(GiftTaste, String)>>,
+    }
+
+    fn read_json(path: &Path) -> Result<Value, String> {
+        let path_name = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|e| {
+            tr!(
+                "mod-read-error",
+                path = path_name.as_str(),
+                error = e.to_string()
+            )
+        })?;
+        // SMAPI allows comments and trailing commas, so the files are read as JSON5
+        json5::from_str::<Value>(&text).map_err(|e| {
+            tr!(
+                "mod-invalid-json",
+                path = path_name.as_str(),
+                error = e.to_string()
+            )
+        })
+    }
+
+    fn read_manifest(folder: &Path) -> Result<Manifest, String> {
+        let manifest = read_json(&folder.join("manifest.json"))?;
+        let field = |name: &str| {
+            manifest
+                .get(name)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+        let unique_id = field("UniqueID").ok_or_else(|| tr!("mod-missing-unique-id"))?;
+        if unique_id.eq_ignore_ascii_case(VANILLA_SOURCE) || unique_id.len() > 100 {
+            return Err(tr!("mod-invalid-id", id = unique_id));
+        }
+        let name = field("Name").unwrap_or_else(|| unique_id.clone());
+        Ok(Manifest { unique_id, name })
+    }
+
+    // the birthday of the old NPCDispositions format looks like "summer 10"
+    fn parse_birthday(text: &str) -> Option<(Season, u8)> {
+        let (season, day) = text.trim().split_once(' ')?;
+        let season = Season::from_str(season).ok()?;
+        let day = day
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|d| (1..=28).contains(d))?;
+        Some((season, day))
+    }
+
+    fn read_characters_entry(character: &mut ModCharacter, entry: &Value) {
+        let season = entry
+            .get("BirthSeason")
+            .and_then(Value::as_str)
+            .and_then(|s| Season::from_str(s).ok());
+        let day = entry
+            .get("BirthDay")
+            .and_then(Value::as_u64)
+            .filter(|d| (1..=28).contains(d));
+        if let (Some(season), Some(day)) = (season, day) {
+            character.birthday = Some((season, day as u8));
+        }
+        if let Some(can_be_romanced) = entry.get("CanBeRomanced").and_then(Value::as_bool) {
+            character.is_bachelor = can_be_romanced;
+        }
+    }
+
+    fn read_disposition_entry(character: &mut ModCharacter, entry: &str) {
+        let fields: Vec<&str> = entry.split('/').collect();
+        if let Some(datable) = fields.get(5) {
+            character.is_bachelor = *datable == "datable";
+        }
+        if let Some(birthday) = fields.get(8).and_then(|b| parse_birthday(b)) {
+            character.birthday = Some(birthday);
+        }
+    }
+
+    fn read_gift_taste_entry(entry: &str) -> Vec<(GiftTaste, String)> {
+        let fields: Vec<&str> = entry.split('/').collect();
+        let mut tastes = vec![];
+        for (index, taste) in GIFT_TASTE_FIELDS {
+            if let Some(items) = fields.get(index) {
+                tastes.extend(
+                    items
+                        .split_whitespace()
+                        .map(|item| (taste, item.to_string())),
+                );
+            }
+        }
+        tastes
+    }
+
+    // only the Content Patcher changes that add villagers or gift tastes are used, everything else is skipped
+    fn parse_content_pack(content: &Value) -> Result<ContentPack, String> {
+        let changes = content
+            .get("Changes")
+            .and_then(Value::as_array)
+            .ok_or_else(|| tr!("mod-missing-changes"))?;
+
+        let mut pack = ContentPack::default();
+        for change in changes {
+            if change.get("Action").and_then(Value::as_str) != Some("EditData") {
+                continue;
+            }
+            let entries = match change.get("Entries").and_then(Value::as_object) {
+                Some(e) => e,
+                None => continue,
+            };
+            let targets = change.get("Target").and_then(Value::as_str).unwrap_or("");
+
+            for target in targets.split(',').map(str::trim) {
+                for (name, entry) in entries {
+                    match (target, entry) {
+                        ("Data/Characters", Value::Object(_)) => read_characters_entry(
+                            pack.characters.entry(name.clone()).or_default(),
+                            entry,
+                        ),
+                        ("Data/NPCDispositions", Value::String(s)) => read_disposition_entry(
+                            pack.characters.entry(name.clone()).or_default(),
+                            s,
+                        ),
+                        ("Data/NPCGiftTastes", Value::String(s)) => {
+                            pack.gift_tastes
+                                .entry(name.clone())
+                                .or_default()
+                                .extend(read_gift_taste_entry(s));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Ok(pack)
+    }
+
+    async fn read_source(
+        pool: &Pool,
+        character_name: &str,
+    ) -> Result<Option<(String, String)>, Box<dyn Error>> {
+        Ok(checked_query!(
+            "SELECT name, source FROM characters WHERE name = $1",
+            character_name
+        )
+        .fetch_optional(pool)
+        .await?
+        .map(|row| (row.get::<String, _>("name"), row.get::<String, _>("source"))))
+    }
+
+    pub async fn handle_import_mod_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("mod-usage"));
+            return Ok(());
+        }
+
+        let path = arguments.join(" ");
+        let folder = Path::new(&path);
+        let parsed = read_manifest(folder).and_then(|manifest| {
+            let content = read_json(&folder.join("content.json"))?;
+            Ok((manifest, parse_content_pack(&content)?))
+        });
+        let (manifest, pack) = match parsed {
+            Ok(p) => p,
+            Err(e) => {
+                print_aesthetic_message(vec![tr!("mod-import-error", path = path.as_str()), e]);
+                return Ok(());
+            }
+        };
+
+        let mut added = vec![];
+        let mut conflicts = vec![];
+        let mut incomplete = vec![];
+        let mut names: Vec<&String> = pack.characters.keys().collect();
+        names.sort();
+        for name in names {
+            let character = &pack.characters[name];
+            match read_source(pool, name).await? {
+                Some((existing, source)) if source != manifest.unique_id => {
+                    conflicts.push(tr!("mod-added-by", name = existing, source = source));
+                    continue;
+                }
+                _ => {}
+            }
+            let (season, day) = match character.birthday {
+                Some(b) => b,
+                None => {
+                    incomplete.push(name.clone());
+                    continue;
+                }
+            };
+            let best_gift = pack
+                .gift_tastes
+                .get(name)
+                .and_then(|tastes| tastes.iter().find(|(t, _)| *t == GiftTaste::Love))
+                .map(|(_, item)| item.clone())
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            let upsert = match pool.backend() {
+                Backend::MySql => "INSERT INTO characters (name, birthday_season, birthday_day, is_bachelor, best_gift, source) VALUES (?, ?, ?, ?, ?, ?) ON DUPLICATE KEY UPDATE birthday_season = VALUES(birthday_season), birthday_day = VALUES(birthday_day), is_bachelor = VALUES(is_bachelor), best_gift = VALUES(best_gift)",
+                Backend::Postgres => "INSERT INTO characters (name, birthday_season, birthday_day, is_bachelor, best_gift, source) VALUES (?, ?, ?, ?, ?, ?) ON CONFLICT (name) DO UPDATE SET birthday_season = EXCLUDED.birthday_season, birthday_day = EXCLUDED.birthday_day, is_bachelor = EXCLUDED.is_bachelor, best_gift = EXCLUDED.best_gift",
+            };
+            query(upsert)
+                .bind(name)
+                .bind(season.as_ref())
+                .bind(day)
+                .bind(character.is_bachelor)
+                .bind(&best_gift)
+                .bind(&manifest.unique_id)
+                .execute(pool)
+                .await?;
+            added.push(name.clone());
+        }
+
+        // mods may also teach vanilla villagers new tastes, but never overwrite the ones someone else set
+        let mut taste_count = 0;
+        let mut unknown_characters = vec![];
+        for (name, tastes) in &pack.gift_tastes {
+            let character_name = match read_source(pool, name).await? {
+                Some((existing, _)) => existing,
+                None => {
+                    unknown_characters.push(name.clone());
+                    continue;
+                }
+            };
+            for (taste, item) in tastes {
+                let upsert = match pool.backend() {
+                    Backend::MySql => "INSERT INTO gift_tastes (character_name, item, taste, source) VALUES (?, ?, ?, ?) ON DUPLICATE KEY UPDATE taste = IF(source = VALUES(source), VALUES(taste), taste)",
+                    Backend::Postgres => "INSERT INTO gift_tastes (character_name, item, taste, source) VALUES (?, ?, ?, ?) ON CONFLICT (character_name, item) DO UPDATE SET taste = EXCLUDED.taste WHERE gift_tastes.source = EXCLUDED.source",
+                };
+                let result = query(upsert)
+                    .bind(&character_name)
+                    .bind(item)
+                    .bind(taste.as_ref())
+                    .bind(&manifest.unique_id)
+                    .execute(pool)
+                    .await?;
+                if result.rows_affected() > 0 {
+                    taste_count += 1;
+                }
+            }
+        }
+
+        let mut messages = vec![
+            tr!(
+                "mod-imported",
+                name = manifest.name.as_str(),
+                id = manifest.unique_id.as_str()
+            ),
+            tr!(
+                "mod-characters-added",
+                names = if added.is_empty() {
+                    tr!("mod-none")
+                } else {
+                    added.join(", ")
+                }
+            ),
+            tr!("mod-tastes-added", count = taste_count),
+        ];
+        if !conflicts.is_empty() {
+            messages.push(tr!("mod-conflicts", names = conflicts.join(", ")));
+        }
+        if !incomplete.is_empty() {
+            messages.push(tr!("mod-incomplete", names = incomplete.join(", ")));
+        }
+        if !unknown_characters.is_empty() {
+            unknown_characters.sort();
+            messages.push(tr!(
+                "mod-unknown-characters",
+                names = unknown_characters.join(", ")
+            ));
+        }
+        messages.push(tr!("mod-item-ids"));
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    pub async fn handle_uninstall_mod_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let source = match arguments.first() {
+            Some(s) if !s.eq_ignore_ascii_case(VANILLA_SOURCE) => s,
+            Some(_) => {
+                println!("{}", tr!("uninstall-vanilla"));
+                return Ok(());
+            }
+            None => {
+                println!("{}", tr!("uninstall-usage"));
+                return Ok(());
+            }
+        };
+
+        // everything else about a mod's characters (friendships, schedules, ...) is deleted along with them
+        let tastes = checked_query!("DELETE FROM gift_tastes WHERE source = $1", source)
+            .execute(pool)
+            .await?
+            .rows_affected();
+        let characters = checked_query!("DELETE FROM characters WHERE source = $1", source)
+            .execute(pool)
+            .await?
+            .rows_affected();
+
+        if tastes == 0 && characters == 0 {
+            print_aesthetic_message(vec![tr!("uninstall-nothing", id = *source)]);
+        } else {
+            print_aesthetic_message(vec![
+                tr!("uninstall-done", id = *source),
+                tr!("uninstall-characters", count = characters),
+                tr!("uninstall-tastes", count = tastes),
+            ]);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::path::PathBuf;
+
+        fn claire_mod() -> PathBuf {
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/ClaireMod")
+        }
+
+        #[test]
+        fn reads_the_manifest_of_a_content_pack() {
+            let manifest = read_manifest(&claire_mod()).unwrap();
+            assert_eq!(manifest.unique_id, "Example.ClaireNPC");
+            assert_eq!(manifest.name, "Claire");
+        }
+
+        #[test]
+        fn reads_villagers_and_gift_tastes_from_content_json() {
+            let content = read_json(&claire_mod().join("content.json")).unwrap();
+            let pack = parse_content_pack(&content).unwrap();
+
+            let claire = &pack.characters["Claire"];
+            assert_eq!(claire.birthday, Some((Season::Summer, 5)));
+            assert!(claire.is_bachelor);
+            assert_eq!(pack.characters.len(), 1);
+
+            assert_eq!(
+                pack.gift_tastes["Claire"],
+                [
+                    (GiftTaste::Love, "72".to_string()),
+                    (GiftTaste::Love, "395".to_string()),
+                    (GiftTaste::Love, "221".to_string()),
+                    (GiftTaste::Like, "18".to_string()),
+                    (GiftTaste::Like, "402".to_string()),
+                    (GiftTaste::Dislike, "330".to_string()),
+                    (GiftTaste::Hate, "766".to_string()),
+                    (GiftTaste::Hate, "92".to_string()),
+                ]
+            );
+            assert_eq!(
+                pack.gift_tastes["Abigail"],
+                [(GiftTaste::Love, "226".to_string())]
+            );
+        }
+
+        #[test]
+        fn reads_the_old_dispositions_format() {
+            let content = json5::from_str::<Value>(
+                r#"{
+                    "Changes": [{
+                        "Action": "EditData",
+                        "Target": "Data/NPCDispositions, Data/Characters",
+                        "Entries": { "Claire": "adult/polite/neutral/positive/female/datable/null/Town/fall 21//null/Claire" }
+                    }]
+                }"#,
+            )
+            .unwrap();
+            let pack = parse_content_pack(&content).unwrap();
+            let claire = &pack.characters["Claire"];
+            assert_eq!(claire.birthday, Some((Season::Fall, 21)));
+            assert!(claire.is_bachelor);
+        }
+
+        #[test]
+        fn rejects_what_is_not_a_content_pack() {
+            let missing = claire_mod().join("missing.json");
+            assert!(read_json(&missing)
+                .unwrap_err()
+                .contains(&missing.display().to_string()));
+            assert_eq!(
+                parse_content_pack(&Value::Object(Default::default())).err(),
+                Some(tr!("mod-missing-changes"))
+            );
+        }
+
+        #[test]
+        fn birthdays_need_a_season_and_a_day_of_it() {
+            assert_eq!(parse_birthday("summer 10"), Some((Season::Summer, 10)));
+            assert_eq!(parse_birthday(" Winter  28 "), Some((Season::Winter, 28)));
+            assert_eq!(parse_birthday("summer 29"), None);
+            assert_eq!(parse_birthday("summer"), None);
+            assert_eq!(parse_birthday("monsoon 3"), None);
+        }
+    }
+}
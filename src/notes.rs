@@ -0,0 +1,186 @@
+pub mod note_commands {
+    use crate::localization::localization_commands::tr;
+    use crate::lookup::lookup_commands::{resolve_character, split_character_name};
+    use crate::print_aesthetic_message;
+    use crate::storage::backend::{checked_query, Pool};
+    use std::error::Error;
+
+    const MAX_NOTE_LENGTH: usize = 500;
+    const MAX_TAG_LENGTH: usize = 50;
+
+    // tags are single lowercase words, so "read tag=<tag>" can find them again
+    pub(crate) fn normalize_tag(tag: &str) -> Option<String> {
+        let tag = tag.trim_start_matches('#').to_lowercase();
+        (!tag.is_empty() && tag.chars().count() <= MAX_TAG_LENGTH).then_some(tag)
+    }
+
+    pub async fn handle_note_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("note-usage"));
+            println!("{}", tr!("note-example"));
+            return Ok(());
+        }
+
+        let (name, text) = split_character_name(pool, &arguments).await?;
+        let character_name = match resolve_character(pool, &name).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        if text.is_empty() {
+            let notes: Vec<String> = checked_query!(
+                "SELECT text FROM notes WHERE character_name = $1 ORDER BY id",
+                &character_name
+            )
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|row| row.get::<String, _>("text"))
+            .collect();
+            let mut messages = vec![tr!("note-list", name = character_name.as_str())];
+            if notes.is_empty() {
+                messages.push(tr!("note-none"));
+            }
+            messages.extend(notes.into_iter().map(|note| format!("📝 {}", note)));
+            print_aesthetic_message(messages);
+            return Ok(());
+        }
+
+        let text = text.join(" ");
+        if text.chars().count() > MAX_NOTE_LENGTH {
+            println!("{}", tr!("note-too-long", length = MAX_NOTE_LENGTH));
+            return Ok(());
+        }
+
+        checked_query!(
+            "INSERT INTO notes (character_name, text) VALUES ($1, $2)",
+            &character_name,
+            &text
+        )
+        .execute(pool)
+        .await?;
+        print_aesthetic_message(vec![tr!("note-added", name = character_name.as_str())]);
+
+        Ok(())
+    }
+
+    pub async fn handle_tag_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 2 {
+            println!("{}", tr!("tag-usage"));
+            println!("{}", tr!("tag-example"));
+            return Ok(());
+        }
+
+        let (name, tags) = split_character_name(pool, &arguments).await?;
+        if tags.is_empty() {
+            println!("{}", tr!("tag-usage"));
+            println!("{}", tr!("tag-example"));
+            return Ok(());
+        }
+        let character_name = match resolve_character(pool, &name).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let mut added = vec![];
+        for tag in tags.iter().filter_map(|t| normalize_tag(t)) {
+            let result = checked_query!(
+                "INSERT INTO tags (character_name, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+                &character_name,
+                &tag
+            )
+            .execute(pool)
+            .await?;
+            // nothing is inserted when the character has the tag already
+            if result.rows_affected() > 0 {
+                added.push(tag);
+            }
+        }
+
+        if added.is_empty() {
+            print_aesthetic_message(vec![tr!("tag-known", name = character_name.as_str())]);
+        } else {
+            print_aesthetic_message(vec![tr!(
+                "tag-added",
+                name = character_name.as_str(),
+                tags = added.join(", ")
+            )]);
+        }
+
+        Ok(())
+    }
+
+    pub async fn handle_untag_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 2 {
+            println!("{}", tr!("untag-usage"));
+            return Ok(());
+        }
+
+        let (name, tags) = split_character_name(pool, &arguments).await?;
+        if tags.is_empty() {
+            println!("{}", tr!("untag-usage"));
+            return Ok(());
+        }
+        let character_name = match resolve_character(pool, &name).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let mut removed = vec![];
+        for tag in tags.iter().filter_map(|t| normalize_tag(t)) {
+            let result = checked_query!(
+                "DELETE FROM tags WHERE character_name = $1 AND tag = $2",
+                &character_name,
+                &tag
+            )
+            .execute(pool)
+            .await?;
+            if result.rows_affected() > 0 {
+                removed.push(tag);
+            }
+        }
+
+        if removed.is_empty() {
+            print_aesthetic_message(vec![tr!("untag-none", name = character_name.as_str())]);
+        } else {
+            print_aesthetic_message(vec![tr!(
+                "untag-removed",
+                name = character_name.as_str(),
+                tags = removed.join(", ")
+            )]);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn tags_lose_their_hash_and_capitals() {
+            assert_eq!(normalize_tag("Gems").as_deref(), Some("gems"));
+            assert_eq!(normalize_tag("#Gems").as_deref(), Some("gems"));
+            assert_eq!(normalize_tag("##ÉTÉ").as_deref(), Some("été"));
+        }
+
+        #[test]
+        fn empty_and_overlong_tags_are_refused() {
+            assert_eq!(normalize_tag(""), None);
+            assert_eq!(normalize_tag("#"), None);
+            assert!(normalize_tag(&"a".repeat(MAX_TAG_LENGTH)).is_some());
+            assert_eq!(normalize_tag(&"a".repeat(MAX_TAG_LENGTH + 1)), None);
+            // characters, not bytes
+            assert!(normalize_tag(&"é".repeat(MAX_TAG_LENGTH)).is_some());
+        }
+    }
+}
@@ -0,0 +1,265 @@
+pub mod profile_commands {
+    use crate::cache::cache_commands::CharacterCache;
+    use crate::config::settings::Config;
+    use crate::localization::localization_commands::{load_translations, tr};
+    use crate::storage::backend::{checked_query, query, Backend, Pool};
+    use crate::{
+        connect_as_migrator, connect_to_database, prepare_database, print_aesthetic_message,
+        schema_change_error,
+    };
+    use std::{error::Error, io};
+
+    pub const DEFAULT_PROFILE: &str = "default";
+
+    // the connection to the default database, which keeps track of all profiles,
+    // and the connection to the database of the profile that is in use right now
+    pub struct Session {
+        pub main_pool: Pool,
+        pub pool: Pool,
+        pub profile: String,
+        pub config: Config,
+        pub cache: CharacterCache,
+    }
+
+    impl Session {
+        pub async fn start(
+            main_pool: Pool,
+            config: Config,
+            schema_ready: bool,
+        ) -> Result<Session, Box<dyn Error>> {
+            // before 'migrate up' there may not even be a profiles table, so the default profile is used
+            let active_profile = if schema_ready {
+                checked_query!("SELECT name FROM profiles WHERE is_active = TRUE")
+                    .fetch_optional(&main_pool)
+                    .await?
+                    .map(|row| row.get::<String, _>("name"))
+            } else {
+                None
+            };
+
+            let (pool, profile) = match active_profile {
+                Some(name) => (open_profile_database(&config, &name).await?, name),
+                None => (main_pool.clone(), DEFAULT_PROFILE.to_string()),
+            };
+
+            Ok(Session {
+                main_pool,
+                pool,
+                profile,
+                cache: CharacterCache::new(config.cache_ttl()?),
+                config,
+            })
+        }
+
+        // Postgres refuses statements that were prepared before a migration changed the tables they
+        // read, so the connections to the profile's database are replaced after one
+        pub async fn reconnect_after_migration(&mut self) -> Result<(), Box<dyn Error>> {
+            let database = match self.profile.as_str() {
+                DEFAULT_PROFILE => self.config.database_name()?,
+                profile => profile_database_name(&self.config, profile)?,
+            };
+            let pool = connect_to_database(&self.config, &database).await?;
+            self.pool.close().await;
+            if self.profile == DEFAULT_PROFILE {
+                self.main_pool = pool.clone();
+            }
+            self.pool = pool;
+            Ok(())
+        }
+    }
+
+    // every profile gets its own database, named after the default one: test_db -> test_db_modded
+    fn profile_database_name(config: &Config, profile: &str) -> Result<String, Box<dyn Error>> {
+        Ok(format!("{}_{}", config.database_name()?, profile))
+    }
+
+    fn is_valid_profile_name(profile: &str) -> bool {
+        !profile.is_empty()
+            && profile.len() <= 32
+            && profile
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+
+    async fn open_profile_database(config: &Config, profile: &str) -> Result<Pool, Box<dyn Error>> {
+        let pool = connect_to_database(config, &profile_database_name(config, profile)?).await?;
+        prepare_database(&pool, config).await?;
+        Ok(pool)
+    }
+
+    async fn profile_exists(main_pool: &Pool, profile: &str) -> Result<bool, Box<dyn Error>> {
+        Ok(
+            checked_query!("SELECT name FROM profiles WHERE name = $1", profile)
+                .fetch_optional(main_pool)
+                .await?
+                .is_some(),
+        )
+    }
+
+    pub async fn handle_profile_command(
+        session: &mut Session,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let profile = arguments.get(1).map(|p| p.to_lowercase());
+
+        match (arguments.first().copied(), profile) {
+            (Some("list"), _) | (None, _) => list_profiles(session).await,
+            (Some("create"), Some(profile)) => create_profile(session, &profile).await,
+            (Some("use"), Some(profile)) => use_profile(session, &profile).await,
+            (Some("delete"), Some(profile)) => delete_profile(session, &profile).await,
+            _ => {
+                println!("{}", tr!("profile-usage"));
+                Ok(())
+            }
+        }
+    }
+
+    async fn list_profiles(session: &Session) -> Result<(), Box<dyn Error>> {
+        let rows = checked_query!("SELECT name FROM profiles ORDER BY name")
+            .fetch_all(&session.main_pool)
+            .await?;
+
+        let mut profiles = vec![DEFAULT_PROFILE.to_string()];
+        profiles.extend(rows.into_iter().map(|row| row.get::<String, _>("name")));
+
+        let mut messages = vec![tr!("profile-list")];
+        for profile in profiles {
+            messages.push(format!(
+                "{} {}",
+                if profile == session.profile {
+                    "➡"
+                } else {
+                    " "
+                },
+                profile
+            ));
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    async fn create_profile(session: &mut Session, profile: &str) -> Result<(), Box<dyn Error>> {
+        if !is_valid_profile_name(profile) || profile == DEFAULT_PROFILE {
+            println!("{}", tr!("profile-invalid-name", default = DEFAULT_PROFILE));
+            return Ok(());
+        }
+        if profile_exists(&session.main_pool, profile).await? {
+            print_aesthetic_message(vec![tr!("profile-exists", name = profile)]);
+            return Ok(());
+        }
+
+        let database = profile_database_name(&session.config, profile)?;
+        // the app user may not create databases, the migrator does
+        let migrator_pool = connect_as_migrator(&session.config, &session.main_pool).await?;
+        let created = create_database(
+            migrator_pool.as_ref().unwrap_or(&session.main_pool),
+            &database,
+        )
+        .await;
+        if let Some(migrator_pool) = migrator_pool {
+            migrator_pool.close().await;
+        }
+        created.map_err(|e| schema_change_error(&session.config, e.into()))?;
+        checked_query!(
+            "INSERT INTO profiles (name, is_active) VALUES ($1, FALSE)",
+            profile
+        )
+        .execute(&session.main_pool)
+        .await?;
+
+        use_profile(session, profile).await
+    }
+
+    async fn create_database(pool: &Pool, database: &str) -> Result<(), sqlx::Error> {
+        match pool.backend() {
+            Backend::MySql => {
+                query(&format!("CREATE DATABASE IF NOT EXISTS `{}`", database))
+                    .execute(pool)
+                    .await?;
+            }
+            // Postgres has no CREATE DATABASE IF NOT EXISTS
+            Backend::Postgres => {
+                let exists = query("SELECT datname FROM pg_database WHERE datname = ?")
+                    .bind(database)
+                    .fetch_optional(pool)
+                    .await?
+                    .is_some();
+                if !exists {
+                    query(&format!("CREATE DATABASE \"{}\"", database))
+                        .execute(pool)
+                        .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn use_profile(session: &mut Session, profile: &str) -> Result<(), Box<dyn Error>> {
+        if profile == DEFAULT_PROFILE {
+            session.pool = session.main_pool.clone();
+        } else if profile_exists(&session.main_pool, profile).await? {
+            session.pool = open_profile_database(&session.config, profile).await?;
+        } else {
+            print_aesthetic_message(vec![tr!("profile-not-found-create", name = profile)]);
+            return Ok(());
+        }
+
+        checked_query!("UPDATE profiles SET is_active = (name = $1)", profile)
+            .execute(&session.main_pool)
+            .await?;
+        session.profile = profile.to_string();
+        load_translations(&session.pool).await?;
+
+        print_aesthetic_message(vec![tr!("profile-switched", name = profile)]);
+
+        Ok(())
+    }
+
+    async fn delete_profile(session: &mut Session, profile: &str) -> Result<(), Box<dyn Error>> {
+        if profile == DEFAULT_PROFILE {
+            println!("{}", tr!("profile-delete-default"));
+            return Ok(());
+        }
+        if profile == session.profile {
+            println!("{}", tr!("profile-delete-active"));
+            return Ok(());
+        }
+        if !profile_exists(&session.main_pool, profile).await? {
+            print_aesthetic_message(vec![tr!("profile-not-found", name = profile)]);
+            return Ok(());
+        }
+
+        println!("{}", tr!("profile-delete-confirm", name = profile));
+        let mut confirmation = String::new();
+        io::stdin()
+            .read_line(&mut confirmation)
+            .expect("Failed to read input!");
+        if confirmation.trim() != profile {
+            println!("{}", tr!("delete-cancelled"));
+            return Ok(());
+        }
+
+        let database = profile_database_name(&session.config, profile)?;
+        let drop_database = match session.main_pool.backend() {
+            Backend::MySql => format!("DROP DATABASE IF EXISTS `{}`", database),
+            // the profile's pool may still hold idle connections, which would keep Postgres from dropping it
+            Backend::Postgres => format!("DROP DATABASE IF EXISTS \"{}\" WITH (FORCE)", database),
+        };
+        let migrator_pool = connect_as_migrator(&session.config, &session.main_pool).await?;
+        let dropped = query(&drop_database)
+            .execute(migrator_pool.as_ref().unwrap_or(&session.main_pool))
+            .await;
+        if let Some(migrator_pool) = migrator_pool {
+            migrator_pool.close().await;
+        }
+        dropped.map_err(|e| schema_change_error(&session.config, e.into()))?;
+        checked_query!("DELETE FROM profiles WHERE name = $1", profile)
+            .execute(&session.main_pool)
+            .await?;
+
+        print_aesthetic_message(vec![tr!("profile-deleted", name = profile)]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,430 @@
+pub mod relationship_commands {
+    use crate::health::health_commands::is_connection_error;
+    use crate::localization::localization_commands::{matches_text, tr, value_name};
+    use crate::lookup::lookup_commands::resolve_character;
+    use crate::storage::backend::{checked_query, checked_query_as, Pool};
+    use crate::{print_aesthetic_message, Character, CharacterRecord};
+    use std::{error::Error, fs, str::FromStr};
+    use strum_macros::{AsRefStr, EnumString};
+
+    #[derive(Debug, Clone, Copy, PartialEq, AsRefStr, EnumString)]
+    pub(crate) enum RelationshipKind {
+        #[strum(ascii_case_insensitive)]
+        Parent,
+        #[strum(ascii_case_insensitive)]
+        Sibling,
+        #[strum(ascii_case_insensitive)]
+        Spouse,
+        #[strum(ascii_case_insensitive)]
+        Friend,
+        #[strum(ascii_case_insensitive)]
+        Rival,
+    }
+
+    const RELATIONSHIP_KINDS: [RelationshipKind; 5] = [
+        RelationshipKind::Parent,
+        RelationshipKind::Sibling,
+        RelationshipKind::Spouse,
+        RelationshipKind::Friend,
+        RelationshipKind::Rival,
+    ];
+
+    impl RelationshipKind {
+        // 'parent' or the word for it in any of the catalogs, like 'Elternteil'
+        fn parse(input: &str) -> Option<RelationshipKind> {
+            RelationshipKind::from_str(input).ok().or_else(|| {
+                RELATIONSHIP_KINDS.into_iter().find(|kind| {
+                    matches_text(
+                        input,
+                        &format!("relationship-{}", kind.as_ref().to_lowercase()),
+                    )
+                })
+            })
+        }
+
+        // a parent relationship has a direction, all the others work both ways
+        fn is_mutual(&self) -> bool {
+            !matches!(self, RelationshipKind::Parent)
+        }
+
+        fn is_family(&self) -> bool {
+            matches!(
+                self,
+                RelationshipKind::Parent | RelationshipKind::Sibling | RelationshipKind::Spouse
+            )
+        }
+
+        fn dot_style(&self) -> &'static str {
+            match self {
+                RelationshipKind::Parent => "color=\"#6b4f2a\", penwidth=2",
+                RelationshipKind::Sibling => "color=\"#6b4f2a\", style=dashed, dir=none",
+                RelationshipKind::Spouse => "color=\"#d6336c\", penwidth=2, dir=none",
+                RelationshipKind::Friend => "color=\"#2f9e44\", dir=none",
+                RelationshipKind::Rival => "color=\"#e03131\", style=dotted, dir=none",
+            }
+        }
+    }
+
+    struct Relationship {
+        from_character: String,
+        to_character: String,
+        kind: RelationshipKind,
+    }
+
+    impl Relationship {
+        fn is_the_same_as(&self, other: &Relationship) -> bool {
+            let same_way = self
+                .from_character
+                .eq_ignore_ascii_case(&other.from_character)
+                && self.to_character.eq_ignore_ascii_case(&other.to_character);
+            let other_way = self.kind.is_mutual()
+                && self
+                    .from_character
+                    .eq_ignore_ascii_case(&other.to_character)
+                && self
+                    .to_character
+                    .eq_ignore_ascii_case(&other.from_character);
+            self.kind == other.kind && (same_way || other_way)
+        }
+    }
+
+    async fn read_relationships(pool: &Pool) -> Result<Vec<Relationship>, Box<dyn Error>> {
+        let rows = checked_query!("SELECT from_character, to_character, kind FROM relationships ORDER BY from_character, to_character")
+            .fetch_all(pool)
+            .await?;
+
+        let mut relationships: Vec<Relationship> = vec![];
+        for row in rows {
+            let relationship = match RelationshipKind::from_str(&row.get::<String, _>("kind")) {
+                Ok(kind) => Relationship {
+                    from_character: row.get::<String, _>("from_character"),
+                    to_character: row.get::<String, _>("to_character"),
+                    kind,
+                },
+                Err(_) => continue,
+            };
+            // older versions could store a mutual relationship both ways
+            if !relationships
+                .iter()
+                .any(|r| r.is_the_same_as(&relationship))
+            {
+                relationships.push(relationship);
+            }
+        }
+        Ok(relationships)
+    }
+
+    // false if the two already had this relationship, stored either way round for a mutual one
+    pub(crate) async fn add_relationship(
+        pool: &Pool,
+        from_character: &str,
+        kind: RelationshipKind,
+        to_character: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let known = checked_query!(
+            "SELECT kind FROM relationships WHERE kind = $1 AND ((from_character = $2 AND to_character = $3) OR ($4 AND from_character = $3 AND to_character = $2))",
+            kind.as_ref(),
+            from_character,
+            to_character,
+            kind.is_mutual()
+        )
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+        if known {
+            return Ok(false);
+        }
+
+        // mutual relationships are stored in alphabetical order, like the ones added before this check
+        let (first, second) =
+            if kind.is_mutual() && from_character.to_lowercase() > to_character.to_lowercase() {
+                (to_character, from_character)
+            } else {
+                (from_character, to_character)
+            };
+        let result = checked_query!("INSERT INTO relationships (from_character, to_character, kind) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING", first, second, kind.as_ref())
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn handle_relate_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 3 {
+            println!("{}", tr!("relate-usage"));
+            println!("{}", tr!("relate-example"));
+            return Ok(());
+        }
+
+        // the kind of relationship splits the two names, so both can have more than one word
+        let kind = arguments
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find_map(|(i, a)| RelationshipKind::parse(a).map(|kind| (i, kind)));
+        let (kind_index, kind) = match kind {
+            Some((i, kind)) if i + 1 < arguments.len() => (i, kind),
+            Some(_) => {
+                println!("{}", tr!("relate-usage"));
+                println!("{}", tr!("relate-example"));
+                return Ok(());
+            }
+            // whatever is between the first and the last word is most likely the misspelt kind
+            None => {
+                let input = arguments[1..arguments.len() - 1].join(" ");
+                println!("{}", tr!("relate-invalid-kind", input = input));
+                return Ok(());
+            }
+        };
+
+        let from_character =
+            match resolve_character(pool, &arguments[..kind_index].join(" ")).await? {
+                Some(c) => c,
+                None => return Ok(()),
+            };
+        let to_character =
+            match resolve_character(pool, &arguments[kind_index + 1..].join(" ")).await? {
+                Some(c) => c,
+                None => return Ok(()),
+            };
+
+        if from_character.eq_ignore_ascii_case(&to_character) {
+            println!("{}", tr!("relate-self"));
+            return Ok(());
+        }
+
+        match add_relationship(pool, &from_character, kind, &to_character).await {
+            Ok(false) => print_aesthetic_message(vec![tr!(
+                "relate-known",
+                from = from_character.as_str(),
+                to = to_character.as_str(),
+                kind = value_name("relationship", &kind)
+            )]),
+            Ok(true) => print_aesthetic_message(vec![tr!(
+                "relate-done",
+                from = from_character.as_str(),
+                to = to_character.as_str(),
+                kind = value_name("relationship", &kind)
+            )]),
+            Err(e) if is_connection_error(&e) => return Err(e.into()),
+            Err(e) => print_aesthetic_message(vec![
+                tr!(
+                    "relate-error",
+                    from = from_character.as_str(),
+                    to = to_character.as_str()
+                ),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    pub async fn handle_family_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("family-usage"));
+            return Ok(());
+        }
+
+        let character_name = match resolve_character(pool, &arguments.join(" ")).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let row = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters WHERE name = $1",
+            &character_name
+        )
+        .fetch_optional(pool)
+        .await?;
+        let character = match row {
+            Some(character) => character,
+            None => {
+                print_aesthetic_message(vec![tr!(
+                    "character-not-found",
+                    name = character_name.as_str()
+                )]);
+                return Ok(());
+            }
+        };
+
+        let mut family = vec![];
+        let mut others = vec![];
+        for relationship in read_relationships(pool).await? {
+            let is_from = relationship
+                .from_character
+                .eq_ignore_ascii_case(&character.name);
+            let is_to = relationship
+                .to_character
+                .eq_ignore_ascii_case(&character.name);
+            let line = match (is_from, is_to, relationship.kind) {
+                (true, _, RelationshipKind::Parent) => {
+                    tr!("family-child", name = relationship.to_character.as_str())
+                }
+                (_, true, RelationshipKind::Parent) => {
+                    tr!("family-parent", name = relationship.from_character.as_str())
+                }
+                (true, _, kind) if kind.is_mutual() => {
+                    describe_mutual(kind, &relationship.to_character)
+                }
+                (_, true, kind) if kind.is_mutual() => {
+                    describe_mutual(kind, &relationship.from_character)
+                }
+                _ => continue,
+            };
+            if relationship.kind.is_family() {
+                family.push(line);
+            } else {
+                others.push(line);
+            }
+        }
+
+        let mut messages = vec![tr!("family-title", name = character.name.as_str())];
+        if family.is_empty() && others.is_empty() {
+            messages.push(tr!("family-none", name = character.name.as_str()));
+        }
+        messages.extend(family);
+        messages.extend(others);
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    // "family-sibling", "family-friend", ...
+    fn describe_mutual(kind: RelationshipKind, other_character: &str) -> String {
+        tr!(
+            &format!("family-{}", kind.as_ref().to_lowercase()),
+            name = other_character
+        )
+    }
+
+    pub async fn handle_graph_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 2 || arguments[0] != "--dot" {
+            println!("{}", tr!("graph-usage"));
+            println!("{}", tr!("graph-example"));
+            return Ok(());
+        }
+        let file_name = arguments[1];
+
+        let characters: Vec<Character> = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters ORDER BY name"
+        )
+        .fetch_all(pool)
+        .await?;
+        let relationships = read_relationships(pool).await?;
+
+        let mut dot = String::from("digraph villagers {\n");
+        dot.push_str("    node [shape=box, style=\"rounded,filled\", fillcolor=\"#fdf6e3\", fontname=\"sans-serif\"];\n\n");
+        for character in &characters {
+            if character.is_bachelor {
+                dot.push_str(&format!(
+                    "    \"{}\" [label=\"❤ {}\", fillcolor=\"#ffc9de\", color=\"#d6336c\", penwidth=2];\n",
+                    escape_dot(&character.name),
+                    escape_dot(&character.name)
+                ));
+            } else {
+                dot.push_str(&format!("    \"{}\";\n", escape_dot(&character.name)));
+            }
+        }
+        dot.push('\n');
+        for relationship in &relationships {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\", {}];\n",
+                escape_dot(&relationship.from_character),
+                escape_dot(&relationship.to_character),
+                escape_dot(&value_name("relationship", &relationship.kind)),
+                relationship.kind.dot_style()
+            ));
+        }
+        dot.push_str("}\n");
+
+        match fs::write(file_name, dot) {
+            Ok(_) => print_aesthetic_message(vec![tr!(
+                "graph-exported",
+                characters = characters.len(),
+                relationships = relationships.len(),
+                file = file_name
+            )]),
+            Err(e) => print_aesthetic_message(vec![
+                tr!("graph-write-error", file = file_name),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    fn escape_dot(text: &str) -> String {
+        text.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn kinds_are_read_in_english_or_any_catalog() {
+            assert_eq!(
+                RelationshipKind::parse("Parent"),
+                Some(RelationshipKind::Parent)
+            );
+            assert_eq!(
+                RelationshipKind::parse("rival"),
+                Some(RelationshipKind::Rival)
+            );
+            assert_eq!(
+                RelationshipKind::parse("Geschwister"),
+                Some(RelationshipKind::Sibling)
+            );
+            assert_eq!(
+                RelationshipKind::parse("ami"),
+                Some(RelationshipKind::Friend)
+            );
+            assert_eq!(RelationshipKind::parse("mother"), None);
+        }
+
+        fn relationship(from: &str, kind: RelationshipKind, to: &str) -> Relationship {
+            Relationship {
+                from_character: from.to_string(),
+                to_character: to.to_string(),
+                kind,
+            }
+        }
+
+        #[test]
+        fn mutual_relationships_are_the_same_either_way_round() {
+            let friends = relationship("Caroline", RelationshipKind::Friend, "Lewis");
+            assert!(friends.is_the_same_as(&relationship(
+                "lewis",
+                RelationshipKind::Friend,
+                "Caroline"
+            )));
+            assert!(!friends.is_the_same_as(&relationship(
+                "Lewis",
+                RelationshipKind::Rival,
+                "Caroline"
+            )));
+
+            let parent = relationship("Caroline", RelationshipKind::Parent, "Abigail");
+            assert!(parent.is_the_same_as(&relationship(
+                "Caroline",
+                RelationshipKind::Parent,
+                "abigail"
+            )));
+            assert!(!parent.is_the_same_as(&relationship(
+                "Abigail",
+                RelationshipKind::Parent,
+                "Caroline"
+            )));
+        }
+    }
+}
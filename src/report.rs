@@ -0,0 +1,395 @@
+pub mod report_commands {
+    use crate::festivals::festival_commands::{conflicting_festival, read_festivals};
+    use crate::gifts::gift_commands::read_loved_gifts;
+    use crate::localization::localization_commands::{
+        current_locale, date_name, season_name, tr, value_name,
+    };
+    use crate::storage::backend::{checked_query_as, Pool};
+    use crate::{
+        print_aesthetic_message, read_notes_and_tags_of, Character, CharacterRecord, Season,
+        StardewDate, Weekday,
+    };
+    use std::{collections::HashMap, error::Error, fs};
+    use strum_macros::{AsRefStr, EnumString};
+
+    const DEFAULT_HTML_TEMPLATE: &str = include_str!("../templates/report.html");
+    const DEFAULT_MARKDOWN_TEMPLATE: &str = include_str!("../templates/report.md");
+    const SEASONS: [Season; 4] = [Season::Spring, Season::Summer, Season::Fall, Season::Winter];
+    const WEEKDAYS: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+    const TEMPLATE_PLACEHOLDERS: &str = "{{title}}, {{lang}}, {{calendar}}, {{candidates}}, {{gifts}}, {{characters}}, {{calendar_heading}}, {{candidates_heading}}, {{gifts_heading}}, {{characters_heading}}";
+
+    #[derive(PartialEq, AsRefStr, EnumString)]
+    enum ReportFormat {
+        #[strum(ascii_case_insensitive)]
+        Html,
+        #[strum(ascii_case_insensitive, serialize = "md", serialize = "markdown")]
+        Markdown,
+    }
+
+    pub async fn handle_report_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut format = None;
+        let mut template_file = None;
+        let mut out_file = None;
+
+        let mut remaining = arguments.iter();
+        while let Some(argument) = remaining.next() {
+            match *argument {
+                "--format" => match remaining.next().map(|f| f.parse::<ReportFormat>()) {
+                    Some(Ok(f)) => format = Some(f),
+                    _ => {
+                        println!("{}", tr!("report-invalid-format"));
+                        return Ok(());
+                    }
+                },
+                "--template" => template_file = remaining.next().copied(),
+                _ => out_file = Some(*argument),
+            }
+        }
+
+        let out_file = match out_file {
+            Some(f) => f,
+            None => {
+                println!("{}", tr!("report-usage"));
+                println!(
+                    "{}",
+                    tr!("report-template-hint", placeholders = TEMPLATE_PLACEHOLDERS)
+                );
+                return Ok(());
+            }
+        };
+        let format = format.unwrap_or(if out_file.ends_with(".html") {
+            ReportFormat::Html
+        } else {
+            ReportFormat::Markdown
+        });
+
+        let template = match template_file {
+            Some(file) => match fs::read_to_string(file) {
+                Ok(t) => t,
+                Err(e) => {
+                    print_aesthetic_message(vec![
+                        tr!("report-template-error", file = file),
+                        format!("{e}"),
+                    ]);
+                    return Ok(());
+                }
+            },
+            None if format == ReportFormat::Html => DEFAULT_HTML_TEMPLATE.to_string(),
+            None => DEFAULT_MARKDOWN_TEMPLATE.to_string(),
+        };
+
+        let mut characters: Vec<Character> = checked_query_as!(
+            CharacterRecord => Character,
+            "SELECT name, birthday_season, birthday_day, is_bachelor, best_gift, source FROM characters ORDER BY name"
+        )
+        .fetch_all(pool)
+        .await?;
+        read_notes_and_tags_of(pool, &mut characters).await?;
+        let loved_gifts = read_loved_gifts(pool).await?;
+
+        let festivals = read_festivals(pool).await?;
+
+        let mut calendar_entries: HashMap<(Season, u8), Vec<String>> = HashMap::new();
+        for festival in &festivals {
+            for day in festival.start_day..=festival.end_day {
+                calendar_entries
+                    .entry((festival.season, day))
+                    .or_default()
+                    .push(format!("🎪 {}", &festival.name));
+            }
+        }
+        for character in &characters {
+            let birthday = birthday_of(character);
+            let entry = if conflicting_festival(&festivals, &birthday).is_some() {
+                format!("⚠ 🎂 {}", &character.name)
+            } else {
+                format!("🎂 {}", &character.name)
+            };
+            calendar_entries
+                .entry((character.birthday_season, character.birthday_day))
+                .or_default()
+                .push(entry);
+        }
+
+        let report = template
+            .replace("{{title}}", &tr!("report-title"))
+            .replace("{{lang}}", current_locale())
+            .replace("{{calendar_heading}}", &tr!("report-heading-calendar"))
+            .replace("{{candidates_heading}}", &tr!("report-heading-candidates"))
+            .replace("{{gifts_heading}}", &tr!("report-heading-gifts"))
+            .replace("{{characters_heading}}", &tr!("report-heading-characters"))
+            .replace("{{calendar}}", &render_calendar(&format, &calendar_entries))
+            .replace("{{candidates}}", &render_candidates(&format, &characters))
+            .replace(
+                "{{gifts}}",
+                &render_gifts(&format, &characters, &loved_gifts),
+            )
+            .replace("{{characters}}", &render_characters(&format, &characters));
+
+        match fs::write(out_file, report) {
+            Ok(_) => print_aesthetic_message(vec![tr!(
+                "report-written",
+                format = format.as_ref().to_lowercase(),
+                count = characters.len(),
+                file = out_file
+            )]),
+            Err(e) => print_aesthetic_message(vec![
+                tr!("report-write-error", file = out_file),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    // one table per season: four weeks of seven days
+    fn render_calendar(
+        format: &ReportFormat,
+        entries: &HashMap<(Season, u8), Vec<String>>,
+    ) -> String {
+        let mut output = String::new();
+        for season in SEASONS {
+            match format {
+                ReportFormat::Html => {
+                    output.push_str(&format!(
+                        "<h3>{}</h3>\n<table>\n",
+                        escape_html(&season_name(&season))
+                    ));
+                    for week in 0..4u8 {
+                        output.push_str("<tr>");
+                        for weekday in 1..=7u8 {
+                            let day = week * 7 + weekday;
+                            let names = entries
+                                .get(&(season, day))
+                                .map(|names| {
+                                    names
+                                        .iter()
+                                        .map(|name| escape_html(name))
+                                        .collect::<Vec<_>>()
+                                        .join("<br>")
+                                })
+                                .unwrap_or_default();
+                            output.push_str(&format!(
+                                "<td class=\"day\"><span class=\"number\">{}</span>{}</td>",
+                                day, names
+                            ));
+                        }
+                        output.push_str("</tr>\n");
+                    }
+                    output.push_str("</table>\n");
+                }
+                ReportFormat::Markdown => {
+                    output.push_str(&format!(
+                        "### {}\n\n",
+                        escape_markdown(&season_name(&season))
+                    ));
+                    let weekdays: Vec<String> = WEEKDAYS
+                        .iter()
+                        .map(|weekday| escape_markdown(&value_name("weekday", weekday)))
+                        .collect();
+                    output.push_str(&format!("| {} |\n", weekdays.join(" | ")));
+                    output.push_str("|---|---|---|---|---|---|---|\n");
+                    for week in 0..4u8 {
+                        output.push('|');
+                        for weekday in 1..=7u8 {
+                            let day = week * 7 + weekday;
+                            let names = entries
+                                .get(&(season, day))
+                                .map(|names| {
+                                    names
+                                        .iter()
+                                        .map(|name| escape_markdown(name))
+                                        .collect::<Vec<_>>()
+                                        .join("<br>")
+                                })
+                                .unwrap_or_default();
+                            if names.is_empty() {
+                                output.push_str(&format!(" {} |", day));
+                            } else {
+                                output.push_str(&format!(" **{}** {} |", day, names));
+                            }
+                        }
+                        output.push('\n');
+                    }
+                    output.push('\n');
+                }
+            }
+        }
+        output
+    }
+
+    fn render_candidates(format: &ReportFormat, characters: &[Character]) -> String {
+        let candidates: Vec<&Character> = characters.iter().filter(|c| c.is_bachelor).collect();
+        if candidates.is_empty() {
+            return match format {
+                ReportFormat::Html => {
+                    format!("<p>{}</p>", escape_html(&tr!("report-no-candidates")))
+                }
+                ReportFormat::Markdown => escape_markdown(&tr!("report-no-candidates")),
+            };
+        }
+
+        match format {
+            ReportFormat::Html => {
+                let items: String = candidates
+                    .iter()
+                    .map(|c| {
+                        format!(
+                            "<li>{}</li>\n",
+                            escape_html(&tr!(
+                                "report-candidate",
+                                name = c.name.as_str(),
+                                date = date_name(&birthday_of(c))
+                            ))
+                        )
+                    })
+                    .collect();
+                format!("<ul>\n{}</ul>", items)
+            }
+            ReportFormat::Markdown => candidates
+                .iter()
+                .map(|c| {
+                    format!(
+                        "- {}\n",
+                        escape_markdown(&tr!(
+                            "report-candidate",
+                            name = c.name.as_str(),
+                            date = date_name(&birthday_of(c))
+                        ))
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn render_gifts(
+        format: &ReportFormat,
+        characters: &[Character],
+        loved_gifts: &HashMap<String, Vec<String>>,
+    ) -> String {
+        let gifts_of = |character: &Character| {
+            loved_gifts
+                .get(&character.name.to_lowercase())
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        match format {
+            ReportFormat::Html => {
+                let mut output = format!(
+                    "<table>\n<tr><th>{}</th><th>{}</th><th>{}</th></tr>\n",
+                    escape_html(&tr!("report-column-character")),
+                    escape_html(&tr!("report-column-best-gift")),
+                    escape_html(&tr!("report-column-loved-gifts"))
+                );
+                for character in characters {
+                    output.push_str(&format!(
+                        "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                        escape_html(&character.name),
+                        escape_html(&character.best_gift),
+                        escape_html(&gifts_of(character).join(", "))
+                    ));
+                }
+                output.push_str("</table>");
+                output
+            }
+            ReportFormat::Markdown => {
+                let mut output = format!(
+                    "| {} | {} | {} |\n|---|---|---|\n",
+                    escape_markdown(&tr!("report-column-character")),
+                    escape_markdown(&tr!("report-column-best-gift")),
+                    escape_markdown(&tr!("report-column-loved-gifts"))
+                );
+                for character in characters {
+                    output.push_str(&format!(
+                        "| {} | {} | {} |\n",
+                        escape_markdown(&character.name),
+                        escape_markdown(&character.best_gift),
+                        escape_markdown(&gifts_of(character).join(", "))
+                    ));
+                }
+                output
+            }
+        }
+    }
+
+    // the same lines 'read' prints in the terminal, grouped by birthday season
+    fn render_characters(format: &ReportFormat, characters: &[Character]) -> String {
+        let mut output = String::new();
+        for season in SEASONS {
+            let mut in_season: Vec<&Character> = characters
+                .iter()
+                .filter(|c| c.birthday_season == season)
+                .collect();
+            if in_season.is_empty() {
+                continue;
+            }
+            in_season.sort_by_key(|c| c.birthday_day);
+
+            match format {
+                ReportFormat::Html => {
+                    output.push_str(&format!(
+                        "<h3>{}</h3>\n",
+                        escape_html(&season_name(&season))
+                    ));
+                    for character in in_season {
+                        output.push_str(&format!(
+                            "<h4>{}</h4>\n<ul>\n",
+                            escape_html(&character.name)
+                        ));
+                        for line in character.info_lines() {
+                            output.push_str(&format!("<li>{}</li>\n", escape_html(&line)));
+                        }
+                        output.push_str("</ul>\n");
+                    }
+                }
+                ReportFormat::Markdown => {
+                    output.push_str(&format!(
+                        "### {}\n\n",
+                        escape_markdown(&season_name(&season))
+                    ));
+                    for character in in_season {
+                        output.push_str(&format!("#### {}\n\n", escape_markdown(&character.name)));
+                        for line in character.info_lines() {
+                            output.push_str(&format!("- {}\n", escape_markdown(&line)));
+                        }
+                        output.push('\n');
+                    }
+                }
+            }
+        }
+        output
+    }
+
+    fn birthday_of(character: &Character) -> StardewDate {
+        StardewDate {
+            season: character.birthday_season,
+            day: character.birthday_day,
+        }
+    }
+
+    fn escape_html(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;")
+    }
+
+    fn escape_markdown(text: &str) -> String {
+        text.replace('|', "\\|")
+            .replace('*', "\\*")
+            .replace('_', "\\_")
+    }
+}
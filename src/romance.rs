@@ -0,0 +1,242 @@
+pub mod romance_commands {
+    use crate::friendships::friendship_commands::{
+        friendship_line, hearts, read_active_save, read_character, read_friendship,
+        require_active_save, FriendshipStatus, Save, POINTS_PER_HEART,
+    };
+    use crate::localization::localization_commands::{tr, value_name};
+    use crate::storage::backend::{checked_query, Pool};
+    use crate::{print_aesthetic_message, Character};
+    use std::error::Error;
+
+    const DATING_HEARTS: i32 = 8;
+
+    struct HeartEvent {
+        id: i32,
+        hearts: i32,
+        location: String,
+        time_window: String,
+        conditions: String,
+        seen: bool,
+    }
+
+    async fn read_heart_events(
+        pool: &Pool,
+        character_name: &str,
+        save: Option<&Save>,
+    ) -> Result<Vec<HeartEvent>, Box<dyn Error>> {
+        let rows = checked_query!("SELECT e.id, e.hearts, e.location, e.time_window, e.conditions, s.event_id IS NOT NULL AS seen FROM heart_events e LEFT JOIN seen_heart_events s ON s.event_id = e.id AND s.save_id = $1 WHERE e.character_name = $2 ORDER BY e.hearts", save.map(|s| s.id), character_name)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| HeartEvent {
+                id: row.get::<i32, _>("id"),
+                hearts: row.get::<i32, _>("hearts"),
+                location: row.get::<String, _>("location"),
+                time_window: row.get::<String, _>("time_window"),
+                conditions: row.get::<String, _>("conditions"),
+                seen: row.get::<i64, _>("seen") != 0,
+            })
+            .collect())
+    }
+
+    async fn read_romantic_character(
+        pool: &Pool,
+        character_name: &str,
+    ) -> Result<Option<Character>, Box<dyn Error>> {
+        let character = match read_character(pool, character_name).await? {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+        if !character.is_bachelor {
+            print_aesthetic_message(vec![tr!(
+                "character-cannot-marry",
+                name = character.name.as_str()
+            )]);
+            return Ok(None);
+        }
+        Ok(Some(character))
+    }
+
+    fn describe_event(event: &HeartEvent) -> String {
+        tr!(
+            "heart-event",
+            hearts = event.hearts,
+            location = event.location.as_str(),
+            time = event.time_window.as_str(),
+            conditions = event.conditions.as_str()
+        )
+    }
+
+    pub async fn handle_romance_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("romance-usage"));
+            println!("{}", tr!("romance-seen-hint"));
+            return Ok(());
+        }
+
+        let character = match read_romantic_character(pool, arguments[0]).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        if arguments.get(1) == Some(&"seen") {
+            return mark_event_as_seen(pool, &character, &arguments[2..]).await;
+        }
+
+        let save = read_active_save(pool).await?;
+        let details = checked_query!(
+            "SELECT proposal_requirements, spouse_room FROM romance WHERE character_name = $1",
+            &character.name
+        )
+        .fetch_optional(pool)
+        .await?;
+        let events = read_heart_events(pool, &character.name, save.as_ref()).await?;
+
+        let mut messages = vec![tr!("romance-title", name = character.name.as_str())];
+        if let Some(save) = &save {
+            let (points, status, _) = read_friendship(pool, save, &character.name).await?;
+            messages.push(tr!(
+                "romance-in-save",
+                save = save.name.as_str(),
+                hearts = hearts(points),
+                points = points,
+                status = value_name("status", &status)
+            ));
+        }
+        match details {
+            Some(row) => {
+                messages.push(tr!(
+                    "romance-proposal",
+                    requirements = row.get::<String, _>("proposal_requirements")
+                ));
+                messages.push(tr!(
+                    "romance-spouse-room",
+                    room = row.get::<String, _>("spouse_room")
+                ));
+            }
+            None => messages.push(tr!(
+                "romance-details-unknown",
+                name = character.name.as_str()
+            )),
+        }
+
+        if events.is_empty() {
+            messages.push(tr!("romance-no-events"));
+        } else {
+            messages.push(tr!("romance-events"));
+            for event in &events {
+                messages.push(format!(
+                    "{} {}",
+                    if event.seen { "✔" } else { "•" },
+                    describe_event(event)
+                ));
+            }
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    async fn mark_event_as_seen(
+        pool: &Pool,
+        character: &Character,
+        arguments: &[&str],
+    ) -> Result<(), Box<dyn Error>> {
+        let save = match require_active_save(pool).await? {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        let heart_level = match arguments.first().map(|h| h.parse::<i32>()) {
+            Some(Ok(h)) => h,
+            _ => {
+                println!("{}", tr!("romance-seen-usage"));
+                return Ok(());
+            }
+        };
+
+        let events = read_heart_events(pool, &character.name, Some(&save)).await?;
+        let event = match events.iter().find(|e| e.hearts == heart_level) {
+            Some(e) => e,
+            None => {
+                print_aesthetic_message(vec![tr!(
+                    "romance-unknown-event",
+                    hearts = heart_level,
+                    name = character.name.as_str()
+                )]);
+                return Ok(());
+            }
+        };
+
+        checked_query!("INSERT INTO seen_heart_events (save_id, event_id) VALUES ($1, $2) ON CONFLICT DO NOTHING", save.id, event.id)
+            .execute(pool)
+            .await?;
+        print_aesthetic_message(vec![tr!(
+            "romance-marked-seen",
+            name = character.name.as_str(),
+            hearts = heart_level,
+            save = save.name.as_str()
+        )]);
+
+        Ok(())
+    }
+
+    pub async fn handle_next_event_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("next-event-usage"));
+            return Ok(());
+        }
+
+        let save = match require_active_save(pool).await? {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        let character = match read_romantic_character(pool, &arguments.join(" ")).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let (points, status, _) = read_friendship(pool, &save, &character.name).await?;
+        let current_hearts = points / POINTS_PER_HEART;
+        let events = read_heart_events(pool, &character.name, Some(&save)).await?;
+
+        let mut messages = vec![friendship_line(&character.name, points)];
+        match events.iter().find(|e| !e.seen) {
+            Some(event) if event.hearts <= current_hearts => {
+                messages.push(tr!("next-event-ready", event = describe_event(event)));
+            }
+            Some(event) if event.hearts > DATING_HEARTS && status == FriendshipStatus::Friend => {
+                messages.push(tr!(
+                    "next-event-after-dating",
+                    event = describe_event(event)
+                ));
+                messages.push(tr!(
+                    "next-event-date-first",
+                    name = character.name.as_str(),
+                    hearts = DATING_HEARTS
+                ));
+            }
+            Some(event) => {
+                messages.push(tr!("next-event-upcoming", event = describe_event(event)));
+                messages.push(tr!(
+                    "next-event-points-needed",
+                    points = event.hearts * POINTS_PER_HEART - points
+                ));
+            }
+            None if events.is_empty() => {
+                messages.push(tr!("next-event-none", name = character.name.as_str()));
+            }
+            None => messages.push(tr!("next-event-all-seen")),
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+pub mod schedule_commands {
+    use crate::commands::terminal_commands::{string_to_day, string_to_season};
+    use crate::friendships::friendship_commands::read_active_save;
+    use crate::health::health_commands::is_connection_error;
+    use crate::localization::localization_commands::{
+        date_name, matches_text, parse_season, tr, value_name,
+    };
+    use crate::lookup::lookup_commands::resolve_character;
+    use crate::storage::backend::{checked_query, Pool};
+    use crate::{print_aesthetic_message, Season, StardewDate, Weekday};
+    use std::{error::Error, str::FromStr};
+    use strum_macros::{AsRefStr, EnumString};
+
+    const DEFAULT_TIME: u16 = 1200;
+
+    #[derive(Debug, Clone, Copy, PartialEq, AsRefStr, EnumString)]
+    pub(crate) enum Weather {
+        #[strum(ascii_case_insensitive)]
+        Sunny,
+        #[strum(ascii_case_insensitive)]
+        Rainy,
+    }
+
+    // one line of a villager's schedule: from `time` on, they are at `location`
+    // empty season, weekday or weather means the line applies to all of them
+    pub(crate) struct ScheduleEntry {
+        character_name: String,
+        season: Option<Season>,
+        weekday: Option<Weekday>,
+        weather: Option<Weather>,
+        time: u16,
+        location: String,
+    }
+
+    // "rainy" or the name of the weather in any language, e.g. "regnerisch"
+    fn parse_weather(input: &str) -> Option<Weather> {
+        Weather::from_str(input).ok().or_else(|| {
+            [Weather::Sunny, Weather::Rainy]
+                .into_iter()
+                .find(|weather| {
+                    matches_text(
+                        input,
+                        &format!("weather-{}", weather.as_ref().to_lowercase()),
+                    )
+                })
+        })
+    }
+
+    impl ScheduleEntry {
+        fn applies_to(&self, date: &StardewDate, weather: Weather) -> bool {
+            self.season.is_none_or(|s| s == date.season)
+                && self.weekday.is_none_or(|w| w == date.weekday())
+                && self.weather.is_none_or(|w| w == weather)
+        }
+
+        // rainy day schedules win over weekday schedules, which win over seasonal ones
+        fn specificity(&self) -> u8 {
+            self.weather.map_or(0, |_| 4)
+                + self.weekday.map_or(0, |_| 2)
+                + self.season.map_or(0, |_| 1)
+        }
+    }
+
+    pub(crate) async fn read_schedules(pool: &Pool) -> Result<Vec<ScheduleEntry>, Box<dyn Error>> {
+        let rows = checked_query!("SELECT character_name, season, weekday, weather, time, location FROM schedules ORDER BY character_name, time")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ScheduleEntry {
+                character_name: row.get::<String, _>("character_name"),
+                season: row
+                    .get::<Option<String>, _>("season")
+                    .and_then(|s| Season::from_str(&s).ok()),
+                weekday: row
+                    .get::<Option<String>, _>("weekday")
+                    .and_then(|w| Weekday::from_str(&w).ok()),
+                weather: row
+                    .get::<Option<String>, _>("weather")
+                    .and_then(|w| Weather::from_str(&w).ok()),
+                time: row.get::<i32, _>("time") as u16,
+                location: row.get::<String, _>("location"),
+            })
+            .collect())
+    }
+
+    // the default schedule of the day with the more specific lines layered on top: a line holds
+    // until a later one that is at least as specific, so a rainy day line keeps them inside all day
+    // while a Friday evening line only changes the evening
+    pub(crate) fn location_at<'a>(
+        entries: &'a [ScheduleEntry],
+        character_name: &str,
+        date: &StardewDate,
+        weather: Weather,
+        time: u16,
+    ) -> Option<&'a ScheduleEntry> {
+        let mut schedule: Vec<&ScheduleEntry> = entries
+            .iter()
+            .filter(|e| e.character_name.eq_ignore_ascii_case(character_name))
+            .filter(|e| e.applies_to(date, weather))
+            .collect();
+        schedule.sort_by_key(|e| (e.time, std::cmp::Reverse(e.specificity())));
+
+        let mut current: Option<&ScheduleEntry> = None;
+        for entry in schedule.iter().filter(|e| e.time <= time) {
+            if current.is_none_or(|c| entry.specificity() >= c.specificity()) {
+                current = Some(entry);
+            }
+        }
+        current.or_else(|| schedule.first().copied())
+    }
+
+    // accepts "14:30" or "1430", the in-game clock runs from 6:00 to 26:00 (2am)
+    pub(crate) fn string_to_time(string: &str) -> Option<u16> {
+        let digits = string.replace(':', "");
+        let time = match digits.parse::<u16>() {
+            Ok(t) if digits.len() <= 2 => t * 100,
+            Ok(t) => t,
+            Err(_) => {
+                println!("{}", tr!("time-invalid", input = string));
+                return None;
+            }
+        };
+        if time % 100 >= 60 || !(600..=2600).contains(&time) {
+            println!("{}", tr!("time-out-of-range", input = string));
+            return None;
+        }
+        Some(time)
+    }
+
+    pub(crate) fn format_time(time: u16) -> String {
+        format!("{:02}:{:02}", (time / 100) % 24, time % 100)
+    }
+
+    fn looks_like_time(string: &str) -> bool {
+        string.contains(':') || (string.len() >= 3 && string.chars().all(|c| c.is_ascii_digit()))
+    }
+
+    // reads the optional "[season day] [time] [weather]" part of 'where' and 'who-is-at'
+    async fn parse_moment(
+        pool: &Pool,
+        arguments: &[&str],
+    ) -> Result<Option<(StardewDate, u16, Weather)>, Box<dyn Error>> {
+        let mut date = None;
+        let mut time = None;
+        let mut weather = Weather::Sunny;
+
+        let mut index = 0;
+        while index < arguments.len() {
+            let argument = arguments[index];
+            if let Some(w) = parse_weather(argument) {
+                weather = w;
+            } else if let Some(season) = parse_season(argument) {
+                let day = match arguments.get(index + 1).and_then(|d| string_to_day(d)) {
+                    Some(d) => d,
+                    None => {
+                        println!("{}", tr!("moment-day-missing"));
+                        return Ok(None);
+                    }
+                };
+                date = Some(StardewDate { season, day });
+                index += 1;
+            } else if looks_like_time(argument) {
+                time = match string_to_time(argument) {
+                    Some(t) => Some(t),
+                    None => return Ok(None),
+                };
+            } else {
+                println!("{}", tr!("moment-not-understood", input = argument));
+                return Ok(None);
+            }
+            index += 1;
+        }
+
+        let date = match date {
+            Some(d) => d,
+            None => match read_active_save(pool).await? {
+                Some(save) => save.date,
+                None => {
+                    println!("{}", tr!("moment-no-date"));
+                    println!("{}", tr!("save-date-hint"));
+                    return Ok(None);
+                }
+            },
+        };
+
+        Ok(Some((date, time.unwrap_or(DEFAULT_TIME), weather)))
+    }
+
+    pub async fn handle_where_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.is_empty() {
+            println!("{}", tr!("where-usage"));
+            println!("{}", tr!("where-example"));
+            return Ok(());
+        }
+
+        let character_name = match resolve_character(pool, arguments[0]).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let (date, time, weather) = match parse_moment(pool, &arguments[1..]).await? {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        let entries = read_schedules(pool).await?;
+        match location_at(&entries, &character_name, &date, weather, time) {
+            Some(entry) => print_aesthetic_message(vec![tr!(
+                "where-found",
+                date = date_name(&date),
+                weekday = value_name("weekday", &date.weekday()),
+                weather = value_name("weather", &weather),
+                time = format_time(time),
+                name = entry.character_name.as_str(),
+                location = entry.location.as_str()
+            )]),
+            None => {
+                print_aesthetic_message(vec![tr!("where-unknown", name = character_name.as_str())])
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn handle_who_is_at_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let time_index = arguments.iter().position(|a| looks_like_time(a));
+        let time_index = match time_index {
+            Some(i) if i > 0 => i,
+            _ => {
+                println!("{}", tr!("who-is-at-usage"));
+                println!("{}", tr!("who-is-at-example"));
+                return Ok(());
+            }
+        };
+
+        let location = arguments[..time_index].join(" ");
+        let (date, time, weather) = match parse_moment(pool, &arguments[time_index..]).await? {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+
+        let entries = read_schedules(pool).await?;
+        let mut character_names: Vec<&str> =
+            entries.iter().map(|e| e.character_name.as_str()).collect();
+        character_names.dedup();
+
+        let mut messages = vec![tr!(
+            "who-is-at-title",
+            time = format_time(time),
+            date = date_name(&date),
+            weekday = value_name("weekday", &date.weekday()),
+            weather = value_name("weather", &weather)
+        )];
+        let mut found = false;
+        for character_name in character_names {
+            if let Some(entry) = location_at(&entries, character_name, &date, weather, time) {
+                if entry
+                    .location
+                    .to_lowercase()
+                    .contains(&location.to_lowercase())
+                {
+                    messages.push(tr!(
+                        "who-is-at-line",
+                        name = character_name,
+                        location = entry.location.as_str()
+                    ));
+                    found = true;
+                }
+            }
+        }
+        if !found {
+            messages.push(tr!("who-is-at-nobody", location = location.as_str()));
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    pub async fn handle_schedule_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if arguments.len() < 6 {
+            println!("{}", tr!("schedule-usage"));
+            println!("{}", tr!("schedule-example"));
+            return Ok(());
+        }
+
+        let character_name = match resolve_character(pool, arguments[0]).await? {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let season = match arguments[1] {
+            "any" => None,
+            s => match string_to_season(s) {
+                Some(s) => Some(s),
+                None => return Ok(()),
+            },
+        };
+        let weekday = match arguments[2] {
+            "any" => None,
+            w => match Weekday::from_str(w) {
+                Ok(w) => Some(w),
+                Err(_) => {
+                    println!("{}", tr!("schedule-invalid-weekday", input = w));
+                    return Ok(());
+                }
+            },
+        };
+        let weather = match arguments[3] {
+            "any" => None,
+            w => match parse_weather(w) {
+                Some(w) => Some(w),
+                None => {
+                    println!("{}", tr!("schedule-invalid-weather", input = w));
+                    return Ok(());
+                }
+            },
+        };
+        let time = match string_to_time(arguments[4]) {
+            Some(t) => t,
+            None => return Ok(()),
+        };
+        let location = arguments[5..].join(" ");
+
+        let result = checked_query!("INSERT INTO schedules (character_name, season, weekday, weather, time, location) VALUES ($1, $2, $3, $4, $5, $6)", &character_name, season.map(|s| s.as_ref().to_string()), weekday.map(|w| w.as_ref().to_string()), weather.map(|w| w.as_ref().to_string()), i32::from(time), &location)
+            .execute(pool)
+            .await;
+
+        match result {
+            Ok(_) => print_aesthetic_message(vec![tr!(
+                "schedule-added",
+                time = format_time(time),
+                name = character_name.as_str(),
+                location = location.as_str()
+            )]),
+            Err(e) if is_connection_error(&e) => return Err(e.into()),
+            Err(e) => print_aesthetic_message(vec![
+                tr!("schedule-error", name = character_name.as_str()),
+                format!("{e}"),
+            ]),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn entry(
+            weekday: Option<Weekday>,
+            weather: Option<Weather>,
+            time: u16,
+            location: &str,
+        ) -> ScheduleEntry {
+            ScheduleEntry {
+                character_name: "Abigail".to_string(),
+                season: None,
+                weekday,
+                weather,
+                time,
+                location: location.to_string(),
+            }
+        }
+
+        fn abigail() -> Vec<ScheduleEntry> {
+            vec![
+                entry(None, None, 600, "Pierre's General Store"),
+                entry(None, Some(Weather::Rainy), 600, "Pierre's General Store"),
+                entry(None, None, 1200, "Mountain Lake"),
+                entry(None, None, 1700, "Pierre's General Store"),
+                entry(Some(Weekday::Friday), None, 1900, "Stardrop Saloon"),
+            ]
+        }
+
+        fn location(date: StardewDate, weather: Weather, time: u16) -> Option<String> {
+            location_at(&abigail(), "abigail", &date, weather, time).map(|e| e.location.clone())
+        }
+
+        // spring 5 is a Friday
+        const FRIDAY: StardewDate = StardewDate {
+            season: Season::Spring,
+            day: 5,
+        };
+
+        #[test]
+        fn friday_noon_follows_the_default_schedule() {
+            assert_eq!(FRIDAY.weekday(), Weekday::Friday);
+            assert_eq!(
+                location(FRIDAY, Weather::Sunny, 1200).as_deref(),
+                Some("Mountain Lake")
+            );
+        }
+
+        #[test]
+        fn friday_evening_uses_the_weekday_line() {
+            assert_eq!(
+                location(FRIDAY, Weather::Sunny, 1800).as_deref(),
+                Some("Pierre's General Store")
+            );
+            assert_eq!(
+                location(FRIDAY, Weather::Sunny, 2000).as_deref(),
+                Some("Stardrop Saloon")
+            );
+        }
+
+        #[test]
+        fn rainy_line_holds_for_the_rest_of_the_day() {
+            assert_eq!(
+                location(FRIDAY, Weather::Rainy, 1300).as_deref(),
+                Some("Pierre's General Store")
+            );
+        }
+
+        #[test]
+        fn before_the_first_line_they_are_where_the_day_starts() {
+            let saturday = StardewDate {
+                season: Season::Spring,
+                day: 6,
+            };
+            assert_eq!(
+                location(saturday, Weather::Sunny, 2000).as_deref(),
+                Some("Pierre's General Store")
+            );
+            let mut entries = abigail();
+            entries.retain(|e| e.time >= 1200);
+            let early = location_at(&entries, "Abigail", &saturday, Weather::Sunny, 700);
+            assert_eq!(early.map(|e| e.location.as_str()), Some("Mountain Lake"));
+        }
+
+        #[test]
+        fn times_are_read_with_or_without_a_colon() {
+            assert_eq!(string_to_time("9"), Some(900));
+            assert_eq!(string_to_time("14"), Some(1400));
+            assert_eq!(string_to_time("1730"), Some(1730));
+            assert_eq!(string_to_time("17:30"), Some(1730));
+            assert_eq!(string_to_time("6:00"), Some(600));
+            assert_eq!(string_to_time("25:30"), Some(2530));
+        }
+
+        #[test]
+        fn times_outside_the_day_are_rejected() {
+            assert_eq!(string_to_time("5"), None);
+            assert_eq!(string_to_time("2610"), None);
+            assert_eq!(string_to_time("12:75"), None);
+            assert_eq!(string_to_time("noon"), None);
+            assert_eq!(format_time(2530), "01:30");
+        }
+
+        #[test]
+        fn unknown_villagers_have_no_location() {
+            assert!(location_at(&abigail(), "Sebastian", &FRIDAY, Weather::Sunny, 1200).is_none());
+        }
+    }
+}
@@ -0,0 +1,352 @@
+pub mod search_commands {
+    use crate::localization::localization_commands::{text, tr};
+    use crate::print_aesthetic_message;
+    use crate::storage::backend::{query, Backend, Pool};
+    use std::{cmp::Ordering, error::Error};
+
+    const MAX_RESULTS: usize = 10;
+    // MySQL ignores shorter words in FULLTEXT indexes, so the LIKE search does too
+    const MIN_WORD_LENGTH: usize = 3;
+
+    // a text column that belongs to a character; every column needs a FULLTEXT index of its own
+    struct SearchField {
+        // message id of the field's name, like "search-field-best-gift"
+        label: &'static str,
+        table: &'static str,
+        column: &'static str,
+        character_column: &'static str,
+        // what to show for a match, usually just the column
+        shown: &'static str,
+    }
+
+    const SEARCH_FIELDS: [SearchField; 10] = [
+        SearchField {
+            label: "search-field-name",
+            table: "characters",
+            column: "name",
+            character_column: "name",
+            shown: "name",
+        },
+        SearchField {
+            label: "search-field-best-gift",
+            table: "characters",
+            column: "best_gift",
+            character_column: "name",
+            shown: "best_gift",
+        },
+        SearchField {
+            label: "search-field-gift-taste",
+            table: "gift_tastes",
+            column: "item",
+            character_column: "character_name",
+            shown: "CONCAT(item, ' (', LOWER(taste), ')')",
+        },
+        SearchField {
+            label: "search-field-alias",
+            table: "aliases",
+            column: "alias",
+            character_column: "character_name",
+            shown: "alias",
+        },
+        SearchField {
+            label: "search-field-note",
+            table: "notes",
+            column: "text",
+            character_column: "character_name",
+            shown: "text",
+        },
+        SearchField {
+            label: "search-field-tag",
+            table: "tags",
+            column: "tag",
+            character_column: "character_name",
+            shown: "tag",
+        },
+        SearchField {
+            label: "search-field-schedule",
+            table: "schedules",
+            column: "location",
+            character_column: "character_name",
+            shown: "location",
+        },
+        SearchField {
+            label: "search-field-heart-event",
+            table: "heart_events",
+            column: "location",
+            character_column: "character_name",
+            shown: "CONCAT(hearts, '♥ ', location)",
+        },
+        SearchField {
+            label: "search-field-heart-event",
+            table: "heart_events",
+            column: "conditions",
+            character_column: "character_name",
+            shown: "CONCAT(hearts, '♥ ', conditions)",
+        },
+        SearchField {
+            label: "search-field-spouse-room",
+            table: "romance",
+            column: "spouse_room",
+            character_column: "character_name",
+            shown: "spouse_room",
+        },
+    ];
+
+    struct SearchResult {
+        character_name: String,
+        score: f64,
+        // (message id of the field, matched text)
+        matches: Vec<(&'static str, String)>,
+    }
+
+    // "likes gems!" -> ["likes", "gems"]
+    fn search_words(text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| w.chars().count() >= MIN_WORD_LENGTH)
+            .map(|w| w.to_lowercase())
+            .collect()
+    }
+
+    fn add_match(
+        results: &mut Vec<SearchResult>,
+        character_name: String,
+        field: &SearchField,
+        shown: String,
+        score: f64,
+    ) {
+        match results
+            .iter_mut()
+            .find(|r| r.character_name == character_name)
+        {
+            Some(result) => {
+                result.score += score;
+                result.matches.push((field.label, shown));
+            }
+            None => results.push(SearchResult {
+                character_name,
+                score,
+                matches: vec![(field.label, shown)],
+            }),
+        }
+    }
+
+    // every word may match anywhere, and "gem" also finds "gems" and "gemstone"
+    async fn search_full_text(
+        pool: &Pool,
+        words: &[String],
+    ) -> Result<Vec<SearchResult>, sqlx::Error> {
+        let against = words
+            .iter()
+            .map(|w| format!("{}*", w))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut results = vec![];
+        for field in &SEARCH_FIELDS {
+            let rows = query(&format!(
+                "SELECT {} AS character_name, {} AS shown, MATCH({}) AGAINST (? IN BOOLEAN MODE) AS relevance FROM {} WHERE MATCH({}) AGAINST (? IN BOOLEAN MODE)",
+                field.character_column, field.shown, field.column, field.table, field.column
+            ))
+            .bind(&against)
+            .bind(&against)
+            .fetch_all(pool)
+            .await?;
+
+            for row in rows {
+                add_match(
+                    &mut results,
+                    row.get::<String, _>("character_name"),
+                    field,
+                    row.get::<String, _>("shown"),
+                    row.get::<f64, _>("relevance"),
+                );
+            }
+        }
+        Ok(results)
+    }
+
+    // whole values count more than words, words more than parts of words
+    fn like_score(words: &[String], value: &str) -> f64 {
+        let value = value.to_lowercase();
+        let value_words = search_words(&value);
+        words
+            .iter()
+            .map(|word| {
+                if value == *word {
+                    3.0
+                } else if value_words.iter().any(|w| w.starts_with(word.as_str())) {
+                    2.0
+                } else if value.contains(word.as_str()) {
+                    1.0
+                } else {
+                    0.0
+                }
+            })
+            .sum()
+    }
+
+    // best first, and alphabetically when the scores are the same
+    fn rank_results(results: &mut [SearchResult]) {
+        results.sort_by(|a, b| match b.score.partial_cmp(&a.score) {
+            Some(Ordering::Equal) | None => a.character_name.cmp(&b.character_name),
+            Some(ordering) => ordering,
+        });
+    }
+
+    // for databases without FULLTEXT indexes
+    async fn search_like(
+        pool: &Pool,
+        words: &[String],
+    ) -> Result<Vec<SearchResult>, Box<dyn Error>> {
+        let mut results = vec![];
+        for field in &SEARCH_FIELDS {
+            // LIKE doesn't work with the case insensitive collation of the Postgres tables
+            let condition = match pool.backend() {
+                Backend::MySql => format!("LOWER({}) LIKE ?", field.column),
+                Backend::Postgres => format!("LOWER({}) COLLATE \"C\" LIKE ?", field.column),
+            };
+            let conditions = vec![condition; words.len()];
+            let query_string = format!(
+                "SELECT {} AS character_name, {} AS shown, {} AS value FROM {} WHERE {}",
+                field.character_column,
+                field.shown,
+                field.column,
+                field.table,
+                conditions.join(" OR ")
+            );
+            let mut search_query = query(&query_string);
+            for word in words {
+                search_query = search_query.bind(format!("%{}%", word));
+            }
+            let rows = search_query.fetch_all(pool).await?;
+
+            for row in rows {
+                add_match(
+                    &mut results,
+                    row.get::<String, _>("character_name"),
+                    field,
+                    row.get::<String, _>("shown"),
+                    like_score(words, &row.get::<String, _>("value")),
+                );
+            }
+        }
+        Ok(results)
+    }
+
+    pub async fn handle_search_command(
+        pool: &Pool,
+        arguments: Vec<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let words = search_words(&arguments.join(" "));
+        if words.is_empty() {
+            println!("{}", tr!("search-usage", length = MIN_WORD_LENGTH));
+            println!("{}", tr!("search-example"));
+            return Ok(());
+        }
+
+        // without FULLTEXT indexes, or when MySQL drops all words as too common, LIKE still finds something.
+        // Postgres has no MATCH ... AGAINST, so it always uses LIKE.
+        let full_text = match pool.backend() {
+            Backend::MySql => search_full_text(pool, &words).await,
+            Backend::Postgres => Ok(vec![]),
+        };
+        let mut results = match full_text {
+            Ok(r) if !r.is_empty() => r,
+            _ => search_like(pool, &words).await?,
+        };
+        rank_results(&mut results);
+
+        let searched = arguments.join(" ");
+        if results.is_empty() {
+            print_aesthetic_message(vec![tr!("search-none", text = searched.as_str())]);
+            return Ok(());
+        }
+
+        let mut messages = vec![tr!("search-results", text = searched.as_str())];
+        for (rank, result) in results.iter().take(MAX_RESULTS).enumerate() {
+            messages.push(format!("{}. {}", rank + 1, &result.character_name));
+            for (label, shown) in &result.matches {
+                messages.push(format!("   {}: {}", text(label), shown));
+            }
+        }
+        if results.len() > MAX_RESULTS {
+            messages.push(tr!("search-more", count = results.len() - MAX_RESULTS));
+        }
+        print_aesthetic_message(messages);
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn words(text: &str) -> Vec<String> {
+            search_words(text)
+        }
+
+        fn field(label: &str) -> &'static SearchField {
+            SEARCH_FIELDS.iter().find(|f| f.label == label).unwrap()
+        }
+
+        #[test]
+        fn short_words_and_punctuation_are_left_out() {
+            assert_eq!(words("likes gems!"), ["likes", "gems"]);
+            assert_eq!(words("a Gem to go"), ["gem"]);
+            assert_eq!(words("Émily's ÉTÉ"), ["émily", "été"]);
+            assert!(words("a b").is_empty());
+        }
+
+        #[test]
+        fn whole_values_beat_words_which_beat_parts_of_words() {
+            assert_eq!(like_score(&words("gems"), "Gems"), 3.0);
+            assert_eq!(like_score(&words("gem"), "Gems and minerals"), 2.0);
+            assert_eq!(like_score(&words("ems"), "Gems"), 1.0);
+            assert_eq!(like_score(&words("ruby"), "Gems"), 0.0);
+            // every word counts
+            assert_eq!(like_score(&words("gem min"), "Gems and minerals"), 4.0);
+        }
+
+        #[test]
+        fn matches_of_the_same_character_add_up() {
+            let mut results = vec![];
+            let name = field("search-field-name");
+            let tag = field("search-field-tag");
+            add_match(&mut results, "Abigail".into(), tag, "gems".into(), 3.0);
+            add_match(&mut results, "Emily".into(), name, "Emily".into(), 2.0);
+            add_match(&mut results, "Abigail".into(), name, "Abigail".into(), 1.0);
+
+            assert_eq!(results.len(), 2);
+            assert_eq!(results[0].score, 4.0);
+            assert_eq!(
+                results[0].matches,
+                [
+                    ("search-field-tag", "gems".to_string()),
+                    ("search-field-name", "Abigail".to_string())
+                ]
+            );
+            assert_eq!(
+                results[1].matches,
+                [("search-field-name", "Emily".to_string())]
+            );
+        }
+
+        #[test]
+        fn results_are_ranked_by_score_then_name() {
+            let result = |name: &str, score| SearchResult {
+                character_name: name.to_string(),
+                score,
+                matches: vec![],
+            };
+            let mut results = vec![
+                result("Sam", 1.0),
+                result("Emily", 2.0),
+                result("Abigail", 1.0),
+                result("Haley", 3.0),
+            ];
+            rank_results(&mut results);
+            let names: Vec<&str> = results.iter().map(|r| r.character_name.as_str()).collect();
+            assert_eq!(names, ["Haley", "Emily", "Abigail", "Sam"]);
+        }
+    }
+}
@@ -1,47 +1,684 @@
 use dotenv::dotenv;
-use sqlx::mysql::*;
+use rand::{distributions::Alphanumeric, Rng};
+use sqlx::{migrate, migrate::Migrator, mysql::*, query, Connection, Executor, MySql, Row};
+use std::collections::HashMap;
 use std::env;
 use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
+use tracing::Instrument;
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    dotenv().ok();
-    let url =
-        env::var("ROOT_DATABASE_URL").expect("ROOT_DATABASE_URL must be set in your .env file");
-    let db_host = env::var("DB_HOST").expect("DB_HOST must be set in your .env file");
-    let db_name = env::var("DB_NAME").expect("DB_NAME must be set in your .env file");
-    let db_user = env::var("DB_USER").expect("DB_USER must be set in your .env file");
-    let db_password = env::var("DB_PASSWORD").expect("DB_PASSWORD must be set in your .env file");
-
-    let pool = MySqlPool::connect(&url).await?;
-
-    sqlx::query(&format!("CREATE DATABASE IF NOT EXISTS {}", db_name))
-        .execute(&pool)
-        .await?;
+// only the settings, their messages and the connect options are used here, the rest belongs to the application
+#[allow(dead_code)]
+mod config;
+mod logging;
+#[allow(dead_code)]
+mod messages;
+#[allow(dead_code, unused_macros, unused_imports)]
+mod storage;
+
+use config::settings::Config;
+use storage::backend::{Backend, ConnectOptions};
+
+static MIGRATOR: Migrator = migrate!("./migrations/mysql");
+
+const PASSWORD_LENGTH: usize = 24;
 
-    sqlx::query(&format!(
-        "CREATE USER IF NOT EXISTS '{}'@'{}' IDENTIFIED BY '{}'",
-        db_user, db_host, db_password
-    ))
-    .execute(&pool)
-    .await?;
+// a database user and the least it needs to do its job
+struct Role {
+    name: &'static str,
+    user_env: &'static str,
+    password_env: &'static str,
+    privileges: &'static [&'static str],
+}
+
+const ROLES: [Role; 3] = [
+    // the application itself only reads and writes rows
+    Role {
+        name: "app",
+        user_env: "DB_USER",
+        password_env: "DB_PASSWORD",
+        privileges: &["SELECT", "INSERT", "UPDATE", "DELETE"],
+    },
+    // creates and changes tables, and the databases of profiles
+    Role {
+        name: "migrator",
+        user_env: "DB_MIGRATOR_USER",
+        password_env: "DB_MIGRATOR_PASSWORD",
+        privileges: &[
+            "SELECT",
+            "INSERT",
+            "UPDATE",
+            "DELETE",
+            "CREATE",
+            "ALTER",
+            "DROP",
+            "INDEX",
+            "REFERENCES",
+        ],
+    },
+    // for reports and other tools that only look
+    Role {
+        name: "report",
+        user_env: "DB_REPORT_USER",
+        password_env: "DB_REPORT_PASSWORD",
+        privileges: &["SELECT"],
+    },
+];
 
-    sqlx::query(&format!(
-        "GRANT ALL PRIVILEGES ON {}.* TO '{}'@'{}'",
-        db_name, db_user, db_host
-    ))
-    .execute(&pool)
-    .await?;
+struct Account {
+    role: &'static Role,
+    user: String,
+    password: String,
+}
+
+struct Settings {
+    root_url: String,
+    // how the users connect: the server, the socket and SSL from the config file or the environment
+    options: MySqlConnectOptions,
+    // the host the users are created for
+    host: String,
+    db_name: String,
+    accounts: Vec<Account>,
+    // roles without a user in the .env file
+    skipped: Vec<&'static Role>,
+}
 
-    sqlx::query("FLUSH PRIVILEGES").execute(&pool).await?;
+#[derive(Debug)]
+enum Mode {
+    Setup,
+    Check,
+    RotatePassword(Option<String>),
+}
 
+fn print_aesthetic_message(messages: Vec<impl Into<String>>) {
     println!("•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•");
     println!(" ");
-    println!(
-        "Database setup complete! You can find it under the name '{}'",
-        db_name
-    );
+    for message in messages {
+        println!("{}", message.into());
+    }
     println!(" ");
     println!("•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•°•");
+}
+
+fn required(key: &str) -> Result<String, String> {
+    env::var(key)
+        .ok()
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| format!("{} must be set in your .env file", key))
+}
+
+fn read_settings() -> Result<Settings, Box<dyn Error>> {
+    // users and grants work differently in Postgres, there the tables are created by 'migrate up'
+    let root_url = required("ROOT_DATABASE_URL")?;
+    let config = Config::load(&[])?;
+    if root_url.starts_with("postgres") || config.backend()? == Backend::Postgres {
+        return Err("setup_db only sets up MySQL and MariaDB. For Postgres, create the database and its users yourself and let the application apply the migrations.".into());
+    }
+
+    let mut accounts = vec![];
+    let mut skipped = vec![];
+    for role in &ROLES {
+        // the app user has always been required, the others are optional
+        match env::var(role.user_env).ok().filter(|u| !u.is_empty()) {
+            Some(user) => accounts.push(Account {
+                role,
+                user,
+                password: required(role.password_env)?,
+            }),
+            None if role.name == "app" => return Err(required(role.user_env).unwrap_err().into()),
+            None => skipped.push(role),
+        }
+    }
+
+    let db_name = config.database_name()?;
+    let options = match config.connect_options(&db_name)? {
+        ConnectOptions::MySql(options) => options,
+        ConnectOptions::Postgres(_) => unreachable!("Postgres was ruled out above"),
+    };
+    Ok(Settings {
+        root_url,
+        host: options.get_host().to_string(),
+        options,
+        db_name,
+        accounts,
+        skipped,
+    })
+}
+
+fn parse_mode(arguments: &[String]) -> Option<Mode> {
+    match arguments {
+        [] => Some(Mode::Setup),
+        [flag] if flag == "--check" => Some(Mode::Check),
+        [flag] if flag == "--rotate-password" => Some(Mode::RotatePassword(None)),
+        [flag, role] if flag == "--rotate-password" => {
+            Some(Mode::RotatePassword(Some(role.clone())))
+        }
+        _ => None,
+    }
+}
+
+// CREATE USER and GRANT can't take bound parameters, so values have to be quoted by hand
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
+fn account_name(settings: &Settings, account: &Account) -> String {
+    format!("{}@{}", quote(&account.user), quote(&settings.host))
+}
+
+// the database itself, and the ones of profiles, named like "<DB_NAME>_<profile>"
+fn scopes(settings: &Settings) -> [String; 2] {
+    [
+        settings.db_name.clone(),
+        format!("{}\\_%", settings.db_name.replace('_', "\\_")),
+    ]
+}
+
+async fn schema_privileges<'e>(
+    executor: impl Executor<'e, Database = MySql>,
+    settings: &Settings,
+    account: &Account,
+    scope: &str,
+) -> Result<Vec<String>, sqlx::Error> {
+    Ok(query("SELECT PRIVILEGE_TYPE FROM information_schema.SCHEMA_PRIVILEGES WHERE GRANTEE = ? AND TABLE_SCHEMA = ?")
+        .bind(account_name(settings, account))
+        .bind(scope)
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .map(|row| row.get::<String, _>("PRIVILEGE_TYPE"))
+        .collect())
+}
+
+// (missing, extra) compared to what the role should have
+fn compare_privileges(role: &Role, existing: &[String]) -> (Vec<&'static str>, Vec<String>) {
+    let missing = role
+        .privileges
+        .iter()
+        .filter(|p| !existing.iter().any(|e| e == *p))
+        .copied()
+        .collect();
+    let extra = existing
+        .iter()
+        .filter(|e| !role.privileges.contains(&e.as_str()))
+        .cloned()
+        .collect();
+    (missing, extra)
+}
+
+fn user_options(settings: &Settings, account: &Account) -> MySqlConnectOptions {
+    settings
+        .options
+        .clone()
+        .username(&account.user)
+        .password(&account.password)
+}
+
+async fn setup(settings: &Settings, root: &MySqlPool) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut report = vec![];
+
+    let database_exists =
+        query("SELECT SCHEMA_NAME FROM information_schema.SCHEMATA WHERE SCHEMA_NAME = ?")
+            .bind(&settings.db_name)
+            .fetch_optional(root)
+            .await?
+            .is_some();
+    if database_exists {
+        report.push(format!(
+            "• The database '{}' already existed.",
+            settings.db_name
+        ));
+    } else {
+        query(&format!(
+            "CREATE DATABASE {}",
+            quote_identifier(&settings.db_name)
+        ))
+        .execute(root)
+        .await?;
+        report.push(format!("✅ Created the database '{}'.", settings.db_name));
+    }
+
+    for account in &settings.accounts {
+        let user_exists = query("SELECT User FROM mysql.user WHERE User = ? AND Host = ?")
+            .bind(&account.user)
+            .bind(&settings.host)
+            .fetch_optional(root)
+            .await?
+            .is_some();
+        if user_exists {
+            report.push(format!(
+                "• The {} user '{}' already existed, its password was left as it is.",
+                account.role.name, account.user
+            ));
+        } else {
+            query(&format!(
+                "CREATE USER {} IDENTIFIED BY {}",
+                account_name(settings, account),
+                quote(&account.password)
+            ))
+            .execute(root)
+            .await?;
+            report.push(format!(
+                "✅ Created the {} user '{}'.",
+                account.role.name, account.user
+            ));
+        }
+
+        for scope in scopes(settings) {
+            let existing = schema_privileges(root, settings, account, &scope).await?;
+            let (missing, extra) = compare_privileges(account.role, &existing);
+            let on = format!("{}.*", quote_identifier(&scope));
+
+            if !missing.is_empty() {
+                query(&format!(
+                    "GRANT {} ON {} TO {}",
+                    missing.join(", "),
+                    on,
+                    account_name(settings, account)
+                ))
+                .execute(root)
+                .await?;
+                report.push(format!(
+                    "✅ Granted {} on {} to '{}'.",
+                    missing.join(", "),
+                    on,
+                    account.user
+                ));
+            }
+            // e.g. the ALL PRIVILEGES earlier versions of this script granted
+            if !extra.is_empty() {
+                query(&format!(
+                    "REVOKE {} ON {} FROM {}",
+                    extra.join(", "),
+                    on,
+                    account_name(settings, account)
+                ))
+                .execute(root)
+                .await?;
+                report.push(format!(
+                    "✅ Revoked {} on {} from '{}', it doesn't need them.",
+                    extra.join(", "),
+                    on,
+                    account.user
+                ));
+            }
+            if missing.is_empty() && extra.is_empty() {
+                report.push(format!(
+                    "• '{}' already had exactly the rights it needs on {}.",
+                    account.user, on
+                ));
+            }
+        }
+    }
+
+    // the app user can't create tables anymore, so the schema is set up here
+    match settings.accounts.iter().find(|a| a.role.name == "migrator") {
+        Some(migrator) => {
+            let mut connection = MySqlConnection::connect_with(&user_options(settings, migrator)).await?;
+            MIGRATOR.run(&mut connection).await?;
+            connection.close().await?;
+            report.push(format!(
+                "✅ The schema of '{}' is up to date (version {}).",
+                settings.db_name,
+                MIGRATOR.iter().map(|m| m.version).max().unwrap_or(0)
+            ));
+        }
+        None => report.push(
+            "‼ Without DB_MIGRATOR_USER, nobody may create the tables. Run 'migrate up' in the application as a user that can."
+                .to_string(),
+        ),
+    }
+
+    for role in &settings.skipped {
+        report.push(format!(
+            "• There is no {} user, set {} and {} to create one.",
+            role.name, role.user_env, role.password_env
+        ));
+    }
+
+    Ok(report)
+}
+
+// connects as every user and compares what it may do with what it should; true if all is well
+async fn check(settings: &Settings) -> Result<(Vec<String>, bool), Box<dyn Error>> {
+    let mut report = vec![];
+    let mut healthy = true;
+
+    for account in &settings.accounts {
+        // a single connection, as a pool would keep trying for its whole acquire timeout
+        let mut connection =
+            match MySqlConnection::connect_with(&user_options(settings, account)).await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    report.push(format!(
+                        "‼ The {} user '{}' can't connect: {}",
+                        account.role.name, account.user, e
+                    ));
+                    healthy = false;
+                    continue;
+                }
+            };
+        report.push(format!(
+            "✅ The {} user '{}' can connect.",
+            account.role.name, account.user
+        ));
+
+        // users can always see their own privileges
+        for scope in scopes(settings) {
+            let existing = schema_privileges(&mut connection, settings, account, &scope).await?;
+            let (missing, extra) = compare_privileges(account.role, &existing);
+            let on = format!("{}.*", quote_identifier(&scope));
+            if !missing.is_empty() {
+                report.push(format!("   ‼ is missing {} on {}", missing.join(", "), on));
+                healthy = false;
+            }
+            if !extra.is_empty() {
+                report.push(format!(
+                    "   ‼ has more than it needs on {}: {}",
+                    on,
+                    extra.join(", ")
+                ));
+                healthy = false;
+            }
+            if missing.is_empty() && extra.is_empty() {
+                report.push(format!("   ✅ has exactly the rights it needs on {}", on));
+            }
+        }
+        connection.close().await?;
+    }
+
+    if healthy {
+        report.push("✅ Everything is set up correctly.".to_string());
+    } else {
+        report.push("Run setup_db without arguments to fix it.".to_string());
+    }
+    Ok((report, healthy))
+}
+
+// replaces the values of the given keys and keeps everything else, comments included
+fn rewrite_env_file(path: &Path, values: &HashMap<&str, String>) -> Result<(), Box<dyn Error>> {
+    let text = fs::read_to_string(path)?;
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    for (key, value) in values {
+        let prefix = format!("{}=", key);
+        match lines
+            .iter_mut()
+            .find(|l| l.trim_start().starts_with(&prefix))
+        {
+            Some(line) => *line = format!("{}{}", prefix, value),
+            None => lines.push(format!("{}{}", prefix, value)),
+        }
+    }
+
+    // written next to it and then moved, so a failure never leaves half a file behind
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, lines.join("\n") + "\n")?;
+    fs::set_permissions(&temporary, fs::metadata(path)?.permissions())?;
+    fs::rename(&temporary, path)?;
     Ok(())
 }
+
+// returns the report and whether every password could be changed. Passwords that were changed before
+// one failed are still saved or printed, as the old ones don't work anymore.
+async fn rotate_passwords(
+    settings: &Settings,
+    root: &MySqlPool,
+    role: Option<&str>,
+    env_file: Option<&PathBuf>,
+) -> Result<(Vec<String>, bool), Box<dyn Error>> {
+    let accounts: Vec<&Account> = settings
+        .accounts
+        .iter()
+        .filter(|a| role.is_none_or(|r| r == a.role.name))
+        .collect();
+    if accounts.is_empty() {
+        return Err(format!(
+            "There is no {} user to rotate the password of. Please use app, migrator or report.",
+            role.unwrap_or_default()
+        )
+        .into());
+    }
+
+    let mut report = vec![];
+    let mut new_passwords = HashMap::new();
+    let mut failed = false;
+    for account in accounts {
+        let password: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(PASSWORD_LENGTH)
+            .map(char::from)
+            .collect();
+        let changed = query(&format!(
+            "ALTER USER {} IDENTIFIED BY {}",
+            account_name(settings, account),
+            quote(&password)
+        ))
+        .execute(root)
+        .await;
+        match changed {
+            Ok(_) => {
+                report.push(format!(
+                    "✅ Changed the password of the {} user '{}'.",
+                    account.role.name, account.user
+                ));
+                new_passwords.insert(account.role.password_env, password);
+            }
+            // the ones after it keep their passwords, the ones before are saved below
+            Err(e) => {
+                report.push(format!(
+                    "‼ Couldn't change the password of the {} user '{}', it keeps the old one: {}",
+                    account.role.name, account.user, e
+                ));
+                failed = true;
+                break;
+            }
+        }
+    }
+    if new_passwords.is_empty() {
+        return Ok((report, false));
+    }
+
+    match env_file.map(|path| (path, rewrite_env_file(path, &new_passwords))) {
+        Some((path, Ok(()))) => {
+            report.push(format!("✅ Saved the new passwords in {}.", path.display()))
+        }
+        // the passwords are changed already, so they must not get lost
+        Some((_, Err(e))) => {
+            report.push(format!("‼ Couldn't update the .env file: {}", e));
+            report.push("Please put these in it yourself:".to_string());
+            report.extend(
+                new_passwords
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value)),
+            );
+        }
+        None => {
+            report
+                .push("‼ There is no .env file, please put these in your environment:".to_string());
+            report.extend(
+                new_passwords
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value)),
+            );
+        }
+    }
+    if new_passwords.contains_key("DB_PASSWORD") && env::var("DATABASE_URL").is_ok() {
+        report.push(
+            "‼ DATABASE_URL still contains the old password of the app user, please update it."
+                .to_string(),
+        );
+    }
+
+    Ok((report, !failed))
+}
+
+async fn run(mode: Mode, env_file: Option<PathBuf>) -> Result<bool, Box<dyn Error>> {
+    let settings = read_settings()?;
+
+    if let Mode::Check = mode {
+        let (report, healthy) = check(&settings).await?;
+        print_aesthetic_message(report);
+        return Ok(healthy);
+    }
+
+    let root = MySqlPool::connect(&settings.root_url).await?;
+    let (report, ok) = match mode {
+        Mode::RotatePassword(role) => {
+            rotate_passwords(&settings, &root, role.as_deref(), env_file.as_ref()).await?
+        }
+        _ => {
+            let mut report = setup(&settings, &root).await?;
+            report.push(format!(
+                "Database setup complete! You can find it under the name '{}'",
+                settings.db_name
+            ));
+            (report, true)
+        }
+    };
+    root.close().await;
+    print_aesthetic_message(report);
+
+    Ok(ok)
+}
+
+#[tokio::main]
+async fn main() {
+    let env_file = dotenv().ok();
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    let mode = match parse_mode(&arguments) {
+        Some(m) => m,
+        None => {
+            eprintln!("‼ Usage: setup_db [--check | --rotate-password [app|migrator|report]]");
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = logging::subscriber::init(
+        &env::var("LOG_LEVEL").unwrap_or("warn".to_string()),
+        &env::var("LOG_FORMAT").unwrap_or("text".to_string()),
+        env::var("LOG_FILE").ok().as_deref(),
+    ) {
+        eprintln!("‼ {}", e);
+        process::exit(1);
+    }
+
+    let span = tracing::info_span!("cli", command = "setup_db", mode = ?mode);
+    let started = Instant::now();
+    let result = run(mode, env_file).instrument(span.clone()).await;
+    let elapsed = started.elapsed();
+    match &result {
+        Ok(ok) => {
+            tracing::info!(parent: &span, ?elapsed, elapsed_secs = elapsed.as_secs_f64(), ok, "command finished")
+        }
+        Err(e) => {
+            tracing::error!(parent: &span, ?elapsed, elapsed_secs = elapsed.as_secs_f64(), error = %e, "command failed")
+        }
+    }
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("‼ {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(arguments: &[&str]) -> Vec<String> {
+        arguments.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn modes_are_read_from_the_arguments() {
+        assert!(matches!(parse_mode(&[]), Some(Mode::Setup)));
+        assert!(matches!(
+            parse_mode(&arguments(&["--check"])),
+            Some(Mode::Check)
+        ));
+        assert!(matches!(
+            parse_mode(&arguments(&["--rotate-password"])),
+            Some(Mode::RotatePassword(None))
+        ));
+        assert!(matches!(
+            parse_mode(&arguments(&["--rotate-password", "report"])),
+            Some(Mode::RotatePassword(Some(role))) if role == "report"
+        ));
+        assert!(parse_mode(&arguments(&["--check", "app"])).is_none());
+        assert!(parse_mode(&arguments(&["--setup"])).is_none());
+    }
+
+    #[test]
+    fn quotes_and_backslashes_are_escaped() {
+        assert_eq!(quote("stardew"), "'stardew'");
+        assert_eq!(quote("it's"), "'it''s'");
+        assert_eq!(quote("a\\b"), "'a\\\\b'");
+        assert_eq!(
+            quote("\\'; DROP USER root; --"),
+            "'\\\\''; DROP USER root; --'"
+        );
+        assert_eq!(quote_identifier("my`db"), "`my``db`");
+    }
+
+    #[test]
+    fn privileges_are_compared_with_the_role() {
+        let report = &ROLES[2];
+        let (missing, extra) = compare_privileges(report, &[]);
+        assert_eq!(missing, ["SELECT"]);
+        assert!(extra.is_empty());
+
+        let existing = vec!["SELECT".to_string(), "DROP".to_string()];
+        let (missing, extra) = compare_privileges(report, &existing);
+        assert!(missing.is_empty());
+        assert_eq!(extra, ["DROP"]);
+
+        let app = &ROLES[0];
+        let existing = vec!["DELETE".to_string(), "SELECT".to_string()];
+        let (missing, extra) = compare_privileges(app, &existing);
+        assert_eq!(missing, ["INSERT", "UPDATE"]);
+        assert!(extra.is_empty());
+    }
+
+    #[test]
+    fn the_env_file_keeps_everything_but_the_new_values() {
+        let path = env::temp_dir().join(format!("setup_db_test_{}.env", process::id()));
+        fs::write(
+            &path,
+            "# the database\nDB_USER=stardew\nDB_PASSWORD=old\n  DB_REPORT_PASSWORD=old\n",
+        )
+        .unwrap();
+
+        let values = HashMap::from([
+            ("DB_PASSWORD", "new".to_string()),
+            ("DB_REPORT_PASSWORD", "newer".to_string()),
+            ("DB_MIGRATOR_PASSWORD", "added".to_string()),
+        ]);
+        let result = rewrite_env_file(&path, &values);
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        result.unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[..4],
+            [
+                "# the database",
+                "DB_USER=stardew",
+                "DB_PASSWORD=new",
+                "DB_REPORT_PASSWORD=newer"
+            ]
+        );
+        assert_eq!(lines[4..], ["DB_MIGRATOR_PASSWORD=added"]);
+    }
+}
@@ -0,0 +1,998 @@
+pub mod backend {
+    use futures_util::stream::BoxStream;
+    use futures_util::StreamExt;
+    use sqlx::database::HasArguments;
+    use sqlx::error::BoxDynError;
+    use sqlx::mysql::{MySqlConnectOptions, MySqlConnection, MySqlPool, MySqlRow};
+    use sqlx::postgres::{PgConnectOptions, PgConnection, PgPool, PgRow};
+    use sqlx::{Column, Connection, Database, Encode, Row as _, Type, TypeInfo, ValueRef};
+    use std::cell::Cell;
+    use std::future::Future;
+    use std::marker::PhantomData;
+    use tokio::sync::mpsc;
+    use tracing::Span;
+
+    // how many rows of a streamed result are read ahead while the caller is still busy with one
+    pub const ROWS_AHEAD: usize = 50;
+
+    // rows read and changed by the queries of the command that is running
+    #[derive(Default)]
+    struct RowCounts {
+        read: Cell<u64>,
+        affected: Cell<u64>,
+    }
+
+    tokio::task_local! {
+        static ROW_COUNTS: RowCounts;
+    }
+
+    fn count_rows(read: u64, affected: u64) {
+        // queries outside of a command, like the ones at startup, aren't counted
+        let _ = ROW_COUNTS.try_with(|counts| {
+            counts.read.set(counts.read.get() + read);
+            counts.affected.set(counts.affected.get() + affected);
+        });
+    }
+
+    // runs a command and records how many rows its queries read and changed as rows_read and
+    // rows_affected on its span, which has to declare both fields
+    pub async fn counting_rows<F: Future>(span: &Span, command: F) -> F::Output {
+        ROW_COUNTS
+            .scope(RowCounts::default(), async {
+                let output = command.await;
+                ROW_COUNTS.with(|counts| {
+                    span.record("rows_read", counts.read.get());
+                    span.record("rows_affected", counts.affected.get());
+                });
+                output
+            })
+            .await
+    }
+
+    // sqlx's Any driver can't read BOOLEAN or TINYINT columns from MySQL, so the commands use this
+    // small layer instead: one query type for both servers, and rows that are read into plain values
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Backend {
+        MySql,
+        Postgres,
+    }
+
+    impl Backend {
+        pub fn default_port(&self) -> u16 {
+            match self {
+                Backend::MySql => 3306,
+                Backend::Postgres => 5432,
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    pub enum ConnectOptions {
+        MySql(MySqlConnectOptions),
+        Postgres(PgConnectOptions),
+    }
+
+    impl ConnectOptions {
+        pub fn get_database(&self) -> Option<&str> {
+            match self {
+                ConnectOptions::MySql(options) => options.get_database(),
+                ConnectOptions::Postgres(options) => options.get_database(),
+            }
+        }
+
+        pub fn username(&self) -> &str {
+            match self {
+                ConnectOptions::MySql(options) => options.get_username(),
+                ConnectOptions::Postgres(options) => options.get_username(),
+            }
+        }
+
+        // the same server and settings, logged in as someone else
+        pub fn with_credentials(self, user: &str, password: &str) -> ConnectOptions {
+            match self {
+                ConnectOptions::MySql(options) => {
+                    ConnectOptions::MySql(options.username(user).password(password))
+                }
+                ConnectOptions::Postgres(options) => {
+                    ConnectOptions::Postgres(options.username(user).password(password))
+                }
+            }
+        }
+
+        // a single connection that is closed right away, to see if the server is there
+        pub async fn try_connect(&self) -> Result<(), sqlx::Error> {
+            match self {
+                ConnectOptions::MySql(options) => {
+                    MySqlConnection::connect_with(options).await?.close().await
+                }
+                ConnectOptions::Postgres(options) => {
+                    PgConnection::connect_with(options).await?.close().await
+                }
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    pub enum Pool {
+        MySql(MySqlPool),
+        Postgres(PgPool),
+    }
+
+    impl Pool {
+        pub fn backend(&self) -> Backend {
+            match self {
+                Pool::MySql(_) => Backend::MySql,
+                Pool::Postgres(_) => Backend::Postgres,
+            }
+        }
+
+        pub fn connect_options(&self) -> ConnectOptions {
+            match self {
+                Pool::MySql(pool) => ConnectOptions::MySql((*pool.connect_options()).clone()),
+                Pool::Postgres(pool) => ConnectOptions::Postgres((*pool.connect_options()).clone()),
+            }
+        }
+
+        pub fn database(&self) -> Option<String> {
+            self.connect_options().get_database().map(str::to_string)
+        }
+
+        pub async fn close(&self) {
+            match self {
+                Pool::MySql(pool) => pool.close().await,
+                Pool::Postgres(pool) => pool.close().await,
+            }
+        }
+
+        pub async fn ping(&self) -> Result<(), sqlx::Error> {
+            match self {
+                Pool::MySql(pool) => pool.acquire().await?.ping().await,
+                Pool::Postgres(pool) => pool.acquire().await?.ping().await,
+            }
+        }
+
+        pub fn size(&self) -> u32 {
+            match self {
+                Pool::MySql(pool) => pool.size(),
+                Pool::Postgres(pool) => pool.size(),
+            }
+        }
+
+        pub fn num_idle(&self) -> usize {
+            match self {
+                Pool::MySql(pool) => pool.num_idle(),
+                Pool::Postgres(pool) => pool.num_idle(),
+            }
+        }
+
+        pub fn max_connections(&self) -> u32 {
+            match self {
+                Pool::MySql(pool) => pool.options().get_max_connections(),
+                Pool::Postgres(pool) => pool.options().get_max_connections(),
+            }
+        }
+    }
+
+    // what can be bound to a '?'. NULLs keep their type, because Postgres needs one for every parameter.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Argument {
+        Bool(Option<bool>),
+        Int(Option<i64>),
+        Float(Option<f64>),
+        Text(Option<String>),
+        Bytes(Option<Vec<u8>>),
+    }
+
+    pub trait IntoArgument {
+        fn into_argument(self) -> Argument;
+        // the NULL of the same type, for binding a None
+        fn null() -> Argument;
+    }
+
+    macro_rules! into_argument {
+        ($variant:ident, $($t:ty),*) => {
+            $(
+                impl IntoArgument for $t {
+                    fn into_argument(self) -> Argument {
+                        Argument::$variant(Some(self.into()))
+                    }
+
+                    fn null() -> Argument {
+                        Argument::$variant(None)
+                    }
+                }
+            )*
+        };
+    }
+
+    into_argument!(Bool, bool);
+    into_argument!(Int, i8, i16, i32, i64, u8, u16, u32);
+    into_argument!(Float, f32, f64);
+    into_argument!(Text, String, &str);
+    into_argument!(Bytes, Vec<u8>);
+
+    impl IntoArgument for usize {
+        fn into_argument(self) -> Argument {
+            Argument::Int(Some(i64::try_from(self).unwrap_or(i64::MAX)))
+        }
+
+        fn null() -> Argument {
+            Argument::Int(None)
+        }
+    }
+
+    impl<T: IntoArgument> IntoArgument for Option<T> {
+        fn into_argument(self) -> Argument {
+            match self {
+                Some(value) => value.into_argument(),
+                None => T::null(),
+            }
+        }
+
+        fn null() -> Argument {
+            T::null()
+        }
+    }
+
+    impl<T: IntoArgument + Clone> IntoArgument for &T {
+        fn into_argument(self) -> Argument {
+            self.clone().into_argument()
+        }
+
+        fn null() -> Argument {
+            T::null()
+        }
+    }
+
+    // a column as it was read from the server
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Value {
+        Null,
+        Bool(bool),
+        Int(i64),
+        Float(f64),
+        Text(String),
+        Bytes(Vec<u8>),
+    }
+
+    // MySQL has no real booleans and Postgres no unsigned numbers, so the conversions are lenient
+    pub trait FromValue: Sized {
+        fn from_value(value: &Value) -> Result<Self, String>;
+    }
+
+    fn unexpected<T>(value: &Value, wanted: &str) -> Result<T, String> {
+        Err(format!("{:?} can't be read as {}", value, wanted))
+    }
+
+    impl FromValue for String {
+        fn from_value(value: &Value) -> Result<Self, String> {
+            match value {
+                Value::Text(text) => Ok(text.clone()),
+                // MySQL sends text in collations sqlx doesn't know as bytes
+                Value::Bytes(bytes) => String::from_utf8(bytes.clone()).map_err(|e| e.to_string()),
+                _ => unexpected(value, "text"),
+            }
+        }
+    }
+
+    impl FromValue for i64 {
+        fn from_value(value: &Value) -> Result<Self, String> {
+            match value {
+                Value::Int(number) => Ok(*number),
+                Value::Bool(b) => Ok(*b as i64),
+                _ => unexpected(value, "a number"),
+            }
+        }
+    }
+
+    impl FromValue for i32 {
+        fn from_value(value: &Value) -> Result<Self, String> {
+            i32::try_from(i64::from_value(value)?).map_err(|e| e.to_string())
+        }
+    }
+
+    impl FromValue for bool {
+        fn from_value(value: &Value) -> Result<Self, String> {
+            match value {
+                Value::Bool(b) => Ok(*b),
+                Value::Int(number) => Ok(*number != 0),
+                _ => unexpected(value, "a boolean"),
+            }
+        }
+    }
+
+    impl FromValue for f64 {
+        fn from_value(value: &Value) -> Result<Self, String> {
+            match value {
+                Value::Float(number) => Ok(*number),
+                Value::Int(number) => Ok(*number as f64),
+                _ => unexpected(value, "a decimal number"),
+            }
+        }
+    }
+
+    impl FromValue for Vec<u8> {
+        fn from_value(value: &Value) -> Result<Self, String> {
+            match value {
+                Value::Bytes(bytes) => Ok(bytes.clone()),
+                Value::Text(text) => Ok(text.clone().into_bytes()),
+                _ => unexpected(value, "bytes"),
+            }
+        }
+    }
+
+    impl<T: FromValue> FromValue for Option<T> {
+        fn from_value(value: &Value) -> Result<Self, String> {
+            match value {
+                Value::Null => Ok(None),
+                _ => T::from_value(value).map(Some),
+            }
+        }
+    }
+
+    pub trait ColumnIndex {
+        fn position(&self, row: &Row) -> Result<usize, sqlx::Error>;
+    }
+
+    impl ColumnIndex for &str {
+        fn position(&self, row: &Row) -> Result<usize, sqlx::Error> {
+            row.columns
+                .iter()
+                .position(|c| c == self)
+                .ok_or_else(|| sqlx::Error::ColumnNotFound(self.to_string()))
+        }
+    }
+
+    impl ColumnIndex for usize {
+        fn position(&self, row: &Row) -> Result<usize, sqlx::Error> {
+            if *self < row.values.len() {
+                Ok(*self)
+            } else {
+                Err(sqlx::Error::ColumnIndexOutOfBounds {
+                    index: *self,
+                    len: row.values.len(),
+                })
+            }
+        }
+    }
+
+    pub struct Row {
+        columns: Vec<String>,
+        values: Vec<Value>,
+    }
+
+    fn unsupported_column(name: &str, type_name: &str) -> sqlx::Error {
+        sqlx::Error::ColumnDecode {
+            index: name.to_string(),
+            source: format!("columns of the type {} can't be read", type_name).into(),
+        }
+    }
+
+    impl Row {
+        pub fn try_get<T: FromValue, I: ColumnIndex>(&self, index: I) -> Result<T, sqlx::Error> {
+            let position = index.position(self)?;
+            T::from_value(&self.values[position]).map_err(|e| sqlx::Error::ColumnDecode {
+                index: self.columns[position].clone(),
+                source: e.into(),
+            })
+        }
+
+        // panics like sqlx's Row::get, so use try_get for columns that may not fit
+        pub fn get<T: FromValue, I: ColumnIndex>(&self, index: I) -> T {
+            self.try_get(index).unwrap_or_else(|e| panic!("{}", e))
+        }
+
+        fn from_mysql(row: &MySqlRow) -> Result<Row, sqlx::Error> {
+            let mut values = vec![];
+            for (i, column) in row.columns().iter().enumerate() {
+                let value = if row.try_get_raw(i)?.is_null() {
+                    Value::Null
+                } else if let Ok(number) = row.try_get::<i64, _>(i) {
+                    Value::Int(number)
+                } else if let Ok(number) = row.try_get::<u64, _>(i) {
+                    Value::Int(i64::try_from(number).unwrap_or(i64::MAX))
+                } else if let Ok(number) = row.try_get::<f64, _>(i) {
+                    Value::Float(number)
+                } else if let Ok(number) = row.try_get::<f32, _>(i) {
+                    Value::Float(number.into())
+                } else if let Ok(text) = row.try_get::<String, _>(i) {
+                    Value::Text(text)
+                } else if let Ok(bytes) = row.try_get::<Vec<u8>, _>(i) {
+                    Value::Bytes(bytes)
+                } else {
+                    return Err(unsupported_column(column.name(), column.type_info().name()));
+                };
+                values.push(value);
+            }
+            Ok(Row {
+                columns: row.columns().iter().map(|c| c.name().to_string()).collect(),
+                values,
+            })
+        }
+
+        fn from_postgres(row: &PgRow) -> Result<Row, sqlx::Error> {
+            let mut values = vec![];
+            for (i, column) in row.columns().iter().enumerate() {
+                let value = if row.try_get_raw(i)?.is_null() {
+                    Value::Null
+                } else if let Ok(b) = row.try_get::<bool, _>(i) {
+                    Value::Bool(b)
+                } else if let Ok(number) = row.try_get::<i64, _>(i) {
+                    Value::Int(number)
+                } else if let Ok(number) = row.try_get::<i32, _>(i) {
+                    Value::Int(number.into())
+                } else if let Ok(number) = row.try_get::<i16, _>(i) {
+                    Value::Int(number.into())
+                } else if let Ok(number) = row.try_get::<f64, _>(i) {
+                    Value::Float(number)
+                } else if let Ok(number) = row.try_get::<f32, _>(i) {
+                    Value::Float(number.into())
+                } else if let Ok(text) = row.try_get::<String, _>(i) {
+                    Value::Text(text)
+                } else if let Ok(bytes) = row.try_get::<Vec<u8>, _>(i) {
+                    Value::Bytes(bytes)
+                } else {
+                    return Err(unsupported_column(column.name(), column.type_info().name()));
+                };
+                values.push(value);
+            }
+            Ok(Row {
+                columns: row.columns().iter().map(|c| c.name().to_string()).collect(),
+                values,
+            })
+        }
+    }
+
+    // a record of a checked query, read from a row by the names of its fields (see from_row!)
+    pub trait FromRow: Sized {
+        fn from_row(row: &Row) -> Result<Self, sqlx::Error>;
+    }
+
+    // from_row!(SaveRecord { id, name, season }) reads the column "id" into the field id and so on,
+    // so a column that is missing or doesn't fit is an error instead of a panic
+    macro_rules! from_row {
+        ($record:ident { $($field:ident),* $(,)? }) => {
+            impl $crate::storage::backend::FromRow for $record {
+                fn from_row(row: &$crate::storage::backend::Row) -> Result<Self, sqlx::Error> {
+                    Ok($record {
+                        $($field: row.try_get(stringify!($field))?,)*
+                    })
+                }
+            }
+        };
+    }
+    pub(crate) use from_row;
+
+    // reads a row into the record R and turns that into T, which may refuse it, e.g. an unknown season
+    fn convert_row<R: FromRow, T: TryFrom<R>>(row: &Row) -> Result<T, sqlx::Error>
+    where
+        T::Error: Into<BoxDynError>,
+    {
+        T::try_from(R::from_row(row)?).map_err(|e| sqlx::Error::Decode(e.into()))
+    }
+
+    pub struct QueryResult {
+        rows_affected: u64,
+    }
+
+    impl QueryResult {
+        pub fn rows_affected(&self) -> u64 {
+            self.rows_affected
+        }
+    }
+
+    // checked queries are written with $1, $2, ... so sqlx can check them against the Postgres schema.
+    // MySQL only knows '?', so every $n becomes one and gets the argument it refers to, and an INSERT
+    // that ends in ON CONFLICT DO NOTHING becomes INSERT IGNORE.
+    fn mysql_sql(sql: &str, arguments: Vec<Argument>) -> (String, Vec<Argument>) {
+        let mut converted = String::with_capacity(sql.len());
+        let mut ordered = vec![];
+        let mut quote = None;
+        let mut chars = sql.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, quote) {
+                ('$', None) if chars.peek().is_some_and(|d| d.is_ascii_digit()) => {
+                    let mut number = String::new();
+                    while let Some(d) = chars.next_if(|d| d.is_ascii_digit()) {
+                        number.push(d);
+                    }
+                    let index = number.parse::<usize>().unwrap_or(0);
+                    if let Some(argument) = index.checked_sub(1).and_then(|i| arguments.get(i)) {
+                        ordered.push(argument.clone());
+                    }
+                    converted.push('?');
+                    continue;
+                }
+                ('\'' | '"', None) => quote = Some(c),
+                (_, Some(q)) if c == q => quote = None,
+                _ => {}
+            }
+            converted.push(c);
+        }
+
+        let converted = match converted
+            .strip_prefix("INSERT INTO")
+            .and_then(|rest| rest.strip_suffix(" ON CONFLICT DO NOTHING"))
+        {
+            Some(rest) => format!("INSERT IGNORE INTO{}", rest),
+            None => converted,
+        };
+
+        if ordered.is_empty() {
+            (converted, arguments)
+        } else {
+            (converted, ordered)
+        }
+    }
+
+    // the other queries are written for MySQL, with '?' placeholders. For Postgres these become $1, $2, ...
+    // and INSERT IGNORE becomes ON CONFLICT DO NOTHING; everything else has to be written so both understand it.
+    fn postgres_sql(sql: &str) -> String {
+        let mut converted = String::with_capacity(sql.len() + 16);
+        let mut parameter = 0;
+        let mut quote = None;
+        for c in sql.chars() {
+            match (c, quote) {
+                ('?', None) => {
+                    parameter += 1;
+                    converted.push_str(&format!("${}", parameter));
+                    continue;
+                }
+                ('\'' | '"', None) => quote = Some(c),
+                (_, Some(q)) if c == q => quote = None,
+                _ => {}
+            }
+            converted.push(c);
+        }
+
+        match converted.strip_prefix("INSERT IGNORE INTO") {
+            Some(rest) => format!("INSERT INTO{} ON CONFLICT DO NOTHING", rest),
+            None => converted,
+        }
+    }
+
+    fn bind_all<'q, DB: Database>(
+        sql: &'q str,
+        arguments: Vec<Argument>,
+    ) -> sqlx::query::Query<'q, DB, <DB as HasArguments<'q>>::Arguments>
+    where
+        Option<bool>: Encode<'q, DB> + Type<DB>,
+        Option<i64>: Encode<'q, DB> + Type<DB>,
+        Option<f64>: Encode<'q, DB> + Type<DB>,
+        Option<String>: Encode<'q, DB> + Type<DB>,
+        Option<Vec<u8>>: Encode<'q, DB> + Type<DB>,
+    {
+        arguments
+            .into_iter()
+            .fold(sqlx::query(sql), |query, argument| match argument {
+                Argument::Bool(value) => query.bind(value),
+                Argument::Int(value) => query.bind(value),
+                Argument::Float(value) => query.bind(value),
+                Argument::Text(value) => query.bind(value),
+                Argument::Bytes(value) => query.bind(value),
+            })
+    }
+
+    // the rows of Query::fetch, handed out one at a time as the server sends them
+    pub struct Rows {
+        receiver: mpsc::Receiver<Result<Row, sqlx::Error>>,
+    }
+
+    impl Rows {
+        pub async fn next(&mut self) -> Option<Result<Row, sqlx::Error>> {
+            let row = self.receiver.recv().await;
+            if let Some(Ok(_)) = row {
+                count_rows(1, 0);
+            }
+            row
+        }
+    }
+
+    async fn send_rows<R>(
+        mut rows: BoxStream<'_, Result<R, sqlx::Error>>,
+        convert: fn(&R) -> Result<Row, sqlx::Error>,
+        sender: mpsc::Sender<Result<Row, sqlx::Error>>,
+    ) {
+        while let Some(row) = rows.next().await {
+            // stops reading once the caller has dropped its Rows
+            if sender.send(row.and_then(|r| convert(&r))).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    pub struct Query {
+        sql: String,
+        arguments: Vec<Argument>,
+    }
+
+    pub fn query(sql: &str) -> Query {
+        Query {
+            sql: sql.to_string(),
+            arguments: vec![],
+        }
+    }
+
+    impl Query {
+        pub fn bind<T: IntoArgument>(mut self, value: T) -> Query {
+            self.arguments.push(value.into_argument());
+            self
+        }
+
+        pub async fn execute(self, pool: &Pool) -> Result<QueryResult, sqlx::Error> {
+            let rows_affected = match pool {
+                Pool::MySql(pool) => {
+                    let (sql, arguments) = mysql_sql(&self.sql, self.arguments);
+                    bind_all(&sql, arguments)
+                        .execute(pool)
+                        .await?
+                        .rows_affected()
+                }
+                Pool::Postgres(pool) => bind_all(&postgres_sql(&self.sql), self.arguments)
+                    .execute(pool)
+                    .await?
+                    .rows_affected(),
+            };
+            count_rows(0, rows_affected);
+            Ok(QueryResult { rows_affected })
+        }
+
+        pub async fn fetch_all(self, pool: &Pool) -> Result<Vec<Row>, sqlx::Error> {
+            let rows: Vec<Row> = match pool {
+                Pool::MySql(pool) => {
+                    let (sql, arguments) = mysql_sql(&self.sql, self.arguments);
+                    bind_all(&sql, arguments)
+                        .fetch_all(pool)
+                        .await?
+                        .iter()
+                        .map(Row::from_mysql)
+                        .collect::<Result<_, _>>()?
+                }
+                Pool::Postgres(pool) => bind_all(&postgres_sql(&self.sql), self.arguments)
+                    .fetch_all(pool)
+                    .await?
+                    .iter()
+                    .map(Row::from_postgres)
+                    .collect::<Result<_, _>>()?,
+            };
+            count_rows(rows.len() as u64, 0);
+            Ok(rows)
+        }
+
+        // streams the rows instead of collecting them, for results that may be too big to hold.
+        // The query runs in its own task, which owns the SQL and the connection until it is done.
+        pub fn fetch(self, pool: &Pool) -> Rows {
+            let (sender, receiver) = mpsc::channel(ROWS_AHEAD);
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                match pool {
+                    Pool::MySql(pool) => {
+                        let (sql, arguments) = mysql_sql(&self.sql, self.arguments);
+                        let rows = bind_all(&sql, arguments).fetch(&pool);
+                        send_rows(rows, Row::from_mysql, sender).await;
+                    }
+                    Pool::Postgres(pool) => {
+                        let sql = postgres_sql(&self.sql);
+                        let rows = bind_all(&sql, self.arguments).fetch(&pool);
+                        send_rows(rows, Row::from_postgres, sender).await;
+                    }
+                }
+            });
+            Rows { receiver }
+        }
+
+        pub async fn fetch_optional(self, pool: &Pool) -> Result<Option<Row>, sqlx::Error> {
+            let row = match pool {
+                Pool::MySql(pool) => {
+                    let (sql, arguments) = mysql_sql(&self.sql, self.arguments);
+                    bind_all(&sql, arguments)
+                        .fetch_optional(pool)
+                        .await?
+                        .as_ref()
+                        .map(Row::from_mysql)
+                        .transpose()?
+                }
+                Pool::Postgres(pool) => bind_all(&postgres_sql(&self.sql), self.arguments)
+                    .fetch_optional(pool)
+                    .await?
+                    .as_ref()
+                    .map(Row::from_postgres)
+                    .transpose()?,
+            };
+            if row.is_some() {
+                count_rows(1, 0);
+            }
+            Ok(row)
+        }
+
+        pub async fn fetch_one(self, pool: &Pool) -> Result<Row, sqlx::Error> {
+            self.fetch_optional(pool)
+                .await?
+                .ok_or(sqlx::Error::RowNotFound)
+        }
+    }
+
+    // the query of checked_query_as!, which gives back T instead of rows
+    pub struct QueryAs<R, T> {
+        query: Query,
+        output: PhantomData<fn(R) -> T>,
+    }
+
+    pub fn query_as<R, T>(query: Query) -> QueryAs<R, T> {
+        QueryAs {
+            query,
+            output: PhantomData,
+        }
+    }
+
+    impl<R: FromRow, T: TryFrom<R>> QueryAs<R, T>
+    where
+        T::Error: Into<BoxDynError>,
+    {
+        pub async fn fetch_all(self, pool: &Pool) -> Result<Vec<T>, sqlx::Error> {
+            self.query
+                .fetch_all(pool)
+                .await?
+                .iter()
+                .map(convert_row::<R, T>)
+                .collect()
+        }
+
+        pub fn fetch(self, pool: &Pool) -> RowsAs<R, T> {
+            RowsAs {
+                rows: self.query.fetch(pool),
+                output: PhantomData,
+            }
+        }
+
+        pub async fn fetch_optional(self, pool: &Pool) -> Result<Option<T>, sqlx::Error> {
+            self.query
+                .fetch_optional(pool)
+                .await?
+                .as_ref()
+                .map(convert_row::<R, T>)
+                .transpose()
+        }
+    }
+
+    // the rows of QueryAs::fetch
+    pub struct RowsAs<R, T> {
+        rows: Rows,
+        output: PhantomData<fn(R) -> T>,
+    }
+
+    impl<R: FromRow, T: TryFrom<R>> RowsAs<R, T>
+    where
+        T::Error: Into<BoxDynError>,
+    {
+        pub async fn next(&mut self) -> Option<Result<T, sqlx::Error>> {
+            self.rows.next().await.map(|row| convert_row::<R, T>(&row?))
+        }
+    }
+
+    // checked_query!("UPDATE characters SET best_gift = $1 WHERE name = $2", gift, name) is checked
+    // against the query cache in .sqlx while compiling, so a renamed column or a parameter of the wrong
+    // type breaks the build. It runs through query() like any other query, on either server.
+    macro_rules! checked_query {
+        ($sql:literal $(, $argument:expr)* $(,)?) => {{
+            if false {
+                let _ = sqlx::query!($sql $(, $argument)*);
+            }
+            $crate::storage::backend::query($sql)$(.bind($argument))*
+        }};
+    }
+    pub(crate) use checked_query;
+
+    // the same for queries that return rows: the selected columns have to be exactly the fields of
+    // the record, with the same types. checked_query_as!(SaveRecord, "SELECT ...") gives back
+    // SaveRecords, checked_query_as!(SaveRecord => Save, "SELECT ...") turns each one into a Save.
+    macro_rules! checked_query_as {
+        ($record:path => $output:ty, $sql:literal $(, $argument:expr)* $(,)?) => {{
+            if false {
+                let _ = sqlx::query_as!($record, $sql $(, $argument)*);
+            }
+            $crate::storage::backend::query_as::<$record, $output>(
+                $crate::storage::backend::query($sql)$(.bind($argument))*,
+            )
+        }};
+        ($record:path, $sql:literal $(, $argument:expr)* $(,)?) => {
+            $crate::storage::backend::checked_query_as!($record => $record, $sql $(, $argument)*)
+        };
+    }
+    pub(crate) use checked_query_as;
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn text(value: &str) -> Argument {
+            Argument::Text(Some(value.to_string()))
+        }
+
+        #[test]
+        fn question_marks_become_numbered_parameters_for_postgres() {
+            assert_eq!(
+                postgres_sql("UPDATE characters SET best_gift = ? WHERE name = ?"),
+                "UPDATE characters SET best_gift = $1 WHERE name = $2"
+            );
+            assert_eq!(
+                postgres_sql("SELECT name FROM characters"),
+                "SELECT name FROM characters"
+            );
+        }
+
+        #[test]
+        fn question_marks_in_quotes_stay_for_postgres() {
+            assert_eq!(
+                postgres_sql("SELECT 'why?' AS \"really?\", name FROM characters WHERE name = ?"),
+                "SELECT 'why?' AS \"really?\", name FROM characters WHERE name = $1"
+            );
+            assert_eq!(
+                postgres_sql("INSERT INTO notes (text) VALUES ('it''s ok?'), (?)"),
+                "INSERT INTO notes (text) VALUES ('it''s ok?'), ($1)"
+            );
+        }
+
+        #[test]
+        fn insert_ignore_becomes_on_conflict_do_nothing() {
+            assert_eq!(
+                postgres_sql("INSERT IGNORE INTO tags (character_name, tag) VALUES (?, ?)"),
+                "INSERT INTO tags (character_name, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING"
+            );
+            // only a statement that starts with it
+            assert_eq!(
+                postgres_sql("SELECT 'INSERT IGNORE INTO' AS text"),
+                "SELECT 'INSERT IGNORE INTO' AS text"
+            );
+        }
+
+        #[test]
+        fn numbered_parameters_become_question_marks_for_mysql() {
+            let (sql, arguments) = mysql_sql(
+                "SELECT name FROM characters WHERE source = $1 AND name > $2 LIMIT $3",
+                vec![text("vanilla"), text("Abigail"), Argument::Int(Some(50))],
+            );
+            assert_eq!(
+                sql,
+                "SELECT name FROM characters WHERE source = ? AND name > ? LIMIT ?"
+            );
+            assert_eq!(
+                arguments,
+                [text("vanilla"), text("Abigail"), Argument::Int(Some(50))]
+            );
+        }
+
+        #[test]
+        fn mysql_arguments_follow_the_order_of_the_parameters() {
+            let (sql, arguments) = mysql_sql(
+                "SELECT $2 AS second, $1 AS first, $2 AS again",
+                vec![text("a"), text("b")],
+            );
+            assert_eq!(sql, "SELECT ? AS second, ? AS first, ? AS again");
+            assert_eq!(arguments, [text("b"), text("a"), text("b")]);
+        }
+
+        #[test]
+        fn on_conflict_do_nothing_becomes_insert_ignore() {
+            let (sql, arguments) = mysql_sql(
+                "INSERT INTO tags (character_name, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+                vec![text("Abigail"), text("gamer")],
+            );
+            assert_eq!(
+                sql,
+                "INSERT IGNORE INTO tags (character_name, tag) VALUES (?, ?)"
+            );
+            assert_eq!(arguments, [text("Abigail"), text("gamer")]);
+            // an upsert that names its columns is written once per server instead
+            let (sql, _) = mysql_sql(
+                "INSERT INTO tags (character_name, tag) VALUES ($1, $2) ON CONFLICT (character_name) DO NOTHING",
+                vec![text("Abigail"), text("gamer")],
+            );
+            assert!(sql.starts_with("INSERT INTO tags"));
+        }
+
+        #[test]
+        fn dollars_in_quotes_stay_for_mysql() {
+            let (sql, arguments) = mysql_sql(
+                "SELECT name FROM characters WHERE best_gift = '$1 coin' AND name = $1",
+                vec![text("Abigail")],
+            );
+            assert_eq!(
+                sql,
+                "SELECT name FROM characters WHERE best_gift = '$1 coin' AND name = ?"
+            );
+            assert_eq!(arguments, [text("Abigail")]);
+        }
+
+        struct GiftRecord {
+            name: String,
+            price: Option<i32>,
+            is_loved: bool,
+        }
+
+        from_row!(GiftRecord {
+            name,
+            price,
+            is_loved
+        });
+
+        struct Gift {
+            name: String,
+        }
+
+        impl TryFrom<GiftRecord> for Gift {
+            type Error = String;
+
+            fn try_from(record: GiftRecord) -> Result<Gift, String> {
+                if record.is_loved {
+                    Ok(Gift { name: record.name })
+                } else {
+                    Err(format!("{} isn't loved", record.name))
+                }
+            }
+        }
+
+        fn row(columns: &[(&str, Value)]) -> Row {
+            Row {
+                columns: columns.iter().map(|(name, _)| name.to_string()).collect(),
+                values: columns.iter().map(|(_, value)| value.clone()).collect(),
+            }
+        }
+
+        #[test]
+        fn records_are_read_by_column_name() {
+            let record = GiftRecord::from_row(&row(&[
+                ("is_loved", Value::Int(1)),
+                ("name", Value::Text("Amethyst".to_string())),
+                ("price", Value::Null),
+            ]))
+            .unwrap();
+            assert_eq!(record.name, "Amethyst");
+            assert_eq!(record.price, None);
+            assert!(record.is_loved);
+        }
+
+        #[test]
+        fn a_missing_or_unfitting_column_is_an_error() {
+            let missing = GiftRecord::from_row(&row(&[
+                ("name", Value::Text("Amethyst".to_string())),
+                ("is_loved", Value::Bool(true)),
+            ]));
+            assert!(
+                matches!(missing, Err(sqlx::Error::ColumnNotFound(column)) if column == "price")
+            );
+
+            let unfitting = GiftRecord::from_row(&row(&[
+                ("name", Value::Int(66)),
+                ("price", Value::Int(500)),
+                ("is_loved", Value::Bool(true)),
+            ]));
+            assert!(
+                matches!(unfitting, Err(sqlx::Error::ColumnDecode { index, .. }) if index == "name")
+            );
+        }
+
+        #[test]
+        fn records_the_output_refuses_are_an_error() {
+            let loved = row(&[
+                ("name", Value::Text("Amethyst".to_string())),
+                ("price", Value::Int(100)),
+                ("is_loved", Value::Bool(true)),
+            ]);
+            assert_eq!(
+                convert_row::<GiftRecord, Gift>(&loved).unwrap().name,
+                "Amethyst"
+            );
+
+            let disliked = row(&[
+                ("name", Value::Text("Clay".to_string())),
+                ("price", Value::Int(50)),
+                ("is_loved", Value::Bool(false)),
+            ]);
+            match convert_row::<GiftRecord, Gift>(&disliked) {
+                Err(sqlx::Error::Decode(e)) => assert_eq!(e.to_string(), "Clay isn't loved"),
+                _ => panic!("Clay was read as a loved gift"),
+            }
+        }
+    }
+}